@@ -4,6 +4,8 @@ mod simple_interaction_plugin;
 mod style_command;
 mod style_commands;
 mod ui_context;
+mod ui_markup;
+mod widget;
 
 use proc_macro::TokenStream;
 use syn::DeriveInput;
@@ -114,16 +116,61 @@ pub fn style_command_macro_derive(input: TokenStream) -> TokenStream {
     style_command::derive_style_command_macro(&ast)
 }
 
+/// Generates the style attributes, `StyleBuilder` functions and `UiStyle` commands from
+/// an enum of attributes.
+///
+/// Each variant declares an attribute with a single named field, the field name being the
+/// command name. `#[animatable]` attributes can also be used with `AnimatedVals`,
+/// `#[static_style_only]` ones cannot be interactive.
+///
+/// By default the attribute sets the `Style` field of the same name. `#[target_enum]` sets the
+/// component of the attribute's type, `#[target_tupl(Component)]` the first field of `Component`,
+/// `#[target_component(Component, field)]` a named field of `Component`, and
+/// `#[target_fn(set_fn)]` calls `fn set_fn(Entity, T, &mut World)`.
+///
+/// # Custom attributes
+///
+/// Add `#[custom_style_attributes]` to the enum to declare attributes in other crates.
+/// These are built on the `Custom` style attribute variants and must define a target.
+/// For an enum `MaterialStyle`, the `MaterialStyleExt`, `MaterialStyleInteractiveExt` and
+/// `MaterialStyleAnimatedExt` traits extend `StyleBuilder` and its interactive and animated
+/// builders, and the `Set*Ext` traits extend `UiStyle`. Custom attributes are not lockable.
+///
+/// The generated code expects `bevy::prelude::*`, `bevy::ecs::system::EntityCommand` and
+/// `sickle_ui::prelude::*` in scope.
+///
+/// ```ignore
+/// #[derive(StyleCommands)]
+/// #[custom_style_attributes]
+/// enum MaterialStyle {
+///     #[animatable]
+///     #[target_fn(set_glow)]
+///     Glow { glow: f32 },
+///     #[target_component(Transform, scale)]
+///     Scale3d { scale_3d: Vec3 },
+/// }
+///
+/// fn set_glow(entity: Entity, glow: f32, world: &mut World) { .. }
+///
+/// style_builder.animated().glow(AnimatedVals {
+///     idle: 0.,
+///     hover: Some(1.),
+///     ..default()
+/// });
+/// ```
 #[proc_macro_derive(
     StyleCommands,
     attributes(
+        custom_style_attributes,
         static_style_only,
         skip_enity_command,
         skip_ui_style_ext,
         skip_lockable_enum,
         animatable,
         target_enum,
-        target_tupl
+        target_tupl,
+        target_component,
+        target_fn
     )
 )]
 pub fn style_commands_macro_derive(input: TokenStream) -> TokenStream {
@@ -131,7 +178,33 @@ pub fn style_commands_macro_derive(input: TokenStream) -> TokenStream {
     style_commands::derive_style_commands_macro(&ast)
 }
 
-#[proc_macro_derive(UiContext)]
+/// Derives `UiContext` for a widget component.
+///
+/// Fields marked with `#[context("Name")]` become the widget's contexts: a
+/// `pub const` named after the uppercased field is generated for each, along with
+/// `get`, `contexts` and `cleared_contexts`. Contexts marked with `no_clear` are
+/// left out of `cleared_contexts`, e.g. when they are sub-widgets with their own theme.
+/// Use `const = NAME` to name the constant independently of the field.
+///
+/// Without any marked fields the default, context-less implementation is derived.
+///
+/// ```ignore
+/// #[derive(Component, Clone, Copy, Debug, Reflect, UiContext)]
+/// pub struct MyWidget {
+///     pub checked: bool,
+///     #[context("Checkmark")]
+///     checkmark: Entity,
+///     #[context("Label")]
+///     label: Entity,
+///     #[context("Panel", no_clear)]
+///     panel: Entity,
+///     #[context("Handle", const = DRAG_HANDLE)]
+///     handle_entity: Entity,
+/// }
+///
+/// // MyWidget::CHECKMARK == "Checkmark", MyWidget::DRAG_HANDLE == "Handle"
+/// ```
+#[proc_macro_derive(UiContext, attributes(context))]
 pub fn ui_context_macro_derive(input: TokenStream) -> TokenStream {
     let ast: DeriveInput = syn::parse(input.clone()).unwrap();
     ui_context::derive_ui_context_macro(&ast)
@@ -142,3 +215,90 @@ pub fn default_theme_macro_derive(input: TokenStream) -> TokenStream {
     let ast: DeriveInput = syn::parse(input.clone()).unwrap();
     default_theme::derive_default_theme_macro(&ast)
 }
+
+/// Derives the `Ui*Ext` builder trait of a widget component.
+///
+/// The widget must implement `WidgetBuilder`, which describes how it is spawned and requires
+/// `DefaultTheme`. The generated `UiFooExt` trait has a single method, named after the widget in
+/// snake case, that takes anything convertible into the widget. Use `#[widget(method = name)]`
+/// to rename the method and `#[widget(children)]` to also take a `spawn_children` closure.
+///
+/// The widget's theme is registered when the first widget is spawned, or when
+/// `WidgetPlugin::<Foo>::default()` is added to the app. Spawning a widget without a default
+/// theme panics.
+///
+/// ```ignore
+/// #[derive(Component, Clone, Debug, Reflect, UiContext, Widget)]
+/// #[widget(children)]
+/// pub struct Card {
+///     #[context("Title")]
+///     title: Entity,
+///     title_text: String,
+/// }
+///
+/// impl From<&str> for Card { .. }
+/// impl DefaultTheme for Card { .. }
+///
+/// impl WidgetBuilder for Card {
+///     fn frame() -> impl Bundle {
+///         NodeBundle::default()
+///     }
+///
+///     fn build(&mut self, builder: &mut UiBuilder<Entity>) {
+///         self.title = builder.label(self.title_text.clone()).id();
+///     }
+/// }
+///
+/// // Generated: `UiCardExt::card(&mut self, widget: impl Into<Card>, spawn_children)`
+/// ui.card("Settings", |card| {
+///     card.checkbox(None, true);
+/// });
+/// ```
+#[proc_macro_derive(Widget, attributes(widget))]
+pub fn widget_macro_derive(input: TokenStream) -> TokenStream {
+    let ast: DeriveInput = syn::parse(input.clone()).unwrap();
+    widget::derive_widget_macro(&ast)
+}
+
+/// Expands markup into `UiBuilder` calls.
+///
+/// The first argument is the builder to spawn into, followed by widgets separated by
+/// whitespace. A widget is the name of a builder method, such as those of the `Ui*Ext` traits,
+/// with its arguments. Arguments are arbitrary Rust expressions. A `{ .. }` block spawns its
+/// widgets as children, passed as the last argument of the widget. Method calls following a
+/// widget are applied to the builder it returns.
+///
+/// ```ignore
+/// ui!(builder,
+///     column {
+///         label("Speed")
+///         slider(SliderConfig::horizontal(None, 0., 1., 0.5, true)).named("speed")
+///         row {
+///             checkbox(None, false).insert(Boost)
+///         }
+///         .style()
+///         .justify_content(JustifyContent::End)
+///     }
+/// );
+/// ```
+///
+/// Expands to:
+///
+/// ```ignore
+/// builder.column(|ui| {
+///     ui.label("Speed");
+///     ui.slider(SliderConfig::horizontal(None, 0., 1., 0.5, true)).named("speed");
+///     ui.row(|ui| {
+///         ui.checkbox(None, false).insert(Boost);
+///     })
+///     .style()
+///     .justify_content(JustifyContent::End);
+/// });
+/// ```
+///
+/// Unknown widgets and wrong arguments are reported at the markup, the same way as
+/// the equivalent method call.
+#[proc_macro]
+pub fn ui(input: TokenStream) -> TokenStream {
+    ui_markup::expand_ui_macro(input)
+}
@@ -2,7 +2,8 @@ use proc_macro::TokenStream;
 use proc_macro2::Ident;
 use quote::{quote, quote_spanned};
 use syn::{
-    spanned::Spanned, AttrStyle, Attribute, Data, DataEnum, Fields, Meta, Type, TypePath, Variant,
+    spanned::Spanned, AttrStyle, Attribute, Data, DataEnum, DeriveInput, Fields, Meta, Path, Token,
+    Type, TypePath, Variant,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -12,7 +13,11 @@ enum ParseError {
     TooManyFields,
     InvalidType,
     InvalidTargetTuplType,
+    InvalidTargetComponent,
+    InvalidTargetFn,
     StaticAnimatable,
+    MissingCustomTarget,
+    UnsupportedCustomAttribute,
 }
 
 #[derive(Clone, Debug)]
@@ -21,6 +26,8 @@ struct StyleAttribute {
     command: Ident,
     type_path: TypePath,
     target_tupl: Option<proc_macro2::TokenStream>,
+    target_component: Option<(Path, Ident)>,
+    target_fn: Option<Path>,
     animatable: bool,
     target_enum: bool,
     static_style_only: bool,
@@ -43,6 +50,8 @@ impl StyleAttribute {
             command,
             type_path,
             target_tupl: None,
+            target_component: None,
+            target_fn: None,
             animatable: false,
             target_enum: false,
             static_style_only: false,
@@ -65,6 +74,13 @@ pub(crate) fn derive_style_commands_macro(ast: &syn::DeriveInput) -> TokenStream
         .into();
     };
 
+    if is_custom(ast) {
+        return match parse_custom_variants(enum_data) {
+            Ok(attributes) => prepare_custom_style_attributes(name_ident, &attributes).into(),
+            Err((span, error)) => match_error(span, error).into(),
+        };
+    }
+
     let attributes = match parse_variants(enum_data) {
         Ok(attributes) => attributes,
         Err((span, error)) => return match_error(span, error).into(),
@@ -75,6 +91,7 @@ pub(crate) fn derive_style_commands_macro(ast: &syn::DeriveInput) -> TokenStream
     let static_style_attribute = prepare_static_style_attribute(&attributes);
     let interactive_style_attribute = prepare_interactive_style_attribute(&attributes);
     let animated_style_attribute = prepare_animated_style_attribute(&attributes);
+    let keyframes_style_attribute = prepare_keyframes_style_attribute(&attributes);
     let enum_equivalence = prepare_enum_equivalence(&attributes);
     let style_commands = prepare_style_commands(&attributes);
 
@@ -83,6 +100,7 @@ pub(crate) fn derive_style_commands_macro(ast: &syn::DeriveInput) -> TokenStream
         #lockable_attribute
         #interactive_style_attribute
         #animated_style_attribute
+        #keyframes_style_attribute
         #enum_equivalence
         #stylable_attribute
         #style_commands
@@ -117,11 +135,31 @@ fn match_error(span: proc_macro2::Span, error: ParseError) -> proc_macro2::Token
                 span => compile_error!("Unsupported target_tupl value. Must be defined as #[target_tupl(Component)]");
             }
         }
+        ParseError::InvalidTargetComponent => {
+            return quote_spanned! {
+                span => compile_error!("Unsupported target_component value. Must be defined as #[target_component(Component, field)]");
+            }
+        }
+        ParseError::InvalidTargetFn => {
+            return quote_spanned! {
+                span => compile_error!("Unsupported target_fn value. Must be defined as #[target_fn(path::to::fn)]");
+            }
+        }
         ParseError::StaticAnimatable => {
             return quote_spanned! {
                 span => compile_error!("Attribute cannot be static only and animatable at the same time!");
             }
         }
+        ParseError::MissingCustomTarget => {
+            return quote_spanned! {
+                span => compile_error!("Custom style attributes must define a target: #[target_enum], #[target_tupl(Component)], #[target_component(Component, field)] or #[target_fn(path::to::fn)]");
+            }
+        }
+        ParseError::UnsupportedCustomAttribute => {
+            return quote_spanned! {
+                span => compile_error!("Custom style attributes always have entity commands and UiStyle extensions, and are never lockable");
+            }
+        }
     }
 }
 
@@ -172,6 +210,10 @@ fn parse_variant(variant: &Variant) -> Result<StyleAttribute, (proc_macro2::Span
             } else if attr.path().is_ident("target_tupl") {
                 let token_stream = target_tupl(attr)?;
                 attribute.target_tupl = Some(token_stream);
+            } else if attr.path().is_ident("target_component") {
+                attribute.target_component = Some(target_component(attr)?);
+            } else if attr.path().is_ident("target_fn") {
+                attribute.target_fn = Some(target_fn(attr)?);
             }
         }
     }
@@ -210,6 +252,53 @@ fn target_tupl(
     Ok(list.tokens.clone())
 }
 
+fn target_component(attr: &Attribute) -> Result<(Path, Ident), (proc_macro2::Span, ParseError)> {
+    let attr_span = attr.path().get_ident().unwrap().span();
+    attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let component: Path = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let field: Ident = input.parse()?;
+
+        Ok((component, field))
+    })
+    .map_err(|_| (attr_span, ParseError::InvalidTargetComponent))
+}
+
+fn target_fn(attr: &Attribute) -> Result<Path, (proc_macro2::Span, ParseError)> {
+    let attr_span = attr.path().get_ident().unwrap().span();
+    attr.parse_args::<Path>()
+        .map_err(|_| (attr_span, ParseError::InvalidTargetFn))
+}
+
+fn is_custom(ast: &DeriveInput) -> bool {
+    ast.attrs.iter().any(|attr| {
+        attr.style == AttrStyle::Outer && attr.path().is_ident("custom_style_attributes")
+    })
+}
+
+fn parse_custom_variants(
+    data: &DataEnum,
+) -> Result<Vec<StyleAttribute>, (proc_macro2::Span, ParseError)> {
+    let mut attributes = parse_variants(data)?;
+    for (attribute, variant) in attributes.iter_mut().zip(data.variants.iter()) {
+        if attribute.skip_enity_command || attribute.skip_ui_style_ext {
+            return Err((variant.span(), ParseError::UnsupportedCustomAttribute));
+        }
+        if !(attribute.target_enum
+            || attribute.target_tupl.is_some()
+            || attribute.target_component.is_some()
+            || attribute.target_fn.is_some())
+        {
+            return Err((variant.ident.span(), ParseError::MissingCustomTarget));
+        }
+
+        // Custom attributes cannot be added to `LockableStyleAttribute`
+        attribute.skip_lockable_enum = true;
+    }
+
+    Ok(attributes)
+}
+
 fn prepare_stylable_attribute(style_attributes: &Vec<StyleAttribute>) -> proc_macro2::TokenStream {
     let base_variants: Vec<proc_macro2::TokenStream> = style_attributes
         .iter()
@@ -253,6 +342,11 @@ fn prepare_static_style_attribute(
         .collect();
     let builder_fns: Vec<proc_macro2::TokenStream> =
         variants.clone().map(to_static_style_builder_fn).collect();
+    let lockable_variants: Vec<proc_macro2::TokenStream> = variants
+        .clone()
+        .filter(|v| !v.skip_lockable_enum)
+        .map(to_lockable_variant)
+        .collect();
 
     quote! {
         #[derive(Clone, Debug)]
@@ -265,7 +359,7 @@ fn prepare_static_style_attribute(
             fn logical_eq(&self, other: &Self) -> bool {
                 match (self, other) {
                     #(#eq_variants)*
-                    (Self::Custom(l0), Self::Custom(r0)) => l0 == r0,
+                    (Self::Custom(l0), Self::Custom(r0)) => l0.logical_eq(r0),
                     _ => false,
                 }
             }
@@ -280,6 +374,13 @@ fn prepare_static_style_attribute(
                     }
                 }
             }
+
+            pub fn lockable(&self) -> Option<LockableStyleAttribute> {
+                match self {
+                    #(#lockable_variants)*
+                    _ => None,
+                }
+            }
         }
 
         impl StyleBuilder {
@@ -304,6 +405,11 @@ fn prepare_interactive_style_attribute(
         .clone()
         .map(to_interactive_style_builder_fn)
         .collect();
+    let lockable_variants: Vec<proc_macro2::TokenStream> = variants
+        .clone()
+        .filter(|v| !v.skip_lockable_enum)
+        .map(to_lockable_variant)
+        .collect();
 
     quote! {
         #[derive(Clone, Debug)]
@@ -316,7 +422,7 @@ fn prepare_interactive_style_attribute(
             fn logical_eq(&self, other: &Self) -> bool {
                 match (self, other) {
                     #(#eq_variants)*
-                    (Self::Custom(l0), Self::Custom(r0)) => l0 == r0,
+                    (Self::Custom(l0), Self::Custom(r0)) => l0.logical_eq(r0),
                     _ => false,
                 }
             }
@@ -345,6 +451,13 @@ fn prepare_interactive_style_attribute(
                     }
                 }
             }
+
+            pub fn lockable(&self) -> Option<LockableStyleAttribute> {
+                match self {
+                    #(#lockable_variants)*
+                    _ => None,
+                }
+            }
         }
 
         impl InteractiveStyleBuilder<'_> {
@@ -367,6 +480,11 @@ fn prepare_animated_style_attribute(
         .collect();
     let builder_fns: Vec<proc_macro2::TokenStream> =
         variants.clone().map(to_animated_style_builder_fn).collect();
+    let lockable_variants: Vec<proc_macro2::TokenStream> = variants
+        .clone()
+        .filter(|v| !v.skip_lockable_enum)
+        .map(to_lockable_variant)
+        .collect();
 
     quote! {
         #[derive(Clone, Debug, PartialEq)]
@@ -379,7 +497,7 @@ fn prepare_animated_style_attribute(
             fn logical_eq(&self, other: &Self) -> bool {
                 match (self, other) {
                     #(#eq_variants)*
-                    (Self::Custom(l0), Self::Custom(r0)) => l0 == r0,
+                    (Self::Custom(l0), Self::Custom(r0)) => l0.logical_eq(r0),
                     _ => false,
                 }
             }
@@ -417,6 +535,13 @@ fn prepare_animated_style_attribute(
                     }
                 }
             }
+
+            pub fn lockable(&self) -> Option<LockableStyleAttribute> {
+                match self {
+                    #(#lockable_variants)*
+                    _ => None,
+                }
+            }
         }
 
         impl AnimatedStyleBuilder<'_> {
@@ -425,6 +550,95 @@ fn prepare_animated_style_attribute(
     }
 }
 
+fn prepare_keyframes_style_attribute(
+    style_attributes: &Vec<StyleAttribute>,
+) -> proc_macro2::TokenStream {
+    let variants = style_attributes.iter().filter(|v| v.animatable);
+    let base_variants: Vec<proc_macro2::TokenStream> =
+        variants.clone().map(to_keyframes_style_variant).collect();
+    let eq_variants: Vec<proc_macro2::TokenStream> =
+        variants.clone().map(to_eq_style_variant).collect();
+    let apply_variants: Vec<proc_macro2::TokenStream> = variants
+        .clone()
+        .map(to_keyframes_style_appl_variant)
+        .collect();
+    let duration_variants: Vec<proc_macro2::TokenStream> = variants
+        .clone()
+        .map(to_keyframes_style_duration_variant)
+        .collect();
+    let builder_fns: Vec<proc_macro2::TokenStream> = variants
+        .clone()
+        .map(to_keyframes_style_builder_fn)
+        .collect();
+    let lockable_variants: Vec<proc_macro2::TokenStream> = variants
+        .clone()
+        .filter(|v| !v.skip_lockable_enum)
+        .map(to_lockable_variant)
+        .collect();
+
+    quote! {
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum KeyframesStyleAttribute {
+            #(#base_variants)*
+            Custom(CustomKeyframesStyleAttribute),
+        }
+
+        impl LogicalEq for KeyframesStyleAttribute {
+            fn logical_eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    #(#eq_variants)*
+                    (Self::Custom(l0), Self::Custom(r0)) => l0.logical_eq(r0),
+                    _ => false,
+                }
+            }
+        }
+
+        impl KeyframesStyleAttribute {
+            fn to_attribute(&self, playhead: f32) -> StaticStyleAttribute {
+                match self {
+                    #(#apply_variants)*
+                    Self::Custom(_) => unreachable!(),
+                }
+            }
+
+            pub fn apply(&self, playhead: f32, ui_style: &mut UiStyle) {
+                match self {
+                    Self::Custom(callback) => {
+                        ui_style
+                            .entity_commands()
+                            .add(ApplyCustomKeyframesStyleAttribute {
+                                callback: callback.clone(),
+                                playhead,
+                            });
+                    }
+                    _ => {
+                        self.to_attribute(playhead).apply(ui_style);
+                    }
+                }
+            }
+
+            /// Length of a single iteration in seconds
+            pub fn duration(&self) -> f32 {
+                match self {
+                    #(#duration_variants)*
+                    Self::Custom(callback) => callback.duration,
+                }
+            }
+
+            pub fn lockable(&self) -> Option<LockableStyleAttribute> {
+                match self {
+                    #(#lockable_variants)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl KeyframesStyleBuilder<'_> {
+            #(#builder_fns)*
+        }
+    }
+}
+
 fn prepare_enum_equivalence(style_attributes: &Vec<StyleAttribute>) -> proc_macro2::TokenStream {
     let interactive_to_static: Vec<proc_macro2::TokenStream> = style_attributes
         .iter()
@@ -459,11 +673,45 @@ fn prepare_enum_equivalence(style_attributes: &Vec<StyleAttribute>) -> proc_macr
         .map(to_eq_animated_variant)
         .collect();
 
+    let keyframes_to_static: Vec<proc_macro2::TokenStream> = style_attributes
+        .iter()
+        .filter(|v| v.animatable)
+        .map(to_eq_static_variant)
+        .collect();
+    let static_to_keyframes: Vec<proc_macro2::TokenStream> = style_attributes
+        .iter()
+        .filter(|v| v.animatable)
+        .map(to_eq_keyframes_variant)
+        .collect();
+    let keyframes_to_interactive: Vec<proc_macro2::TokenStream> = style_attributes
+        .iter()
+        .filter(|v| v.animatable)
+        .map(to_eq_interactive_variant)
+        .collect();
+    let interactive_to_keyframes: Vec<proc_macro2::TokenStream> = style_attributes
+        .iter()
+        .filter(|v| v.animatable)
+        .map(to_eq_keyframes_variant)
+        .collect();
+    let keyframes_to_animated: Vec<proc_macro2::TokenStream> = style_attributes
+        .iter()
+        .filter(|v| v.animatable)
+        .map(to_eq_animated_variant)
+        .collect();
+    let animated_to_keyframes: Vec<proc_macro2::TokenStream> = style_attributes
+        .iter()
+        .filter(|v| v.animatable)
+        .map(to_eq_keyframes_variant)
+        .collect();
+
     quote! {
         impl LogicalEq<StaticStyleAttribute> for InteractiveStyleAttribute {
             fn logical_eq(&self, other: &StaticStyleAttribute) -> bool {
                 match (self, other) {
                     #(#interactive_to_static)*
+                    (Self::Custom(l0), StaticStyleAttribute::Custom(r0)) => {
+                        l0.declared_key().is_some() && l0.declared_key() == r0.declared_key()
+                    }
                     _ => false,
                 }
             }
@@ -472,6 +720,9 @@ fn prepare_enum_equivalence(style_attributes: &Vec<StyleAttribute>) -> proc_macr
             fn logical_eq(&self, other: &InteractiveStyleAttribute) -> bool {
                 match (self, other) {
                     #(#static_to_interactive)*
+                    (Self::Custom(l0), InteractiveStyleAttribute::Custom(r0)) => {
+                        l0.declared_key().is_some() && l0.declared_key() == r0.declared_key()
+                    }
                     _ => false,
                 }
             }
@@ -480,6 +731,9 @@ fn prepare_enum_equivalence(style_attributes: &Vec<StyleAttribute>) -> proc_macr
             fn logical_eq(&self, other: &InteractiveStyleAttribute) -> bool {
                 match (self, other) {
                     #(#animated_to_interactive)*
+                    (Self::Custom(l0), InteractiveStyleAttribute::Custom(r0)) => {
+                        l0.declared_key().is_some() && l0.declared_key() == r0.declared_key()
+                    }
                     _ => false,
                 }
             }
@@ -488,6 +742,9 @@ fn prepare_enum_equivalence(style_attributes: &Vec<StyleAttribute>) -> proc_macr
             fn logical_eq(&self, other: &AnimatedStyleAttribute) -> bool {
                 match (self, other) {
                     #(#interactive_to_animated)*
+                    (Self::Custom(l0), AnimatedStyleAttribute::Custom(r0)) => {
+                        l0.declared_key().is_some() && l0.declared_key() == r0.declared_key()
+                    }
                     _ => false,
                 }
             }
@@ -496,6 +753,9 @@ fn prepare_enum_equivalence(style_attributes: &Vec<StyleAttribute>) -> proc_macr
             fn logical_eq(&self, other: &StaticStyleAttribute) -> bool {
                 match (self, other) {
                     #(#animated_to_static)*
+                    (Self::Custom(l0), StaticStyleAttribute::Custom(r0)) => {
+                        l0.declared_key().is_some() && l0.declared_key() == r0.declared_key()
+                    }
                     _ => false,
                 }
             }
@@ -504,6 +764,75 @@ fn prepare_enum_equivalence(style_attributes: &Vec<StyleAttribute>) -> proc_macr
             fn logical_eq(&self, other: &AnimatedStyleAttribute) -> bool {
                 match (self, other) {
                     #(#static_to_animated)*
+                    (Self::Custom(l0), AnimatedStyleAttribute::Custom(r0)) => {
+                        l0.declared_key().is_some() && l0.declared_key() == r0.declared_key()
+                    }
+                    _ => false,
+                }
+            }
+        }
+        impl LogicalEq<StaticStyleAttribute> for KeyframesStyleAttribute {
+            fn logical_eq(&self, other: &StaticStyleAttribute) -> bool {
+                match (self, other) {
+                    #(#keyframes_to_static)*
+                    (Self::Custom(l0), StaticStyleAttribute::Custom(r0)) => {
+                        l0.declared_key().is_some() && l0.declared_key() == r0.declared_key()
+                    }
+                    _ => false,
+                }
+            }
+        }
+        impl LogicalEq<KeyframesStyleAttribute> for StaticStyleAttribute {
+            fn logical_eq(&self, other: &KeyframesStyleAttribute) -> bool {
+                match (self, other) {
+                    #(#static_to_keyframes)*
+                    (Self::Custom(l0), KeyframesStyleAttribute::Custom(r0)) => {
+                        l0.declared_key().is_some() && l0.declared_key() == r0.declared_key()
+                    }
+                    _ => false,
+                }
+            }
+        }
+        impl LogicalEq<InteractiveStyleAttribute> for KeyframesStyleAttribute {
+            fn logical_eq(&self, other: &InteractiveStyleAttribute) -> bool {
+                match (self, other) {
+                    #(#keyframes_to_interactive)*
+                    (Self::Custom(l0), InteractiveStyleAttribute::Custom(r0)) => {
+                        l0.declared_key().is_some() && l0.declared_key() == r0.declared_key()
+                    }
+                    _ => false,
+                }
+            }
+        }
+        impl LogicalEq<KeyframesStyleAttribute> for InteractiveStyleAttribute {
+            fn logical_eq(&self, other: &KeyframesStyleAttribute) -> bool {
+                match (self, other) {
+                    #(#interactive_to_keyframes)*
+                    (Self::Custom(l0), KeyframesStyleAttribute::Custom(r0)) => {
+                        l0.declared_key().is_some() && l0.declared_key() == r0.declared_key()
+                    }
+                    _ => false,
+                }
+            }
+        }
+        impl LogicalEq<AnimatedStyleAttribute> for KeyframesStyleAttribute {
+            fn logical_eq(&self, other: &AnimatedStyleAttribute) -> bool {
+                match (self, other) {
+                    #(#keyframes_to_animated)*
+                    (Self::Custom(l0), AnimatedStyleAttribute::Custom(r0)) => {
+                        l0.declared_key().is_some() && l0.declared_key() == r0.declared_key()
+                    }
+                    _ => false,
+                }
+            }
+        }
+        impl LogicalEq<KeyframesStyleAttribute> for AnimatedStyleAttribute {
+            fn logical_eq(&self, other: &KeyframesStyleAttribute) -> bool {
+                match (self, other) {
+                    #(#animated_to_keyframes)*
+                    (Self::Custom(l0), KeyframesStyleAttribute::Custom(r0)) => {
+                        l0.declared_key().is_some() && l0.declared_key() == r0.declared_key()
+                    }
                     _ => false,
                 }
             }
@@ -558,6 +887,13 @@ fn to_eq_animated_variant(style_attribute: &StyleAttribute) -> proc_macro2::Toke
     }
 }
 
+fn to_eq_keyframes_variant(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
+    let ident = &style_attribute.ident;
+    quote! {
+        (Self::#ident(_), KeyframesStyleAttribute::#ident(_)) => true,
+    }
+}
+
 fn to_base_attribute_variant(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
     let ident = &style_attribute.ident;
     quote! {
@@ -565,6 +901,13 @@ fn to_base_attribute_variant(style_attribute: &StyleAttribute) -> proc_macro2::T
     }
 }
 
+fn to_lockable_variant(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
+    let ident = &style_attribute.ident;
+    quote! {
+        Self::#ident(_) => Some(LockableStyleAttribute::#ident),
+    }
+}
+
 fn to_static_style_variant(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
     let ident = &style_attribute.ident;
     let type_path = &style_attribute.type_path;
@@ -589,6 +932,14 @@ fn to_animated_style_variant(style_attribute: &StyleAttribute) -> proc_macro2::T
     }
 }
 
+fn to_keyframes_style_variant(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
+    let ident = &style_attribute.ident;
+    let type_path = &style_attribute.type_path;
+    quote! {
+        #ident(KeyframeVals<#type_path>),
+    }
+}
+
 fn to_static_style_apply_variant(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
     let ident = &style_attribute.ident;
     let command = &style_attribute.command;
@@ -617,6 +968,22 @@ fn to_animated_style_appl_variant(style_attribute: &StyleAttribute) -> proc_macr
     }
 }
 
+fn to_keyframes_style_appl_variant(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
+    let ident = &style_attribute.ident;
+    quote! {
+        Self::#ident(keyframes) => StaticStyleAttribute::#ident(keyframes.sample(playhead)),
+    }
+}
+
+fn to_keyframes_style_duration_variant(
+    style_attribute: &StyleAttribute,
+) -> proc_macro2::TokenStream {
+    let ident = &style_attribute.ident;
+    quote! {
+        Self::#ident(keyframes) => keyframes.duration(),
+    }
+}
+
 fn to_static_style_builder_fn(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
     let ident = &style_attribute.ident;
     let type_path = &style_attribute.type_path;
@@ -666,6 +1033,25 @@ fn to_animated_style_builder_fn(style_attribute: &StyleAttribute) -> proc_macro2
     }
 }
 
+fn to_keyframes_style_builder_fn(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
+    let ident = &style_attribute.ident;
+    let type_path = &style_attribute.type_path;
+    let command = &style_attribute.command;
+    quote! {
+        pub fn #command(
+            &mut self,
+            keyframes: impl Into<KeyframeVals<#type_path>>,
+        ) -> &mut KeyframeSettings {
+            let attribute = DynamicStyleAttribute::Keyframes {
+                attribute: KeyframesStyleAttribute::#ident(keyframes.into()),
+                controller: KeyframesController::default(),
+            };
+
+            self.add_and_extract_settings(attribute)
+        }
+    }
+}
+
 fn to_ui_style_extensions(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
     let cmd_struct_name = &style_attribute.cmd_struct_name.clone();
     let cmd_struct_ident = &style_attribute.cmd_struct_ident.clone();
@@ -755,7 +1141,34 @@ fn to_setter_entity_command_frag(style_attribute: &StyleAttribute) -> proc_macro
     let target_type = &style_attribute.type_path;
     let target_attr_name = &style_attribute.target_attr_name;
 
-    if style_attribute.target_enum {
+    if let Some(target_fn) = &style_attribute.target_fn {
+        quote! {
+            #target_fn(entity, self.#target_attr, world);
+        }
+    } else if let Some((component_type, field)) = &style_attribute.target_component {
+        let component_name: Vec<String> = component_type
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect();
+        let component_name = component_name.join("::");
+
+        quote! {
+            let Some(mut #target_attr) = world.get_mut::<#component_type>(entity) else {
+                warn!(
+                    "Failed to set {} property on entity {:?}: No {} component found!",
+                    #target_attr_name,
+                    entity,
+                    #component_name,
+                );
+                return;
+            };
+
+            if #target_attr.#field != self.#target_attr {
+                #target_attr.#field = self.#target_attr;
+            }
+        }
+    } else if style_attribute.target_enum {
         let target_type_name = target_type.path.get_ident().unwrap().to_string();
 
         quote! {
@@ -814,3 +1227,260 @@ fn to_setter_entity_command_frag(style_attribute: &StyleAttribute) -> proc_macro
         }
     }
 }
+
+fn prepare_custom_style_attributes(
+    name_ident: &Ident,
+    style_attributes: &Vec<StyleAttribute>,
+) -> proc_macro2::TokenStream {
+    let name = name_ident.to_string();
+    let name = name.trim_start_matches('_');
+    let static_ext = Ident::new(format!("{}Ext", name).as_str(), name_ident.span());
+    let interactive_ext = Ident::new(
+        format!("{}InteractiveExt", name).as_str(),
+        name_ident.span(),
+    );
+    let animated_ext = Ident::new(format!("{}AnimatedExt", name).as_str(), name_ident.span());
+    let keyframes_ext = Ident::new(format!("{}KeyframesExt", name).as_str(), name_ident.span());
+
+    let static_attributes = style_attributes.iter();
+    let static_signatures: Vec<proc_macro2::TokenStream> = static_attributes
+        .clone()
+        .map(to_custom_static_signature)
+        .collect();
+    let static_fns: Vec<proc_macro2::TokenStream> =
+        static_attributes.map(to_custom_static_builder_fn).collect();
+
+    let interactive_attributes = style_attributes.iter().filter(|v| !v.static_style_only);
+    let interactive_signatures: Vec<proc_macro2::TokenStream> = interactive_attributes
+        .clone()
+        .map(to_custom_interactive_signature)
+        .collect();
+    let interactive_fns: Vec<proc_macro2::TokenStream> = interactive_attributes
+        .map(to_custom_interactive_builder_fn)
+        .collect();
+
+    let animated_attributes = style_attributes.iter().filter(|v| v.animatable);
+    let animated_signatures: Vec<proc_macro2::TokenStream> = animated_attributes
+        .clone()
+        .map(to_custom_animated_signature)
+        .collect();
+    let animated_fns: Vec<proc_macro2::TokenStream> = animated_attributes
+        .clone()
+        .map(to_custom_animated_builder_fn)
+        .collect();
+    let keyframes_signatures: Vec<proc_macro2::TokenStream> = animated_attributes
+        .clone()
+        .map(to_custom_keyframes_signature)
+        .collect();
+    let keyframes_fns: Vec<proc_macro2::TokenStream> = animated_attributes
+        .map(to_custom_keyframes_builder_fn)
+        .collect();
+
+    let interactive = match interactive_fns.is_empty() {
+        true => proc_macro2::TokenStream::new(),
+        false => quote! {
+            pub trait #interactive_ext {
+                #(#interactive_signatures)*
+            }
+
+            impl #interactive_ext for InteractiveStyleBuilder<'_> {
+                #(#interactive_fns)*
+            }
+        },
+    };
+    let animated = match animated_fns.is_empty() {
+        true => proc_macro2::TokenStream::new(),
+        false => quote! {
+            pub trait #animated_ext {
+                #(#animated_signatures)*
+            }
+
+            impl #animated_ext for AnimatedStyleBuilder<'_> {
+                #(#animated_fns)*
+            }
+
+            pub trait #keyframes_ext {
+                #(#keyframes_signatures)*
+            }
+
+            impl #keyframes_ext for KeyframesStyleBuilder<'_> {
+                #(#keyframes_fns)*
+            }
+        },
+    };
+    let style_commands = prepare_style_commands(style_attributes);
+
+    quote! {
+        pub trait #static_ext {
+            #(#static_signatures)*
+        }
+
+        impl #static_ext for StyleBuilder {
+            #(#static_fns)*
+        }
+
+        #interactive
+        #animated
+        #style_commands
+    }
+}
+
+fn to_custom_static_signature(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
+    let type_path = &style_attribute.type_path;
+    let command = &style_attribute.command;
+    quote! {
+        fn #command(&mut self, #command: impl Into<#type_path>) -> &mut Self;
+    }
+}
+
+fn to_custom_interactive_signature(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
+    let type_path = &style_attribute.type_path;
+    let command = &style_attribute.command;
+    quote! {
+        fn #command(&mut self, bundle: impl Into<InteractiveVals<#type_path>>) -> &mut Self;
+    }
+}
+
+fn to_custom_animated_signature(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
+    let type_path = &style_attribute.type_path;
+    let command = &style_attribute.command;
+    quote! {
+        fn #command(
+            &mut self,
+            bundle: impl Into<AnimatedVals<#type_path>>,
+        ) -> &mut AnimationSettings;
+    }
+}
+
+fn to_custom_keyframes_signature(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
+    let type_path = &style_attribute.type_path;
+    let command = &style_attribute.command;
+    quote! {
+        fn #command(
+            &mut self,
+            keyframes: impl Into<KeyframeVals<#type_path>>,
+        ) -> &mut KeyframeSettings;
+    }
+}
+
+fn to_custom_static_builder_fn(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
+    let type_path = &style_attribute.type_path;
+    let command = &style_attribute.command;
+    let cmd_struct_ident = &style_attribute.cmd_struct_ident;
+    quote! {
+        fn #command(&mut self, #command: impl Into<#type_path>) -> &mut Self {
+            let #command: #type_path = #command.into();
+            let declared = DeclaredStyleAttribute::new::<#cmd_struct_ident, _>(#command.clone());
+            self.add(DynamicStyleAttribute::Static(StaticStyleAttribute::Custom(
+                CustomStaticStyleAttribute::declared(declared, move |entity, world| {
+                    EntityCommand::apply(
+                        #cmd_struct_ident {
+                            #command: #command.clone(),
+                            check_lock: true,
+                        },
+                        entity,
+                        world,
+                    );
+                }),
+            )));
+
+            self
+        }
+    }
+}
+
+fn to_custom_interactive_builder_fn(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
+    let type_path = &style_attribute.type_path;
+    let command = &style_attribute.command;
+    let cmd_struct_ident = &style_attribute.cmd_struct_ident;
+    quote! {
+        fn #command(&mut self, bundle: impl Into<InteractiveVals<#type_path>>) -> &mut Self {
+            let bundle: InteractiveVals<#type_path> = bundle.into();
+            let declared = DeclaredStyleAttribute::new::<#cmd_struct_ident, _>(bundle.clone());
+            self.style_builder.add(DynamicStyleAttribute::Interactive(
+                InteractiveStyleAttribute::Custom(CustomInteractiveStyleAttribute::declared(
+                    declared,
+                    move |entity, flux_interaction, world| {
+                        EntityCommand::apply(
+                            #cmd_struct_ident {
+                                #command: bundle.to_value(flux_interaction),
+                                check_lock: true,
+                            },
+                            entity,
+                            world,
+                        );
+                    },
+                )),
+            ));
+
+            self
+        }
+    }
+}
+
+fn to_custom_animated_builder_fn(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
+    let type_path = &style_attribute.type_path;
+    let command = &style_attribute.command;
+    let cmd_struct_ident = &style_attribute.cmd_struct_ident;
+    quote! {
+        fn #command(
+            &mut self,
+            bundle: impl Into<AnimatedVals<#type_path>>,
+        ) -> &mut AnimationSettings {
+            let bundle: AnimatedVals<#type_path> = bundle.into();
+            let declared = DeclaredStyleAttribute::new::<#cmd_struct_ident, _>(bundle.clone());
+            let attribute = DynamicStyleAttribute::Animated {
+                attribute: AnimatedStyleAttribute::Custom(CustomAnimatedStyleAttribute::declared(
+                    declared,
+                    move |entity, current_state, world| {
+                        EntityCommand::apply(
+                            #cmd_struct_ident {
+                                #command: bundle.to_value(&current_state),
+                                check_lock: true,
+                            },
+                            entity,
+                            world,
+                        );
+                    },
+                )),
+                controller: DynamicStyleController::default(),
+            };
+
+            self.add_and_extract_animation(attribute)
+        }
+    }
+}
+
+fn to_custom_keyframes_builder_fn(style_attribute: &StyleAttribute) -> proc_macro2::TokenStream {
+    let type_path = &style_attribute.type_path;
+    let command = &style_attribute.command;
+    let cmd_struct_ident = &style_attribute.cmd_struct_ident;
+    quote! {
+        fn #command(
+            &mut self,
+            keyframes: impl Into<KeyframeVals<#type_path>>,
+        ) -> &mut KeyframeSettings {
+            let keyframes: KeyframeVals<#type_path> = keyframes.into();
+            let declared = DeclaredStyleAttribute::new::<#cmd_struct_ident, _>(keyframes.clone());
+            let attribute = DynamicStyleAttribute::Keyframes {
+                attribute: KeyframesStyleAttribute::Custom(CustomKeyframesStyleAttribute::declared(
+                    declared,
+                    keyframes.duration(),
+                    move |entity, playhead, world| {
+                        EntityCommand::apply(
+                            #cmd_struct_ident {
+                                #command: keyframes.sample(playhead),
+                                check_lock: true,
+                            },
+                            entity,
+                            world,
+                        );
+                    },
+                )),
+                controller: KeyframesController::default(),
+            };
+
+            self.add_and_extract_settings(attribute)
+        }
+    }
+}
@@ -1,10 +1,225 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use proc_macro2::{Ident, Span};
+use quote::{quote, quote_spanned};
+use syn::{parse::ParseStream, spanned::Spanned, AttrStyle, Data, Field, LitStr, Token};
+
+#[derive(Clone, Copy, Debug)]
+enum ParseError {
+    InvalidAttribute,
+    MissingName,
+    DuplicateName,
+    DuplicateConst,
+    UnnamedField,
+}
+
+struct ContextField {
+    ident: Ident,
+    const_ident: Ident,
+    name: LitStr,
+    no_clear: bool,
+}
 
 pub(crate) fn derive_ui_context_macro(ast: &syn::DeriveInput) -> TokenStream {
     let name_ident = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = ast.generics.split_for_impl();
+
+    let context_fields = match parse_context_fields(&ast.data) {
+        Ok(context_fields) => context_fields,
+        Err((span, error)) => return match_error(span, error).into(),
+    };
+
+    if context_fields.is_empty() {
+        return quote! {
+            impl #impl_generics UiContext for #name_ident #type_generics #where_clause { }
+        }
+        .into();
+    }
+
+    let type_name = name_ident.to_string();
+    let constants = context_fields.iter().map(|field| {
+        let const_ident = &field.const_ident;
+        let name = &field.name;
+        quote! {
+            pub const #const_ident: &'static str = #name;
+        }
+    });
+    let get_arms = context_fields.iter().map(|field| {
+        let ident = &field.ident;
+        let const_ident = &field.const_ident;
+        quote! {
+            #name_ident::#const_ident => Ok(self.#ident),
+        }
+    });
+    let contexts = context_fields.iter().map(|field| {
+        let const_ident = &field.const_ident;
+        quote! { #name_ident::#const_ident }
+    });
+    let cleared_contexts = context_fields
+        .iter()
+        .filter(|field| !field.no_clear)
+        .map(|field| {
+            let const_ident = &field.const_ident;
+            quote! { #name_ident::#const_ident }
+        });
+
     quote! {
-        impl UiContext for #name_ident { }
+        impl #impl_generics #name_ident #type_generics #where_clause {
+            #(#constants)*
+        }
+
+        impl #impl_generics UiContext for #name_ident #type_generics #where_clause {
+            fn get(&self, target: &str) -> Result<Entity, String> {
+                match target {
+                    #(#get_arms)*
+                    _ => Err(format!(
+                        "{} doesn't exists for {}. Possible contexts: {:?}",
+                        target,
+                        #type_name,
+                        self.contexts()
+                    )),
+                }
+            }
+
+            fn cleared_contexts(&self) -> Vec<&'static str> {
+                vec![#(#cleared_contexts),*]
+            }
+
+            fn contexts(&self) -> Vec<&'static str> {
+                vec![#(#contexts),*]
+            }
+        }
     }
     .into()
 }
+
+fn match_error(span: Span, error: ParseError) -> proc_macro2::TokenStream {
+    match error {
+        ParseError::InvalidAttribute => {
+            quote_spanned! {
+                span => compile_error!("Unsupported context value. Must be defined as #[context(\"Name\")], optionally followed by `no_clear` and `const = NAME`");
+            }
+        }
+        ParseError::MissingName => {
+            quote_spanned! {
+                span => compile_error!("Missing context name. Must be defined as #[context(\"Name\")]");
+            }
+        }
+        ParseError::DuplicateName => {
+            quote_spanned! {
+                span => compile_error!("Context name already defined for this field");
+            }
+        }
+        ParseError::DuplicateConst => {
+            quote_spanned! {
+                span => compile_error!("Context constant already defined for this field");
+            }
+        }
+        ParseError::UnnamedField => {
+            quote_spanned! {
+                span => compile_error!("Contexts can only be defined on named fields");
+            }
+        }
+    }
+}
+
+fn parse_context_fields(data: &Data) -> Result<Vec<ContextField>, (Span, ParseError)> {
+    let Data::Struct(struct_data) = data else {
+        return Ok(Vec::new());
+    };
+
+    let mut context_fields = Vec::new();
+    for field in &struct_data.fields {
+        if let Some(context_field) = parse_context_field(field)? {
+            context_fields.push(context_field);
+        }
+    }
+
+    Ok(context_fields)
+}
+
+fn parse_context_field(field: &Field) -> Result<Option<ContextField>, (Span, ParseError)> {
+    let mut name: Option<LitStr> = None;
+    let mut const_ident: Option<Ident> = None;
+    let mut no_clear = false;
+    let mut attr_span: Option<Span> = None;
+
+    for attr in &field.attrs {
+        if attr.style != AttrStyle::Outer || !attr.path().is_ident("context") {
+            continue;
+        }
+
+        attr_span = attr_span.or(Some(attr.span()));
+        let args = attr
+            .parse_args_with(parse_context_args)
+            .map_err(|_| (attr.span(), ParseError::InvalidAttribute))?;
+
+        if let Some(attr_name) = args.name {
+            if name.is_some() {
+                return Err((attr_name.span(), ParseError::DuplicateName));
+            }
+            name = Some(attr_name);
+        }
+        if let Some(attr_const) = args.const_ident {
+            if const_ident.is_some() {
+                return Err((attr_const.span(), ParseError::DuplicateConst));
+            }
+            const_ident = Some(attr_const);
+        }
+        no_clear |= args.no_clear;
+    }
+
+    let Some(attr_span) = attr_span else {
+        return Ok(None);
+    };
+    let Some(name) = name else {
+        return Err((attr_span, ParseError::MissingName));
+    };
+    let Some(ident) = field.ident.clone() else {
+        return Err((field.span(), ParseError::UnnamedField));
+    };
+
+    let const_ident = const_ident.unwrap_or_else(|| {
+        Ident::new(ident.to_string().to_uppercase().as_str(), ident.span())
+    });
+
+    Ok(Some(ContextField {
+        ident,
+        const_ident,
+        name,
+        no_clear,
+    }))
+}
+
+#[derive(Default)]
+struct ContextArgs {
+    name: Option<LitStr>,
+    const_ident: Option<Ident>,
+    no_clear: bool,
+}
+
+/// Any of `"Name"`, `no_clear` and `const = NAME`, separated by commas
+fn parse_context_args(input: ParseStream) -> syn::Result<ContextArgs> {
+    let mut args = ContextArgs::default();
+
+    while !input.is_empty() {
+        if input.peek(LitStr) && args.name.is_none() {
+            args.name = Some(input.parse()?);
+        } else if input.peek(Token![const]) && args.const_ident.is_none() {
+            input.parse::<Token![const]>()?;
+            input.parse::<Token![=]>()?;
+            args.const_ident = Some(input.parse()?);
+        } else {
+            let flag: Ident = input.parse()?;
+            if flag != "no_clear" || args.no_clear {
+                return Err(syn::Error::new(flag.span(), "unexpected context flag"));
+            }
+            args.no_clear = true;
+        }
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok(args)
+}
@@ -0,0 +1,208 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    braced, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token::Paren,
+    AngleBracketedGenericArguments, Expr, Ident, Token,
+};
+
+/// `builder, node*`
+struct UiMarkup {
+    builder: Expr,
+    nodes: Vec<UiNode>,
+}
+
+/// `widget(args)? { node* }? (.method::<T>?(args))*`
+struct UiNode {
+    widget: Ident,
+    paren: Option<Paren>,
+    args: Punctuated<Expr, Token![,]>,
+    children: Option<Vec<UiNode>>,
+    calls: Vec<UiMethodCall>,
+}
+
+struct UiMethodCall {
+    method: Ident,
+    turbofish: Option<AngleBracketedGenericArguments>,
+    args: Punctuated<Expr, Token![,]>,
+}
+
+impl Parse for UiMarkup {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let builder: Expr = input.parse()?;
+        if !input.is_empty() {
+            input
+                .parse::<Token![,]>()
+                .map_err(|err| syn::Error::new(err.span(), "expected `,` after the UI builder"))?;
+        }
+
+        let nodes = parse_nodes(input)?;
+
+        Ok(Self { builder, nodes })
+    }
+}
+
+fn parse_nodes(input: ParseStream) -> syn::Result<Vec<UiNode>> {
+    let mut nodes = Vec::new();
+    while !input.is_empty() {
+        if input.peek(Token![,]) {
+            return Err(input.error("unexpected `,`: widgets are separated by whitespace"));
+        }
+        if !input.peek(Ident) {
+            return Err(input.error(
+                "expected a widget, e.g. `column { .. }` or `label(\"text\")`; \
+                method calls must directly follow their widget",
+            ));
+        }
+
+        nodes.push(input.parse()?);
+    }
+
+    Ok(nodes)
+}
+
+impl Parse for UiNode {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let widget: Ident = input.parse()?;
+
+        let (paren, args) = match input.peek(Paren) {
+            true => {
+                let content;
+                let paren = parenthesized!(content in input);
+                (Some(paren), Punctuated::parse_terminated(&content)?)
+            }
+            false => (None, Punctuated::new()),
+        };
+
+        let children = match input.peek(syn::token::Brace) {
+            true => {
+                let content;
+                braced!(content in input);
+                Some(parse_nodes(&content)?)
+            }
+            false => None,
+        };
+
+        let mut calls = Vec::new();
+        while input.peek(Token![.]) {
+            input.parse::<Token![.]>()?;
+            calls.push(input.parse()?);
+        }
+
+        Ok(Self {
+            widget,
+            paren,
+            args,
+            children,
+            calls,
+        })
+    }
+}
+
+impl Parse for UiMethodCall {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let method: Ident = input.parse()?;
+        let turbofish = match input.peek(Token![::]) {
+            true => Some(AngleBracketedGenericArguments::parse_turbofish(input)?),
+            false => None,
+        };
+
+        if !input.peek(Paren) {
+            return Err(syn::Error::new(
+                method.span(),
+                format!(
+                    "expected `(` after `.{}`: only method calls can follow a widget",
+                    method
+                ),
+            ));
+        }
+
+        let content;
+        parenthesized!(content in input);
+        let args = Punctuated::parse_terminated(&content)?;
+
+        Ok(Self {
+            method,
+            turbofish,
+            args,
+        })
+    }
+}
+
+pub(crate) fn expand_ui_macro(input: TokenStream) -> TokenStream {
+    let markup = syn::parse_macro_input!(input as UiMarkup);
+    // Not nameable from the markup, so arguments can refer to any outer variable
+    let ui = format_ident!("__ui", span = Span::mixed_site());
+    let builder = &markup.builder;
+    // Widgets are called on a variable the same way as in code, so both `UiBuilder` values
+    // and references work. Other expressions are evaluated once.
+    let (binding, receiver) = match builder {
+        Expr::Path(_) => (quote! {}, quote! { #builder }),
+        _ => (
+            quote! {
+                #[allow(unused_mut)]
+                let mut #ui = #builder;
+            },
+            quote! { #ui },
+        ),
+    };
+
+    let nodes = markup
+        .nodes
+        .iter()
+        .map(|node| expand_node(node, &receiver, &ui));
+
+    quote! {
+        {
+            #binding
+            #(#nodes)*
+        }
+    }
+    .into()
+}
+
+fn expand_node(
+    node: &UiNode,
+    receiver: &proc_macro2::TokenStream,
+    ui: &Ident,
+) -> proc_macro2::TokenStream {
+    // The widget keeps its span, so unknown widgets are reported on the markup by rustc,
+    // including its suggestions for similarly named methods and missing trait imports.
+    let widget = &node.widget;
+    let mut args: Vec<proc_macro2::TokenStream> =
+        node.args.iter().map(|arg| quote! { #arg }).collect();
+
+    if let Some(children) = &node.children {
+        let children = children.iter().map(|child| {
+            // Located at the child, so errors don't point at the whole macro call
+            let receiver = Ident::new("__ui", ui.span().located_at(child.widget.span()));
+            expand_node(child, &quote! { #receiver }, ui)
+        });
+        args.push(quote! {
+            |#ui| {
+                #(#children)*
+            }
+        });
+    }
+
+    let calls = node.calls.iter().map(|call| {
+        let method = &call.method;
+        let turbofish = &call.turbofish;
+        let args = &call.args;
+        quote! { .#method #turbofish (#args) }
+    });
+
+    // Argument errors are reported within the parentheses of the markup
+    let mut call = quote_spanned! { widget.span() => #receiver.#widget };
+    match &node.paren {
+        Some(paren) => paren.surround(&mut call, |tokens| tokens.extend(quote! { #(#args),* })),
+        None => call.extend(quote_spanned! { widget.span() => (#(#args),*) }),
+    }
+
+    quote! {
+        #call #(#calls)*;
+    }
+}
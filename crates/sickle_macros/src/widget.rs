@@ -0,0 +1,116 @@
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{AttrStyle, DeriveInput};
+
+struct WidgetAttributes {
+    method: Ident,
+    children: bool,
+}
+
+pub(crate) fn derive_widget_macro(ast: &DeriveInput) -> TokenStream {
+    let name_ident = &ast.ident;
+    if !ast.generics.params.is_empty() {
+        return quote_spanned! {
+            name_ident.span() => compile_error!("Widget cannot be derived for generic types");
+        }
+        .into();
+    }
+
+    let attributes = match parse_widget_attributes(ast) {
+        Ok(attributes) => attributes,
+        Err(error) => return error.into_compile_error().into(),
+    };
+
+    let vis = &ast.vis;
+    let ext_ident = format_ident!("Ui{}Ext", name_ident);
+    let method = &attributes.method;
+    let doc = format!("Spawns a [`{}`] widget.", name_ident);
+
+    let (params, spawn_children) = match attributes.children {
+        true => (
+            quote! { widget: impl Into<#name_ident>, spawn_children: impl FnOnce(&mut UiBuilder<Entity>) },
+            quote! { spawn_children },
+        ),
+        false => (quote! { widget: impl Into<#name_ident> }, quote! { |_| {} }),
+    };
+
+    // Reported on the widget instead of the generated trait
+    let assertions = quote_spanned! {
+        name_ident.span() => const _: fn() = || {
+            fn assert_widget_builder<W: WidgetBuilder>() {}
+            assert_widget_builder::<#name_ident>();
+        };
+    };
+
+    quote! {
+        #assertions
+
+        #vis trait #ext_ident {
+            #[doc = #doc]
+            fn #method(&mut self, #params) -> UiBuilder<'_, Entity>;
+        }
+
+        impl #ext_ident for UiBuilder<'_, Entity> {
+            fn #method(&mut self, #params) -> UiBuilder<'_, Entity> {
+                UiWidgetBuilderExt::widget(self, widget.into(), #spawn_children)
+            }
+        }
+    }
+    .into()
+}
+
+/// `#[widget(method = name, children)]`, both optional
+fn parse_widget_attributes(ast: &DeriveInput) -> syn::Result<WidgetAttributes> {
+    let mut attributes = WidgetAttributes {
+        method: Ident::new(
+            to_snake_case(&ast.ident.to_string()).as_str(),
+            ast.ident.span(),
+        ),
+        children: false,
+    };
+
+    for attr in &ast.attrs {
+        if attr.style != AttrStyle::Outer || !attr.path().is_ident("widget") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("method") {
+                attributes.method = meta.value()?.parse()?;
+                Ok(())
+            } else if meta.path.is_ident("children") {
+                attributes.children = true;
+                Ok(())
+            } else {
+                Err(meta
+                    .error("unsupported widget attribute, expected `method = ..` or `children`"))
+            }
+        })?;
+    }
+
+    Ok(attributes)
+}
+
+/// `UIButton` -> `ui_button`: acronyms are kept together and split before the next word
+fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake_case = String::with_capacity(name.len() + 4);
+    for (i, char) in chars.iter().enumerate() {
+        if char.is_uppercase() {
+            let after_lowercase =
+                i > 0 && (chars[i - 1].is_lowercase() || chars[i - 1].is_numeric());
+            let ends_acronym = i > 0
+                && chars[i - 1].is_uppercase()
+                && chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if after_lowercase || ends_acronym {
+                snake_case.push('_');
+            }
+            snake_case.extend(char.to_lowercase());
+        } else {
+            snake_case.push(*char);
+        }
+    }
+
+    snake_case
+}
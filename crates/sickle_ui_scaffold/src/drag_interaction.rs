@@ -1,9 +1,16 @@
+use bevy::ecs::system::Command;
 use bevy::prelude::*;
-use bevy::ui::RelativeCursorPosition;
+use bevy::ui::{FocusPolicy, RelativeCursorPosition};
 use bevy::window::{CursorGrabMode, PrimaryWindow};
 use bevy_reflect::Reflect;
 
-use crate::flux_interaction::{FluxInteraction, FluxInteractionUpdate};
+use crate::{
+    flux_interaction::{FluxInteraction, FluxInteractionUpdate},
+    ui_builder::{UiBuilder, UiBuilderExt},
+    ui_style::{manual::SetAbsolutePositionExt, UiStyleExt},
+};
+
+const DRAG_GHOST_Z_INDEX: i32 = 100000;
 
 pub struct DragInteractionPlugin;
 
@@ -15,6 +22,8 @@ impl Plugin for DragInteractionPlugin {
                 (
                     update_drag_progress,
                     update_drag_state,
+                    update_drag_ghosts,
+                    cleanup_orphaned_drag_ghosts,
                     update_cursor_confinement_from_drag.run_if(is_windows_os),
                 )
                     .chain()
@@ -120,9 +129,11 @@ fn update_drag_progress(
                 continue;
             }
 
-            // Drag start is only a single frame, triggered after initial movement
+            // Drag start is only a single frame, triggered after initial movement.
+            // Its diff is already consumed, it must not be reported again with the state change.
             if draggable.state == DragState::DragStart {
                 draggable.state = DragState::Dragging;
+                draggable.diff = Vec2::ZERO.into();
             }
 
             let new_position: Option<Vec2> = match draggable.source {
@@ -175,22 +186,21 @@ fn update_drag_state(
         if *flux_interaction == FluxInteraction::Pressed
             && draggable.state != DragState::MaybeDragged
         {
-            let mut drag_source = DragSource::Mouse;
-
-            // No window method: Cursor is at the Node's top left screenspace rect.min,
-            // plus the relative screenspace position in the Node, which is relcurpos times the node rect's size
-            let mut initial_position;
-            if let Some(relative_cursor_pos) = relcurpos.normalized {
+            // Touches are checked first: without a cursor, the relative cursor position
+            // is derived from the first touch as well
+            let (drag_source, initial_position) = if let Some(touch) = r_touches.iter().next() {
+                (DragSource::Touch(touch.id()), touch.position().into())
+            } else if let Some(relative_cursor_pos) = relcurpos.normalized {
+                // No window method: Cursor is at the Node's top left screenspace rect.min,
+                // plus the relative screenspace position in the Node, which is relcurpos times the node rect's size
                 let node_rect = node.logical_rect(global_trans);
-                initial_position = Some(node_rect.min + (node_rect.size() * relative_cursor_pos))
+                (
+                    DragSource::Mouse,
+                    Some(node_rect.min + (node_rect.size() * relative_cursor_pos)),
+                )
             } else {
-                initial_position = None;
-            }
-
-            if initial_position.is_none() {
-                initial_position = r_touches.first_pressed_position();
-                drag_source = DragSource::Touch(r_touches.iter().next().unwrap().id());
-            }
+                (DragSource::Mouse, None)
+            };
 
             draggable.state = DragState::MaybeDragged;
             draggable.source = drag_source;
@@ -202,6 +212,7 @@ fn update_drag_state(
         {
             if draggable.state == DragState::DragStart || draggable.state == DragState::Dragging {
                 draggable.state = DragState::DragEnd;
+                draggable.diff = Vec2::ZERO.into();
             } else if draggable.state == DragState::MaybeDragged {
                 draggable.state = DragState::Inactive;
                 draggable.clear();
@@ -209,3 +220,278 @@ fn update_drag_state(
         }
     }
 }
+
+fn update_drag_ghosts(
+    mut q_draggable: Query<
+        (Entity, &Draggable, &mut DragGhost, &Node, &GlobalTransform),
+        Changed<Draggable>,
+    >,
+    mut commands: Commands,
+) {
+    for (entity, draggable, mut drag_ghost, node, transform) in &mut q_draggable {
+        match draggable.state {
+            DragState::DragStart => {
+                if let Some(ghost) = drag_ghost.ghost.take() {
+                    commands.entity(ghost).despawn_recursive();
+                }
+
+                let node_rect = node.logical_rect(transform);
+                let origin = draggable.origin.unwrap_or(node_rect.min);
+                drag_ghost.grab_offset = origin - node_rect.min;
+                let position = draggable.position.unwrap_or(origin) - drag_ghost.grab_offset;
+
+                let ghost = commands.spawn(DragGhost::frame(entity, position)).id();
+                match drag_ghost.content {
+                    DragGhostContent::TranslucentClone => commands.add(SpawnDragGhostClone {
+                        source: entity,
+                        ghost,
+                        opacity: drag_ghost.opacity,
+                    }),
+                    DragGhostContent::Custom(builder) => {
+                        builder(&mut commands.ui_builder(ghost), entity);
+                    }
+                }
+
+                drag_ghost.ghost = ghost.into();
+            }
+            DragState::Dragging => {
+                let (Some(ghost), Some(position)) = (drag_ghost.ghost, draggable.position) else {
+                    continue;
+                };
+
+                commands
+                    .style(ghost)
+                    .absolute_position(position - drag_ghost.grab_offset);
+            }
+            DragState::DragEnd | DragState::DragCanceled | DragState::Inactive => {
+                if let Some(ghost) = drag_ghost.ghost.take() {
+                    commands.entity(ghost).despawn_recursive();
+                }
+            }
+            DragState::MaybeDragged => (),
+        }
+    }
+}
+
+fn cleanup_orphaned_drag_ghosts(
+    q_ghosts: Query<(Entity, &DragGhostFrame)>,
+    q_drag_ghosts: Query<&DragGhost>,
+    mut commands: Commands,
+) {
+    for (entity, frame) in &q_ghosts {
+        let orphaned = match q_drag_ghosts.get(frame.source) {
+            Ok(drag_ghost) => drag_ghost.ghost != Some(entity),
+            Err(_) => true,
+        };
+
+        if orphaned {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+struct SpawnDragGhostClone {
+    source: Entity,
+    ghost: Entity,
+    opacity: f32,
+}
+
+impl Command for SpawnDragGhostClone {
+    fn apply(self, world: &mut World) {
+        if world.get_entity(self.ghost).is_none() {
+            return;
+        }
+
+        SpawnDragGhostClone::clone_node(self.source, self.ghost, self.opacity, true, world);
+    }
+}
+
+impl SpawnDragGhostClone {
+    /// Copies the visual components of a node and its descendants under `parent`.
+    /// Interaction and widget components are not copied, the clone is purely visual.
+    fn clone_node(source: Entity, parent: Entity, opacity: f32, is_root: bool, world: &mut World) {
+        let Some(mut style) = world.get::<Style>(source).cloned() else {
+            return;
+        };
+        if let Some(visibility) = world.get::<Visibility>(source) {
+            if *visibility == Visibility::Hidden {
+                return;
+            }
+        }
+
+        if is_root {
+            let size = match world.get::<Node>(source) {
+                Some(node) => node.size(),
+                None => Vec2::ZERO,
+            };
+
+            style.position_type = PositionType::Relative;
+            style.left = Val::Auto;
+            style.top = Val::Auto;
+            style.right = Val::Auto;
+            style.bottom = Val::Auto;
+            style.margin = UiRect::all(Val::Px(0.));
+            style.width = Val::Px(size.x);
+            style.height = Val::Px(size.y);
+        }
+
+        let fade = |color: Color| color.with_a(color.a() * opacity);
+        let background_color = match world.get::<BackgroundColor>(source) {
+            Some(color) => fade(color.0),
+            None => Color::NONE,
+        };
+        let border_color = match world.get::<BorderColor>(source) {
+            Some(color) => fade(color.0),
+            None => Color::NONE,
+        };
+
+        let clone = if let Some(text) = world.get::<Text>(source) {
+            let mut text = text.clone();
+            for section in text.sections.iter_mut() {
+                section.style.color = fade(section.style.color);
+            }
+
+            world
+                .spawn((
+                    TextBundle {
+                        style,
+                        text,
+                        background_color: background_color.into(),
+                        focus_policy: FocusPolicy::Pass,
+                        ..default()
+                    },
+                    BorderColor(border_color),
+                ))
+                .id()
+        } else if let Some(image) = world.get::<UiImage>(source) {
+            let image = image.clone();
+
+            world
+                .spawn((
+                    ImageBundle {
+                        style,
+                        image,
+                        background_color: background_color.into(),
+                        focus_policy: FocusPolicy::Pass,
+                        ..default()
+                    },
+                    BorderColor(border_color),
+                ))
+                .id()
+        } else {
+            world
+                .spawn(NodeBundle {
+                    style,
+                    background_color: background_color.into(),
+                    border_color: border_color.into(),
+                    focus_policy: FocusPolicy::Pass,
+                    ..default()
+                })
+                .id()
+        };
+
+        world.entity_mut(parent).add_child(clone);
+
+        let children: Vec<Entity> = match world.get::<Children>(source) {
+            Some(children) => children.iter().copied().collect(),
+            None => Vec::new(),
+        };
+
+        for child in children {
+            SpawnDragGhostClone::clone_node(child, clone, opacity, false, world);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum DragGhostContent {
+    /// A semi-transparent, non-interactive copy of the dragged node
+    TranslucentClone,
+    /// Builds the ghost content from scratch. Receives the ghost container and the dragged entity.
+    Custom(fn(&mut UiBuilder<Entity>, Entity)),
+}
+
+/// Adds a preview that follows the cursor while the [`Draggable`] on the same entity is dragged
+#[derive(Component, Clone, Debug)]
+pub struct DragGhost {
+    pub content: DragGhostContent,
+    /// Opacity multiplier applied to the colors of a [`DragGhostContent::TranslucentClone`]
+    pub opacity: f32,
+    ghost: Option<Entity>,
+    grab_offset: Vec2,
+}
+
+impl Default for DragGhost {
+    fn default() -> Self {
+        Self {
+            content: DragGhostContent::TranslucentClone,
+            opacity: 0.5,
+            ghost: None,
+            grab_offset: Vec2::ZERO,
+        }
+    }
+}
+
+impl DragGhost {
+    pub fn translucent_clone() -> Self {
+        Self::default()
+    }
+
+    pub fn custom(builder: fn(&mut UiBuilder<Entity>, Entity)) -> Self {
+        Self {
+            content: DragGhostContent::Custom(builder),
+            ..default()
+        }
+    }
+
+    pub fn with_opacity(self, opacity: f32) -> Self {
+        Self {
+            opacity: opacity.clamp(0., 1.),
+            ..self
+        }
+    }
+
+    /// The currently displayed ghost container, if the entity is being dragged
+    pub fn ghost(&self) -> Option<Entity> {
+        self.ghost
+    }
+
+    fn frame(source: Entity, position: Vec2) -> impl Bundle {
+        (
+            Name::new("Drag Ghost"),
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(position.x),
+                    top: Val::Px(position.y),
+                    ..default()
+                },
+                focus_policy: FocusPolicy::Pass,
+                z_index: ZIndex::Global(DRAG_GHOST_Z_INDEX),
+                ..default()
+            },
+            DragGhostFrame { source },
+        )
+    }
+}
+
+/// Marks the container of a drag ghost
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DragGhostFrame {
+    source: Entity,
+}
+
+impl Default for DragGhostFrame {
+    fn default() -> Self {
+        Self {
+            source: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl DragGhostFrame {
+    pub fn source(&self) -> Entity {
+        self.source
+    }
+}
@@ -1,6 +1,12 @@
+use std::{any::TypeId, marker::PhantomData};
+
 use bevy::{prelude::*, ui::RelativeCursorPosition};
 
-use crate::drag_interaction::{DragState, Draggable, DraggableUpdate};
+use crate::{
+    drag_interaction::{DragState, Draggable, DraggableUpdate},
+    theme::pseudo_state::PseudoState,
+    ui_commands::ManagePseudoStateExt,
+};
 
 pub struct DropInteractionPlugin;
 
@@ -12,6 +18,7 @@ impl Plugin for DropInteractionPlugin {
                 (
                     update_drop_zone_single_frame_state,
                     update_drop_zones.run_if(should_update_drop_zones),
+                    update_drop_zone_payload_feedback,
                 )
                     .chain()
                     .in_set(DroppableUpdate),
@@ -19,6 +26,45 @@ impl Plugin for DropInteractionPlugin {
     }
 }
 
+/// Enables typed drag and drop of `T` between [`DragPayload<T>`] carriers and
+/// drop zones that list `T` in their [`AcceptedPayloads`].
+///
+/// Sends [`Dropped<T>`] when an accepted payload is dropped.
+pub struct DropPayloadPlugin<T>(PhantomData<T>);
+
+impl<T> Default for DropPayloadPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> DropPayloadPlugin<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Plugin for DropPayloadPlugin<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    fn build(&self, app: &mut App) {
+        app.add_event::<Dropped<T>>()
+            .add_systems(
+                Update,
+                update_drag_payload_types::<T>
+                    .before(update_drop_zone_payload_feedback)
+                    .in_set(DroppableUpdate),
+            )
+            .add_systems(
+                Update,
+                send_dropped_payloads::<T>
+                    .after(update_drop_zones)
+                    .in_set(DroppableUpdate),
+            );
+    }
+}
+
 #[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
 pub struct DroppableUpdate;
 
@@ -126,6 +172,97 @@ fn update_drop_zones(
     }
 }
 
+fn update_drag_payload_types<T: Send + Sync + 'static>(
+    q_added_payloads: Query<Entity, Added<DragPayload<T>>>,
+    mut q_removed_payloads: RemovedComponents<DragPayload<T>>,
+    mut q_payload_types: Query<&mut DragPayloadTypes>,
+    mut commands: Commands,
+) {
+    let type_id = TypeId::of::<T>();
+    for entity in &q_added_payloads {
+        if let Ok(mut payload_types) = q_payload_types.get_mut(entity) {
+            if !payload_types.0.contains(&type_id) {
+                payload_types.0.push(type_id);
+            }
+        } else {
+            commands
+                .entity(entity)
+                .insert(DragPayloadTypes(vec![type_id]));
+        }
+    }
+
+    for entity in q_removed_payloads.read() {
+        if let Ok(mut payload_types) = q_payload_types.get_mut(entity) {
+            payload_types.0.retain(|id| *id != type_id);
+        }
+    }
+}
+
+fn update_drop_zone_payload_feedback(
+    q_drop_zones: Query<(Entity, &DropZone, &AcceptedPayloads), Changed<DropZone>>,
+    q_payload_types: Query<&DragPayloadTypes>,
+    mut commands: Commands,
+) {
+    for (entity, drop_zone, accepted) in &q_drop_zones {
+        let incoming = match drop_zone.drop_phase {
+            DropPhase::DroppableEntered | DropPhase::DroppableHover => drop_zone.incoming_droppable,
+            _ => None,
+        };
+
+        let Some(droppable) = incoming else {
+            commands
+                .entity(entity)
+                .remove_pseudo_state(PseudoState::DropAccepted)
+                .remove_pseudo_state(PseudoState::DropRejected);
+            continue;
+        };
+
+        let accepts = match q_payload_types.get(droppable) {
+            Ok(payload_types) => payload_types.0.iter().any(|id| accepted.accepts_id(*id)),
+            Err(_) => false,
+        };
+
+        if accepts {
+            commands
+                .entity(entity)
+                .add_pseudo_state(PseudoState::DropAccepted)
+                .remove_pseudo_state(PseudoState::DropRejected);
+        } else {
+            commands
+                .entity(entity)
+                .add_pseudo_state(PseudoState::DropRejected)
+                .remove_pseudo_state(PseudoState::DropAccepted);
+        }
+    }
+}
+
+fn send_dropped_payloads<T: Clone + Send + Sync + 'static>(
+    q_drop_zones: Query<(Entity, &DropZone, &AcceptedPayloads), Changed<DropZone>>,
+    q_payloads: Query<&DragPayload<T>>,
+    mut e_dropped: EventWriter<Dropped<T>>,
+) {
+    for (entity, drop_zone, accepted) in &q_drop_zones {
+        if drop_zone.drop_phase != DropPhase::Dropped || !accepted.accepts::<T>() {
+            continue;
+        }
+
+        let Some(source) = drop_zone.incoming_droppable else {
+            continue;
+        };
+
+        let Ok(payload) = q_payloads.get(source) else {
+            continue;
+        };
+
+        e_dropped.send(Dropped {
+            zone: entity,
+            source,
+            position: drop_zone.position,
+            payload: payload.0.clone(),
+        });
+    }
+}
+
 #[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
 #[reflect]
 pub enum DropPhase {
@@ -161,3 +298,67 @@ impl DropZone {
         self.position
     }
 }
+
+/// Typed data carried by a [`Draggable`] [`Droppable`] entity.
+///
+/// Requires [`DropPayloadPlugin<T>`] to be added to the app.
+#[derive(Component, Clone, Debug, Default)]
+pub struct DragPayload<T: Send + Sync + 'static>(pub T);
+
+/// The payload types carried by a droppable, tracked by [`DropPayloadPlugin`]s
+#[derive(Component, Clone, Debug, Default)]
+pub struct DragPayloadTypes(Vec<TypeId>);
+
+impl DragPayloadTypes {
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.0.contains(&TypeId::of::<T>())
+    }
+}
+
+/// Declares which [`DragPayload`] types a [`DropZone`] accepts.
+///
+/// Zones with this component get `PseudoState::DropAccepted` or `PseudoState::DropRejected`
+/// while a droppable hovers them.
+#[derive(Component, Clone, Debug, Default)]
+pub struct AcceptedPayloads {
+    types: Vec<TypeId>,
+}
+
+impl AcceptedPayloads {
+    pub fn new() -> Self {
+        Self { types: Vec::new() }
+    }
+
+    pub fn of<T: 'static>() -> Self {
+        Self::new().with::<T>()
+    }
+
+    pub fn with<T: 'static>(mut self) -> Self {
+        self.add::<T>();
+        self
+    }
+
+    pub fn add<T: 'static>(&mut self) {
+        let type_id = TypeId::of::<T>();
+        if !self.types.contains(&type_id) {
+            self.types.push(type_id);
+        }
+    }
+
+    pub fn accepts<T: 'static>(&self) -> bool {
+        self.accepts_id(TypeId::of::<T>())
+    }
+
+    pub fn accepts_id(&self, type_id: TypeId) -> bool {
+        self.types.contains(&type_id)
+    }
+}
+
+/// Sent when a [`DragPayload<T>`] is dropped on a zone accepting `T`
+#[derive(Event, Clone, Debug)]
+pub struct Dropped<T: Send + Sync + 'static> {
+    pub zone: Entity,
+    pub source: Entity,
+    pub position: Option<Vec2>,
+    pub payload: T,
+}
@@ -8,6 +8,7 @@ pub struct FluxInteractionPlugin;
 impl Plugin for FluxInteractionPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<FluxInteractionConfig>()
+            .register_type::<FluxInteractionConfig>()
             .configure_sets(Update, FluxInteractionUpdate)
             .add_systems(
                 Update,
@@ -24,6 +25,7 @@ impl Plugin for FluxInteractionPlugin {
 }
 
 #[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
 pub struct FluxInteractionConfig {
     pub max_interaction_duration: f32,
 }
@@ -15,8 +15,13 @@ use serde::{Deserialize, Serialize};
 
 pub mod prelude {
     pub use super::{
-        drag_interaction::{DragState, Draggable, DraggableUpdate},
-        drop_interaction::{DropPhase, DropZone, Droppable, DroppableUpdate},
+        drag_interaction::{
+            DragGhost, DragGhostContent, DragSource, DragState, Draggable, DraggableUpdate,
+        },
+        drop_interaction::{
+            AcceptedPayloads, DragPayload, DropPayloadPlugin, DropPhase, DropZone, Droppable,
+            DroppableUpdate, Dropped,
+        },
         flux_interaction::{
             FluxInteraction, FluxInteractionStopwatch, FluxInteractionStopwatchLock,
             FluxInteractionUpdate, TrackedInteraction,
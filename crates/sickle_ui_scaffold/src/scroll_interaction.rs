@@ -20,24 +20,21 @@ fn update_scrollables(
     r_keys: Res<ButtonInput<KeyCode>>,
     mut q_scrollables: Query<(&mut Scrollable, &Interaction)>,
 ) {
-    let mut axis = ScrollAxis::Vertical;
-    let mut offset = 0.;
-    let mut unit = MouseScrollUnit::Line;
+    let shift_pressed = r_keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let mut lines = Vec2::ZERO;
+    let mut pixels = Vec2::ZERO;
     let mut has_event = false;
 
-    // Only the last event is kept
+    // All events of the frame are accumulated, so fast wheels and touchpads don't lose distance
     for mouse_wheel_event in mouse_wheel_events.read() {
-        axis = ScrollAxis::Vertical;
-        unit = mouse_wheel_event.unit;
-        offset = if mouse_wheel_event.x != 0. {
-            -mouse_wheel_event.x
-        } else {
-            -mouse_wheel_event.y
-        };
+        let mut delta = Vec2::new(-mouse_wheel_event.x, -mouse_wheel_event.y);
+        if shift_pressed && delta.x == 0. {
+            delta = Vec2::new(delta.y, 0.);
+        }
 
-        if mouse_wheel_event.x > 0. || r_keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
-        {
-            axis = ScrollAxis::Horizontal;
+        match mouse_wheel_event.unit {
+            MouseScrollUnit::Line => lines += delta,
+            MouseScrollUnit::Pixel => pixels += delta,
         }
 
         has_event = true;
@@ -47,13 +44,30 @@ fn update_scrollables(
         return;
     }
 
+    let (delta, unit) = if pixels == Vec2::ZERO {
+        (lines, MouseScrollUnit::Line)
+    } else if lines == Vec2::ZERO {
+        (pixels, MouseScrollUnit::Pixel)
+    } else {
+        (
+            pixels + (lines * Scrollable::LINE_SIZE),
+            MouseScrollUnit::Pixel,
+        )
+    };
+
+    let axis = if delta.x.abs() > delta.y.abs() {
+        ScrollAxis::Horizontal
+    } else {
+        ScrollAxis::Vertical
+    };
+
     for (mut scrollable, interaction) in &mut q_scrollables {
         if *interaction != Interaction::Hovered {
             continue;
         }
 
         scrollable.axis = axis.into();
-        scrollable.diff = offset.into();
+        scrollable.delta = delta;
         scrollable.unit = unit;
     }
 }
@@ -69,7 +83,7 @@ pub enum ScrollAxis {
 #[reflect(Component)]
 pub struct Scrollable {
     axis: Option<ScrollAxis>,
-    diff: f32,
+    delta: Vec2,
     unit: MouseScrollUnit,
 }
 
@@ -77,18 +91,36 @@ impl Default for Scrollable {
     fn default() -> Self {
         Self {
             axis: Default::default(),
-            diff: Default::default(),
+            delta: Vec2::ZERO,
             unit: MouseScrollUnit::Pixel,
         }
     }
 }
 
 impl Scrollable {
+    /// Pixel distance of a single line, used when line and pixel based events are mixed in a frame
+    pub const LINE_SIZE: f32 = 20.;
+
+    /// The dominant axis of the last scroll and the accumulated distance along it
     pub fn last_change(&self) -> Option<(ScrollAxis, f32, MouseScrollUnit)> {
         let Some(axis) = self.axis else {
             return None;
         };
 
-        (axis, self.diff, self.unit).into()
+        let diff = match axis {
+            ScrollAxis::Horizontal => self.delta.x,
+            ScrollAxis::Vertical => self.delta.y,
+        };
+
+        (axis, diff, self.unit).into()
+    }
+
+    /// The accumulated distance of the last scroll on both axes
+    pub fn last_delta(&self) -> Option<(Vec2, MouseScrollUnit)> {
+        let Some(_) = self.axis else {
+            return None;
+        };
+
+        (self.delta, self.unit).into()
     }
 }
@@ -1,6 +1,7 @@
 pub mod dynamic_style;
 pub mod dynamic_style_attribute;
 pub mod icons;
+pub mod keyframes;
 pub mod pseudo_state;
 pub mod style_animation;
 pub mod theme_colors;
@@ -29,13 +30,18 @@ pub mod prelude {
         },
         dynamic_style_attribute::{DynamicStyleAttribute, DynamicStyleController},
         icons::IconData,
+        keyframes::{
+            Keyframe, KeyframeSettings, KeyframeTrigger, KeyframeVals, KeyframesCompleted,
+            KeyframesController, PlayKeyframesExt,
+        },
         pseudo_state::{
-            FlexDirectionToPseudoState, HierarchyToPseudoState, PseudoState, PseudoStates,
+            DefaultLayoutDirection, FlexDirectionToPseudoState, HierarchyToPseudoState,
+            LayoutDirection, LayoutDirectionOverride, PseudoState, PseudoStates,
             VisibilityToPseudoState,
         },
         style_animation::{
-            AnimationLoop, AnimationSettings, AnimationState, InteractionStyle,
-            LoopedAnimationConfig,
+            AnimationCompleteCallback, AnimationCompleted, AnimationCompletion, AnimationLoop,
+            AnimationSettings, AnimationState, InteractionStyle, LoopedAnimationConfig,
         },
         theme_colors::{Accent, Container, On, Surface},
         theme_data::{Contrast, Scheme, ThemeData},
@@ -54,6 +60,7 @@ impl Plugin for ThemePlugin {
             (ThemeUpdate, CustomThemeUpdate.after(ThemeUpdate)).before(UiSystem::Layout),
         )
         .init_resource::<ThemeData>()
+        .register_type::<ThemeData>()
         .init_resource::<ThemeRegistry>()
         .add_plugins((AutoPseudoStatePlugin, DynamicStylePlugin));
     }
@@ -253,6 +260,192 @@ where
         &self.pseudo_themes
     }
 
+    /// Describes how the themes of `entity` resolve into styles, without applying them.
+    /// Returns `None` if `entity` doesn't have `C`.
+    pub fn explain(entity: Entity, world: &World) -> Option<ThemeExplanation> {
+        let context = world.get::<C>(entity)?;
+        let theme_data = world.resource::<ThemeData>();
+        let pseudo_states = world
+            .get::<PseudoStates>(entity)
+            .map_or(Vec::new(), |pseudo_states| pseudo_states.get().clone());
+
+        let default_theme = C::default_theme();
+        let themes = Theme::<C>::applied_themes(entity, &default_theme, world);
+        let pseudo_themes = Theme::<C>::applied_pseudo_themes(&themes, &pseudo_states);
+
+        let candidates = themes
+            .iter()
+            .flat_map(|(theme, source)| {
+                theme
+                    .pseudo_themes()
+                    .iter()
+                    .map(move |pseudo_theme| (pseudo_theme, *source))
+            })
+            .map(|(pseudo_theme, source)| PseudoThemeCandidate {
+                source,
+                state: pseudo_theme.state().clone(),
+                score: pseudo_theme.count_match(&pseudo_states),
+                applied: pseudo_themes
+                    .iter()
+                    .position(|(applied, _)| std::ptr::eq(*applied, pseudo_theme)),
+            })
+            .collect();
+
+        let styles = Theme::<C>::build_styles(&pseudo_themes, entity, context, theme_data, world)
+            .into_iter()
+            .map(|(placement, style)| ContextStyleExplanation {
+                context: placement.and_then(|placement| {
+                    context
+                        .contexts()
+                        .into_iter()
+                        .find(|name| context.get(name) == Ok(placement))
+                }),
+                entity: placement.unwrap_or(entity),
+                style,
+            })
+            .collect();
+
+        Some(ThemeExplanation {
+            component: type_name::<C>(),
+            entity,
+            themes: themes.iter().map(|(_, source)| *source).collect(),
+            pseudo_states,
+            candidates,
+            styles,
+        })
+    }
+
+    /// Themes of `entity` in order of application: the default theme first, then the themes
+    /// of its ancestors from the root down, and its own theme last.
+    pub(crate) fn applied_themes<'a>(
+        entity: Entity,
+        default_theme: &'a Option<Theme<C>>,
+        world: &'a World,
+    ) -> Vec<(&'a Theme<C>, Option<Entity>)> {
+        // Default -> General (App-wide) -> Specialized (Screen) theming is a reasonable guess.
+        // Round to 4, which is the first growth step.
+        // TODO: Cache most common theme count in theme data.
+        let mut themes: Vec<(&Theme<C>, Option<Entity>)> = Vec::with_capacity(4);
+        // Add own theme
+        if let Some(own_theme) = world.get::<Theme<C>>(entity) {
+            themes.push((own_theme, Some(entity)));
+        }
+
+        // Add all ancestor themes
+        let mut current_ancestor = entity;
+        while let Some(parent) = world.get::<Parent>(current_ancestor) {
+            current_ancestor = parent.get();
+            if let Some(ancestor_theme) = world.get::<Theme<C>>(current_ancestor) {
+                themes.push((ancestor_theme, Some(current_ancestor)));
+            }
+        }
+
+        if let Some(default_theme) = default_theme {
+            themes.push((default_theme, None));
+        }
+
+        // The list contains themes in reverse order of application
+        themes.reverse();
+        themes
+    }
+
+    /// Pseudo themes of `themes` that apply to `pseudo_states`, in order of application:
+    /// base themes first, then the pseudo themes matching more and more of the states.
+    pub(crate) fn applied_pseudo_themes<'a>(
+        themes: &[(&'a Theme<C>, Option<Entity>)],
+        pseudo_states: &Vec<PseudoState>,
+    ) -> Vec<(&'a PseudoTheme<C>, Option<Entity>)> {
+        // Assuming we have a base style and two-three pseudo state style is a reasonable guess.
+        // TODO: Cache most common pseudo theme count in theme data.
+        let mut pseudo_themes: Vec<(&PseudoTheme<C>, Option<Entity>)> =
+            Vec::with_capacity(themes.len() * 4);
+
+        for (theme, source_entity) in themes {
+            if let Some(base_theme) = theme.pseudo_themes().iter().find(|pt| pt.is_base_theme()) {
+                pseudo_themes.push((base_theme, *source_entity));
+            }
+        }
+
+        for i in 0..pseudo_states.len() {
+            for (theme, source_entity) in themes {
+                theme
+                    .pseudo_themes()
+                    .iter()
+                    .filter(|pt| pt.count_match(pseudo_states) == i + 1)
+                    .for_each(|pt| pseudo_themes.push((pt, *source_entity)));
+            }
+        }
+
+        pseudo_themes
+    }
+
+    /// Builds the styles of `pseudo_themes` and merges them per placement, overwriting
+    /// per-attribute in order of application.
+    pub(crate) fn build_styles(
+        pseudo_themes: &[(&PseudoTheme<C>, Option<Entity>)],
+        entity: Entity,
+        context: &C,
+        theme_data: &ThemeData,
+        world: &World,
+    ) -> Vec<(Option<Entity>, DynamicStyle)> {
+        pseudo_themes
+            .iter()
+            .map(
+                |(pseudo_theme, source_entity)| match pseudo_theme.builder().clone() {
+                    DynamicStyleBuilder::Static(style) => vec![(None, style.clone())],
+                    DynamicStyleBuilder::StyleBuilder(builder) => {
+                        let mut style_builder = StyleBuilder::new();
+                        builder(&mut style_builder, theme_data);
+
+                        style_builder.convert_with(context)
+                    }
+                    DynamicStyleBuilder::ContextStyleBuilder(builder) => {
+                        let mut style_builder = StyleBuilder::new();
+                        builder(&mut style_builder, context, theme_data);
+
+                        style_builder.convert_with(context)
+                    }
+                    DynamicStyleBuilder::WorldStyleBuilder(builder) => {
+                        let mut style_builder = StyleBuilder::new();
+                        builder(&mut style_builder, entity, context, world);
+
+                        style_builder.convert_with(context)
+                    }
+                    DynamicStyleBuilder::InfoWorldStyleBuilder(builder) => {
+                        let mut style_builder = StyleBuilder::new();
+                        builder(
+                            &mut style_builder,
+                            *source_entity,
+                            pseudo_theme.state(),
+                            entity,
+                            context,
+                            world,
+                        );
+
+                        style_builder.convert_with(context)
+                    }
+                },
+            )
+            .filter(|e_to_dys| e_to_dys.len() > 0)
+            .fold(
+                Vec::with_capacity(context.contexts().len() + 1),
+                |mut acc, context_styles| {
+                    for context_style in context_styles {
+                        let index = acc.iter().position(|entry| entry.0 == context_style.0);
+                        match index {
+                            Some(index) => {
+                                let (_, prev_entry) = acc[index].clone();
+                                acc[index].1 = prev_entry.merge(context_style.1);
+                            }
+                            None => acc.push(context_style),
+                        }
+                    }
+
+                    acc
+                },
+            )
+    }
+
     pub fn post_update() -> impl IntoSystemConfigs<()> {
         Theme::<C>::post_update_in(ThemeUpdate)
     }
@@ -329,21 +522,24 @@ impl InsertThemedComponentExt for UiBuilder<'_, Entity> {
 
 /// Tracks all the themes that have been registered with [`ComponentThemePlugin`].
 ///
-/// This can be used to check if a theme's plugin is missing.
+/// This can be used to check if a theme's plugin is missing, or to explain the themes of an entity.
 #[derive(Resource, Default, Debug)]
 pub struct ThemeRegistry {
     themes: HashSet<TypeId>,
+    explainers: Vec<fn(Entity, &World) -> Option<ThemeExplanation>>,
 }
 
 impl ThemeRegistry {
-    fn new_with<C: 'static>() -> Self {
+    fn new_with<C: DefaultTheme>() -> Self {
         let mut registry = Self::default();
         registry.add::<C>();
         registry
     }
 
-    fn add<C: 'static>(&mut self) {
-        self.themes.insert(TypeId::of::<C>());
+    fn add<C: DefaultTheme>(&mut self) {
+        if self.themes.insert(TypeId::of::<C>()) {
+            self.explainers.push(Theme::<C>::explain);
+        }
     }
 
     /// Returns `true` if the theme `C` has been registered.
@@ -355,6 +551,52 @@ impl ThemeRegistry {
     pub fn contains_by_id(&self, id: TypeId) -> bool {
         self.themes.contains(&id)
     }
+
+    /// Explains every registered theme of the themed components on `entity`.
+    pub fn explain(&self, entity: Entity, world: &World) -> Vec<ThemeExplanation> {
+        self.explainers
+            .iter()
+            .filter_map(|explain| explain(entity, world))
+            .collect()
+    }
+}
+
+/// How the [`Theme`]s of a themed component resolve into the [`DynamicStyle`]s of its entity
+/// and its contexts. See [`Theme::explain`].
+#[derive(Clone, Debug)]
+pub struct ThemeExplanation {
+    /// Type name of the themed component
+    pub component: &'static str,
+    /// The entity of the themed component
+    pub entity: Entity,
+    /// Sources of the themes in order of application. `None` is the [`DefaultTheme`].
+    pub themes: Vec<Option<Entity>>,
+    /// The pseudo states of the entity the pseudo themes are matched against
+    pub pseudo_states: Vec<PseudoState>,
+    /// Every pseudo theme of the themes, in order of the themes
+    pub candidates: Vec<PseudoThemeCandidate>,
+    /// The merged style of each placement
+    pub styles: Vec<ContextStyleExplanation>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PseudoThemeCandidate {
+    /// Source of the theme, `None` for the [`DefaultTheme`]
+    pub source: Option<Entity>,
+    pub state: Option<Vec<PseudoState>>,
+    /// Result of [`PseudoTheme::count_match`] against the pseudo states of the entity
+    pub score: usize,
+    /// Position in the order of application if the pseudo theme was applied
+    pub applied: Option<usize>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ContextStyleExplanation {
+    /// The [`UiContext`] the style is placed to, `None` for the themed entity itself
+    pub context: Option<&'static str>,
+    /// The entity the style is placed to
+    pub entity: Entity,
+    pub style: DynamicStyle,
 }
 
 #[derive(Default)]
@@ -384,22 +626,43 @@ where
             is_custom: true,
         }
     }
-}
 
-impl<C> Plugin for ComponentThemePlugin<C>
-where
-    C: DefaultTheme,
-{
-    fn build(&self, app: &mut App) {
-        if let Some(mut registry) = app.world.get_resource_mut::<ThemeRegistry>() {
+    /// Registers the theme of `C` and adds its update systems to `PostUpdate`.
+    /// Does nothing and returns `false` if the theme is already registered.
+    ///
+    /// Used by the plugin, and to register themes in a running app outside of `PostUpdate`.
+    pub fn register(&self, world: &mut World) -> bool {
+        if let Some(mut registry) = world.get_resource_mut::<ThemeRegistry>() {
+            if registry.contains::<C>() {
+                return false;
+            }
+
             registry.add::<C>();
         } else {
-            app.insert_resource(ThemeRegistry::new_with::<C>());
+            world.insert_resource(ThemeRegistry::new_with::<C>());
+        }
+
+        let mut schedules = world.resource_mut::<Schedules>();
+        if !schedules.contains(PostUpdate) {
+            schedules.insert(Schedule::new(PostUpdate));
         }
 
+        // Safe unwrap: inserted above
+        let post_update = schedules.get_mut(PostUpdate).unwrap();
         match self.is_custom {
-            true => app.add_systems(PostUpdate, Theme::<C>::custom_post_update()),
-            false => app.add_systems(PostUpdate, Theme::<C>::post_update()),
+            true => post_update.add_systems(Theme::<C>::custom_post_update()),
+            false => post_update.add_systems(Theme::<C>::post_update()),
         };
+
+        true
+    }
+}
+
+impl<C> Plugin for ComponentThemePlugin<C>
+where
+    C: DefaultTheme,
+{
+    fn build(&self, app: &mut App) {
+        self.register(&mut app.world);
     }
 }
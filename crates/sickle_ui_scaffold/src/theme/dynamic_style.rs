@@ -5,7 +5,13 @@ use crate::{
     ui_style::{LogicalEq, UiStyleExt},
 };
 
-use super::{dynamic_style_attribute::DynamicStyleAttribute, CustomThemeUpdate};
+use super::{
+    dynamic_style_attribute::DynamicStyleAttribute,
+    keyframes::{KeyframeTrigger, KeyframesCompleted},
+    pseudo_state::PseudoStates,
+    style_animation::AnimationCompleted,
+    CustomThemeUpdate,
+};
 
 pub struct DynamicStylePlugin;
 
@@ -17,6 +23,8 @@ impl Plugin for DynamicStylePlugin {
                 .after(CustomThemeUpdate)
                 .before(UiSystem::Layout),
         )
+        .add_event::<AnimationCompleted>()
+        .add_event::<KeyframesCompleted>()
         .add_systems(
             PostUpdate,
             (
@@ -24,6 +32,7 @@ impl Plugin for DynamicStylePlugin {
                 update_dynamic_style_static_attributes,
                 update_dynamic_style_on_flux_change,
                 update_dynamic_style_on_stopwatch_change,
+                update_dynamic_style_keyframes,
             )
                 .chain()
                 .in_set(DynamicStylePostUpdate),
@@ -185,16 +194,35 @@ fn update_dynamic_style_on_stopwatch_change(
                     continue;
                 };
 
+                let target = match context_attribute.target {
+                    Some(context) => context,
+                    None => entity,
+                };
+
                 if let Some(stopwatch) = stopwatch {
-                    controller.update(interaction, stopwatch.0.elapsed_secs());
+                    if let Some((state, completion)) =
+                        controller.update(interaction, stopwatch.0.elapsed_secs())
+                    {
+                        let completed = AnimationCompleted {
+                            entity,
+                            target,
+                            state,
+                            completion,
+                        };
+                        let on_complete = controller.animation.on_complete;
+
+                        par_commands.command_scope(|mut commands| {
+                            commands.add(move |world: &mut World| {
+                                world.send_event(completed);
+                                if let Some(on_complete) = on_complete {
+                                    (on_complete.0)(&completed, world);
+                                }
+                            });
+                        });
+                    }
                 }
 
                 if style_changed || controller.dirty() {
-                    let target = match context_attribute.target {
-                        Some(context) => context,
-                        None => entity,
-                    };
-
                     par_commands.command_scope(|mut commands| {
                         attribute.apply(controller.current_state(), &mut commands.style(target));
                     });
@@ -244,6 +272,54 @@ fn update_dynamic_style_on_stopwatch_change(
         });
 }
 
+fn update_dynamic_style_keyframes(
+    time: Res<Time<Real>>,
+    mut q_styles: Query<(Entity, &mut DynamicStyle, Option<&PseudoStates>)>,
+    mut completed: EventWriter<KeyframesCompleted>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_seconds();
+    for (entity, mut style, pseudo_states) in &mut q_styles {
+        let style_changed = style.is_changed();
+
+        // Ticking keyframes must not trigger the change-based style systems
+        let style = style.bypass_change_detection();
+        for context_attribute in &mut style.attributes {
+            let ContextStyleAttribute {
+                target,
+                attribute:
+                    DynamicStyleAttribute::Keyframes {
+                        attribute,
+                        ref mut controller,
+                    },
+            } = context_attribute
+            else {
+                continue;
+            };
+
+            let target = match target {
+                Some(context) => *context,
+                None => entity,
+            };
+
+            if let Some(completion) = controller.update(attribute.duration(), pseudo_states, delta)
+            {
+                completed.send(KeyframesCompleted {
+                    entity,
+                    target,
+                    trigger: controller.settings.trigger.clone(),
+                    iteration: completion.iteration,
+                    finished: completion.finished,
+                });
+            }
+
+            if style_changed || controller.dirty() {
+                attribute.apply(controller.playhead(), &mut commands.style(target));
+            }
+        }
+    }
+}
+
 #[derive(Component, Clone, Debug, Default)]
 #[component(storage = "SparseSet")]
 pub struct DynamicStyleStopwatch(pub Stopwatch, pub StopwatchLock);
@@ -278,6 +354,14 @@ impl ContextStyleAttribute {
             attribute,
         }
     }
+
+    pub fn target(&self) -> Option<Entity> {
+        self.target
+    }
+
+    pub fn attribute(&self) -> &DynamicStyleAttribute {
+        &self.attribute
+    }
 }
 
 // TODO: Consider moving to sparse set. Static styles are removed in
@@ -332,6 +416,11 @@ impl DynamicStyle {
 
     pub fn copy_controllers(&mut self, other: &DynamicStyle) {
         for context_attribute in self.attributes.iter_mut() {
+            if context_attribute.attribute.is_keyframes() {
+                DynamicStyle::copy_keyframes_controller(context_attribute, other);
+                continue;
+            }
+
             if !context_attribute.attribute.is_animated() {
                 continue;
             }
@@ -370,6 +459,68 @@ impl DynamicStyle {
         }
     }
 
+    fn copy_keyframes_controller(
+        context_attribute: &mut ContextStyleAttribute,
+        other: &DynamicStyle,
+    ) {
+        let Some(old_attribute) = other
+            .attributes
+            .iter()
+            .find(|csa| csa.logical_eq(context_attribute))
+        else {
+            return;
+        };
+
+        let DynamicStyleAttribute::Keyframes {
+            controller: old_controller,
+            attribute: old_attribute,
+        } = &old_attribute.attribute
+        else {
+            return;
+        };
+
+        let ContextStyleAttribute {
+            attribute:
+                DynamicStyleAttribute::Keyframes {
+                    ref mut controller,
+                    attribute,
+                },
+            ..
+        } = context_attribute
+        else {
+            return;
+        };
+
+        if attribute == old_attribute && controller.settings == old_controller.settings {
+            controller.copy_state_from(old_controller);
+        }
+    }
+
+    /// Requests the keyframe animations triggered by command `name` to play.
+    /// Returns false if there are none.
+    pub fn play_keyframes(&mut self, name: &str) -> bool {
+        let mut played = false;
+        for context_attribute in self.attributes.iter_mut() {
+            let DynamicStyleAttribute::Keyframes {
+                ref mut controller, ..
+            } = context_attribute.attribute
+            else {
+                continue;
+            };
+
+            if controller.settings.trigger == KeyframeTrigger::Command(name.into()) {
+                controller.play();
+                played = true;
+            }
+        }
+
+        played
+    }
+
+    pub fn attributes(&self) -> &Vec<ContextStyleAttribute> {
+        &self.attributes
+    }
+
     pub fn is_interactive(&self) -> bool {
         self.attributes
             .iter()
@@ -3,12 +3,18 @@ use bevy::utils::default;
 use crate::{
     flux_interaction::FluxInteraction,
     ui_style::{
-        generated::{AnimatedStyleAttribute, InteractiveStyleAttribute, StaticStyleAttribute},
+        generated::{
+            AnimatedStyleAttribute, InteractiveStyleAttribute, KeyframesStyleAttribute,
+            LockableStyleAttribute, StaticStyleAttribute,
+        },
         LogicalEq,
     },
 };
 
-use super::style_animation::{AnimationSettings, AnimationState, InteractionStyle};
+use super::{
+    keyframes::KeyframesController,
+    style_animation::{AnimationCompletion, AnimationSettings, AnimationState, InteractionStyle},
+};
 
 #[derive(Clone, Debug)]
 pub enum DynamicStyleAttribute {
@@ -25,6 +31,12 @@ pub enum DynamicStyleAttribute {
         attribute: AnimatedStyleAttribute,
         controller: DynamicStyleController,
     },
+
+    // Plays on its own clock, independent of flux
+    Keyframes {
+        attribute: KeyframesStyleAttribute,
+        controller: KeyframesController,
+    },
 }
 
 impl LogicalEq for DynamicStyleAttribute {
@@ -72,6 +84,64 @@ impl LogicalEq for DynamicStyleAttribute {
                 },
                 Self::Interactive(r0),
             ) => l_attribute.logical_eq(r0),
+            (
+                Self::Static(l0),
+                Self::Keyframes {
+                    attribute: r_attribute,
+                    ..
+                },
+            ) => l0.logical_eq(r_attribute),
+            (
+                Self::Interactive(l0),
+                Self::Keyframes {
+                    attribute: r_attribute,
+                    ..
+                },
+            ) => l0.logical_eq(r_attribute),
+            (
+                Self::Animated {
+                    attribute: l_attribute,
+                    ..
+                },
+                Self::Keyframes {
+                    attribute: r_attribute,
+                    ..
+                },
+            ) => l_attribute.logical_eq(r_attribute),
+            (
+                Self::Keyframes {
+                    attribute: l_attribute,
+                    ..
+                },
+                Self::Keyframes {
+                    attribute: r_attribute,
+                    ..
+                },
+            ) => l_attribute.logical_eq(r_attribute),
+            (
+                Self::Keyframes {
+                    attribute: l_attribute,
+                    ..
+                },
+                Self::Static(r0),
+            ) => l_attribute.logical_eq(r0),
+            (
+                Self::Keyframes {
+                    attribute: l_attribute,
+                    ..
+                },
+                Self::Interactive(r0),
+            ) => l_attribute.logical_eq(r0),
+            (
+                Self::Keyframes {
+                    attribute: l_attribute,
+                    ..
+                },
+                Self::Animated {
+                    attribute: r_attribute,
+                    ..
+                },
+            ) => l_attribute.logical_eq(r_attribute),
         }
     }
 }
@@ -95,6 +165,10 @@ impl DynamicStyleAttribute {
         matches!(self, DynamicStyleAttribute::Animated { .. })
     }
 
+    pub fn is_keyframes(&self) -> bool {
+        matches!(self, DynamicStyleAttribute::Keyframes { .. })
+    }
+
     pub fn controller(&self) -> Result<&DynamicStyleController, &'static str> {
         let DynamicStyleAttribute::Animated { ref controller, .. } = self else {
             return Err("DynamicStyleAttribute isn't animated!");
@@ -113,6 +187,16 @@ impl DynamicStyleAttribute {
 
         Ok(controller)
     }
+
+    /// The `LockableStyleAttribute` that prevents the attribute from being applied, if any
+    pub fn lockable(&self) -> Option<LockableStyleAttribute> {
+        match self {
+            DynamicStyleAttribute::Static(attribute) => attribute.lockable(),
+            DynamicStyleAttribute::Interactive(attribute) => attribute.lockable(),
+            DynamicStyleAttribute::Animated { attribute, .. } => attribute.lockable(),
+            DynamicStyleAttribute::Keyframes { attribute, .. } => attribute.lockable(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -121,6 +205,9 @@ pub struct DynamicStyleController {
     current_state: AnimationState,
     dirty: bool,
     entering: bool,
+    target_style: Option<InteractionStyle>,
+    elapsed: f32,
+    reached: bool,
 }
 
 impl Default for DynamicStyleController {
@@ -130,6 +217,9 @@ impl Default for DynamicStyleController {
             current_state: Default::default(),
             dirty: Default::default(),
             entering: true,
+            target_style: None,
+            elapsed: 0.,
+            reached: false,
         }
     }
 }
@@ -143,7 +233,13 @@ impl DynamicStyleController {
         }
     }
 
-    pub fn update(&mut self, flux_interaction: &FluxInteraction, mut elapsed: f32) {
+    /// Ticks the animation towards the state of `flux_interaction`.
+    /// Returns the target state if its transition or a loop iteration completed.
+    pub fn update(
+        &mut self,
+        flux_interaction: &FluxInteraction,
+        mut elapsed: f32,
+    ) -> Option<(InteractionStyle, AnimationCompletion)> {
         // TODO: `enter` animation is currently played when a style animation different from
         // the previous one is requested. This means that playing the enter animation is *contextual*
         // and cannot be directly controlled by the developer. Figure out a way to factor out these
@@ -191,6 +287,33 @@ impl DynamicStyleController {
             .current_state
             .tick(target_style, tween, loop_tween, elapsed);
 
+        // The stopwatch is reset when a new animation starts
+        let restarted = self.target_style != Some(target_style) || elapsed < self.elapsed;
+        if restarted {
+            self.reached = self.current_state.is_holding(target_style);
+        }
+        self.target_style = Some(target_style);
+        self.elapsed = elapsed;
+
+        let transition_length = match tween {
+            Some(tween) => tween.duration.max(0.) + tween.delay(),
+            None => 0.,
+        };
+        let completion = if !self.reached && elapsed >= transition_length {
+            self.reached = true;
+            Some((target_style, AnimationCompletion::Transition))
+        } else if !restarted
+            && loop_tween.is_some()
+            && new_state.iteration() > self.current_state.iteration()
+        {
+            Some((
+                target_style,
+                AnimationCompletion::Loop(self.current_state.iteration()),
+            ))
+        } else {
+            None
+        };
+
         // Remove entering flag post tick, to allow Hold to occur
         self.entering = match self.animation.enter {
             Some(tween) => self.entering && elapsed < (tween.duration + tween.delay()),
@@ -201,6 +324,8 @@ impl DynamicStyleController {
             self.current_state = new_state;
             self.dirty = true;
         }
+
+        completion
     }
 
     pub fn current_state(&self) -> &AnimationState {
@@ -219,5 +344,8 @@ impl DynamicStyleController {
         self.current_state = other.current_state().clone();
         self.entering = other.entering;
         self.dirty = other.dirty;
+        self.target_style = other.target_style;
+        self.elapsed = other.elapsed;
+        self.reached = other.reached;
     }
 }
@@ -63,6 +63,7 @@ pub struct CustomIconData {
 
 #[derive(Clone, Debug, Reflect)]
 pub struct Icons {
+    pub arrow_left: IconData,
     pub arrow_right: IconData,
     pub checkmark: IconData,
     pub chevron_left: IconData,
@@ -71,6 +72,7 @@ pub struct Icons {
     pub exit_to_app: IconData,
     pub expand_less: IconData,
     pub expand_more: IconData,
+    pub minimize: IconData,
     pub open_in_new: IconData,
     pub radio_button_checked: IconData,
     pub radio_button_unchecked: IconData,
@@ -84,6 +86,12 @@ pub struct Icons {
 impl Default for Icons {
     fn default() -> Self {
         Self {
+            arrow_left: IconData::FontCodepoint(
+                "embedded://sickle_ui/fonts/MaterialIcons-Regular.ttf".into(),
+                '\u{E5DE}',
+                Color::WHITE,
+                12.,
+            ),
             arrow_right: IconData::FontCodepoint(
                 "embedded://sickle_ui/fonts/MaterialIcons-Regular.ttf".into(),
                 '\u{E5DF}',
@@ -132,6 +140,12 @@ impl Default for Icons {
                 Color::WHITE,
                 12.,
             ),
+            minimize: IconData::FontCodepoint(
+                "embedded://sickle_ui/fonts/MaterialIcons-Regular.ttf".into(),
+                '\u{E931}',
+                Color::WHITE,
+                12.,
+            ),
             open_in_new: IconData::FontCodepoint(
                 "embedded://sickle_ui/fonts/MaterialIcons-Regular.ttf".into(),
                 '\u{E89E}',
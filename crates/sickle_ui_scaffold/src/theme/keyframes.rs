@@ -0,0 +1,415 @@
+use bevy::{
+    ecs::system::{EntityCommand, EntityCommands},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+
+use sickle_math::{
+    ease::{Ease, ValueEasing},
+    lerp::Lerp,
+};
+
+use super::{
+    dynamic_style::DynamicStyle,
+    pseudo_state::{PseudoState, PseudoStates},
+    style_animation::AnimationLoop,
+};
+
+/// A value reached `time` seconds into a [`KeyframeVals`] animation.
+///
+/// `easing` applies to the transition from the previous keyframe.
+#[derive(Clone, Debug, Default, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct Keyframe<T: Lerp + Default + Clone + PartialEq> {
+    pub time: f32,
+    pub value: T,
+    #[reflect(default)]
+    pub easing: Option<Ease>,
+}
+
+impl<T: Lerp + Default + Clone + PartialEq> Keyframe<T> {
+    pub fn new(time: f32, value: T, easing: impl Into<Option<Ease>>) -> Self {
+        Self {
+            time,
+            value,
+            easing: easing.into(),
+        }
+    }
+}
+
+/// Values of an attribute over time, used by `style_builder.keyframes()`
+///
+/// ```
+/// # use sickle_ui_scaffold::prelude::*;
+/// # use sickle_math::ease::Ease;
+/// let pulse = KeyframeVals::new(1.)
+///     .keyframe(0.3, 1.1, Ease::OutQuad)
+///     .keyframe(0.6, 1., Ease::InQuad);
+///
+/// assert_eq!(pulse.duration(), 0.6);
+/// assert_eq!(pulse.sample(0.6), 1.);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct KeyframeVals<T: Lerp + Default + Clone + PartialEq> {
+    keyframes: Vec<Keyframe<T>>,
+}
+
+impl<T: Lerp + Default + Clone + PartialEq> From<Vec<Keyframe<T>>> for KeyframeVals<T> {
+    fn from(value: Vec<Keyframe<T>>) -> Self {
+        let mut keyframes = value;
+        keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+
+        Self { keyframes }
+    }
+}
+
+impl<T: Lerp + Default + Clone + PartialEq> KeyframeVals<T> {
+    /// Starts the animation with `value` at 0 seconds
+    pub fn new(value: T) -> Self {
+        Self {
+            keyframes: vec![Keyframe::new(0., value, None)],
+        }
+    }
+
+    /// Adds a keyframe, keeping them ordered by time
+    pub fn keyframe(mut self, time: f32, value: T, easing: impl Into<Option<Ease>>) -> Self {
+        let index = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.time <= time);
+        self.keyframes
+            .insert(index, Keyframe::new(time, value, easing));
+
+        self
+    }
+
+    pub fn keyframes(&self) -> &Vec<Keyframe<T>> {
+        &self.keyframes
+    }
+
+    /// The time of the last keyframe
+    pub fn duration(&self) -> f32 {
+        match self.keyframes.last() {
+            Some(keyframe) => keyframe.time.max(0.),
+            None => 0.,
+        }
+    }
+
+    /// The value at `time` seconds, holding the first and last values outside of the keyframes
+    pub fn sample(&self, time: f32) -> T {
+        let Some(first) = self.keyframes.first() else {
+            return T::default();
+        };
+
+        if time <= first.time {
+            return first.value.clone();
+        }
+
+        for pair in self.keyframes.windows(2) {
+            let (from, to) = (&pair[0], &pair[1]);
+            if time >= to.time {
+                continue;
+            }
+
+            let length = to.time - from.time;
+            let t = match length > 0. {
+                true => ((time - from.time) / length).clamp(0., 1.),
+                false => 1.,
+            };
+
+            return from
+                .value
+                .lerp(to.value.clone(), t.ease(to.easing.unwrap_or(Ease::Linear)));
+        }
+
+        // Safe unwrap: first exists
+        self.keyframes.last().unwrap().value.clone()
+    }
+}
+
+/// What starts a keyframe animation
+#[derive(Clone, Debug, Default, PartialEq, Eq, Reflect, Serialize, Deserialize)]
+pub enum KeyframeTrigger {
+    /// Plays when the style is applied
+    #[default]
+    Enter,
+    /// Plays each time the entity gains the pseudo state
+    PseudoState(PseudoState),
+    /// Plays when requested by name with [`PlayKeyframesExt::play_keyframes`]
+    Command(String),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct KeyframeSettings {
+    #[reflect(default)]
+    pub trigger: KeyframeTrigger,
+    #[reflect(default)]
+    pub delay: Option<f32>,
+    #[reflect(default)]
+    pub loop_type: AnimationLoop,
+}
+
+impl KeyframeSettings {
+    pub fn new() -> Self {
+        Self { ..default() }
+    }
+
+    pub fn copy_from(&mut self, other: Self) -> &mut Self {
+        self.trigger = other.trigger;
+        self.delay = other.delay;
+        self.loop_type = other.loop_type;
+
+        self
+    }
+
+    pub fn on_enter(&mut self) -> &mut Self {
+        self.trigger = KeyframeTrigger::Enter;
+
+        self
+    }
+
+    pub fn on_pseudo_state(&mut self, state: PseudoState) -> &mut Self {
+        self.trigger = KeyframeTrigger::PseudoState(state);
+
+        self
+    }
+
+    pub fn on_command(&mut self, name: impl Into<String>) -> &mut Self {
+        self.trigger = KeyframeTrigger::Command(name.into());
+
+        self
+    }
+
+    pub fn delay(&mut self, delay: impl Into<Option<f32>>) -> &mut Self {
+        self.delay = delay.into();
+
+        self
+    }
+
+    pub fn loop_type(&mut self, loop_type: AnimationLoop) -> &mut Self {
+        self.loop_type = loop_type;
+
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum KeyframesPlayback {
+    #[default]
+    Waiting,
+    Playing {
+        elapsed: f32,
+    },
+    Finished,
+}
+
+/// An iteration of a keyframe animation that completed during an update
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeyframesCompletion {
+    pub iteration: u32,
+    pub finished: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KeyframesController {
+    pub settings: KeyframeSettings,
+    playback: KeyframesPlayback,
+    playhead: f32,
+    iteration: u32,
+    has_state: bool,
+    requested: bool,
+    dirty: bool,
+}
+
+impl KeyframesController {
+    pub fn new(settings: KeyframeSettings) -> Self {
+        Self {
+            settings,
+            ..default()
+        }
+    }
+
+    /// Plays the animation from the start on the next update
+    pub fn play(&mut self) {
+        self.requested = true;
+    }
+
+    /// Advances the animation of length `duration` by `delta` seconds, starting it if triggered.
+    /// Returns the last completed iteration, if any completed.
+    pub fn update(
+        &mut self,
+        duration: f32,
+        pseudo_states: Option<&PseudoStates>,
+        delta: f32,
+    ) -> Option<KeyframesCompletion> {
+        let triggered = match &self.settings.trigger {
+            KeyframeTrigger::Enter => self.playback == KeyframesPlayback::Waiting || self.requested,
+            KeyframeTrigger::PseudoState(state) => {
+                let has_state = pseudo_states.is_some_and(|states| states.has(state));
+                let gained = has_state && !self.has_state;
+                self.has_state = has_state;
+
+                gained || self.requested
+            }
+            KeyframeTrigger::Command(_) => self.requested,
+        };
+        self.requested = false;
+
+        let elapsed = match (triggered, self.playback) {
+            (true, _) => {
+                self.iteration = 0;
+                0.
+            }
+            (false, KeyframesPlayback::Playing { elapsed }) => elapsed + delta,
+            _ => {
+                self.dirty = false;
+                return None;
+            }
+        };
+
+        let (playhead, iteration, finished) = self.playhead_at(elapsed, duration);
+        let completion = match finished || iteration > self.iteration {
+            true => KeyframesCompletion {
+                iteration: match finished {
+                    true => iteration,
+                    false => iteration - 1,
+                },
+                finished,
+            }
+            .into(),
+            false => None,
+        };
+
+        self.playback = match finished {
+            true => KeyframesPlayback::Finished,
+            false => KeyframesPlayback::Playing { elapsed },
+        };
+        self.dirty = triggered || playhead != self.playhead;
+        self.playhead = playhead;
+        self.iteration = iteration;
+
+        completion
+    }
+
+    fn playhead_at(&self, elapsed: f32, duration: f32) -> (f32, u32, bool) {
+        let elapsed = elapsed - self.settings.delay.unwrap_or(0.);
+        if elapsed < 0. {
+            return (0., 0, false);
+        }
+
+        if duration <= 0. {
+            return (duration, 0, true);
+        }
+
+        let iteration = (elapsed / duration).floor() as u32;
+        let offset = elapsed - (iteration as f32 * duration);
+        let even = iteration % 2 == 0;
+
+        match self.settings.loop_type {
+            AnimationLoop::None => match iteration > 0 {
+                true => (duration, 0, true),
+                false => (offset, 0, false),
+            },
+            AnimationLoop::Continous => (offset, iteration, false),
+            AnimationLoop::Times(times, reset) => match iteration >= times as u32 {
+                true => match reset {
+                    true => (0., iteration.saturating_sub(1), true),
+                    false => (duration, iteration.saturating_sub(1), true),
+                },
+                false => (offset, iteration, false),
+            },
+            AnimationLoop::PingPongContinous => match even {
+                true => (offset, iteration, false),
+                false => (duration - offset, iteration, false),
+            },
+            AnimationLoop::PingPong(times) => match iteration >= times as u32 {
+                true => match times % 2 == 0 {
+                    true => (0., iteration.saturating_sub(1), true),
+                    false => (duration, iteration.saturating_sub(1), true),
+                },
+                false => match even {
+                    true => (offset, iteration, false),
+                    false => (duration - offset, iteration, false),
+                },
+            },
+        }
+    }
+
+    /// Seconds into the keyframes
+    pub fn playhead(&self) -> f32 {
+        self.playhead
+    }
+
+    pub fn iteration(&self) -> u32 {
+        self.iteration
+    }
+
+    pub fn is_playing(&self) -> bool {
+        matches!(self.playback, KeyframesPlayback::Playing { .. })
+    }
+
+    pub fn finished(&self) -> bool {
+        self.playback == KeyframesPlayback::Finished
+    }
+
+    pub fn dirty(&self) -> bool {
+        self.dirty
+    }
+
+    pub fn copy_state_from(&mut self, other: &KeyframesController) {
+        self.playback = other.playback;
+        self.playhead = other.playhead;
+        self.iteration = other.iteration;
+        self.has_state = other.has_state;
+        self.requested = other.requested;
+        self.dirty = other.dirty;
+    }
+}
+
+/// Sent when a keyframe animation completes an iteration, and once more when it finishes.
+/// Continuous loops never finish.
+#[derive(Event, Clone, Debug, PartialEq)]
+pub struct KeyframesCompleted {
+    /// The entity with the [`DynamicStyle`]
+    pub entity: Entity,
+    /// The entity the animated attribute is applied to
+    pub target: Entity,
+    pub trigger: KeyframeTrigger,
+    pub iteration: u32,
+    pub finished: bool,
+}
+
+struct PlayKeyframes {
+    name: String,
+}
+
+impl EntityCommand for PlayKeyframes {
+    fn apply(self, id: Entity, world: &mut World) {
+        let Some(mut style) = world.get_mut::<DynamicStyle>(id) else {
+            warn!(
+                "Failed to play keyframes {}: Entity {:?} has no DynamicStyle!",
+                self.name, id
+            );
+            return;
+        };
+
+        let played = style.bypass_change_detection().play_keyframes(&self.name);
+        if !played {
+            warn!(
+                "Failed to play keyframes {}: Entity {:?} has no keyframes triggered by it",
+                self.name, id
+            );
+        }
+    }
+}
+
+pub trait PlayKeyframesExt {
+    /// Plays the keyframe animations of the entity's style triggered by command `name`
+    fn play_keyframes(&mut self, name: impl Into<String>) -> &mut Self;
+}
+
+impl PlayKeyframesExt for EntityCommands<'_> {
+    fn play_keyframes(&mut self, name: impl Into<String>) -> &mut Self {
+        self.add(PlayKeyframes { name: name.into() });
+
+        self
+    }
+}
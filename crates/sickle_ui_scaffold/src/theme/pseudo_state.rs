@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use bevy::{prelude::*, render::view::VisibilitySystems};
+use bevy::{ecs::system::SystemParam, prelude::*, render::view::VisibilitySystems};
 use serde::{Deserialize, Serialize};
 
 use crate::{ui_commands::ManagePseudoStateExt, CardinalDirection};
@@ -11,16 +11,21 @@ pub struct AutoPseudoStatePlugin;
 
 impl Plugin for AutoPseudoStatePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            PostUpdate,
-            propagate_flex_direction_to_pseudo_state.before(ThemeUpdate),
-        )
-        .add_systems(
-            PostUpdate,
-            propagate_visibility_to_pseudo_state
-                .after(VisibilitySystems::VisibilityPropagate)
-                .before(ThemeUpdate),
-        );
+        app.init_resource::<DefaultLayoutDirection>()
+            .add_systems(
+                PostUpdate,
+                (
+                    propagate_flex_direction_to_pseudo_state,
+                    propagate_layout_direction_to_pseudo_state,
+                )
+                    .before(ThemeUpdate),
+            )
+            .add_systems(
+                PostUpdate,
+                propagate_visibility_to_pseudo_state
+                    .after(VisibilitySystems::VisibilityPropagate)
+                    .before(ThemeUpdate),
+            );
     }
 }
 
@@ -75,6 +80,159 @@ fn propagate_visibility_to_pseudo_state(
     }
 }
 
+/// UI nodes whose resolved layout direction may have changed since the last run
+#[derive(SystemParam)]
+struct LayoutDirectionNodes<'w, 's> {
+    q_nodes: Query<'w, 's, Entity, With<Node>>,
+    q_added_nodes: Query<'w, 's, Entity, Added<Node>>,
+    q_reparented_nodes: Query<'w, 's, Entity, (Changed<Parent>, With<Node>)>,
+    removed_parents: RemovedComponents<'w, 's, Parent>,
+    q_children: Query<'w, 's, &'static Children>,
+}
+
+impl LayoutDirectionNodes<'_, '_> {
+    /// Added nodes, plus reparented nodes along with their descendants, which inherit the
+    /// direction of their new ancestry
+    fn outdated(&mut self) -> Vec<Entity> {
+        let removed_parents: Vec<Entity> = self
+            .removed_parents
+            .read()
+            .filter(|entity| self.q_nodes.contains(*entity))
+            .collect();
+
+        let mut entities: Vec<Entity> = self.q_added_nodes.iter().collect();
+        for entity in self.q_reparented_nodes.iter().chain(removed_parents) {
+            entities.push(entity);
+            entities.extend(self.q_children.iter_descendants(entity));
+        }
+
+        entities.sort_unstable();
+        entities.dedup();
+        entities
+    }
+}
+
+fn propagate_layout_direction_to_pseudo_state(
+    r_default: Res<DefaultLayoutDirection>,
+    q_changed_overrides: Query<
+        (Entity, &LayoutDirectionOverride),
+        Changed<LayoutDirectionOverride>,
+    >,
+    mut q_removed_overrides: RemovedComponents<LayoutDirectionOverride>,
+    mut nodes: LayoutDirectionNodes,
+    q_overrides: Query<&LayoutDirectionOverride>,
+    q_parents: Query<&Parent>,
+    q_pseudo_states: Query<&PseudoStates>,
+    mut q_styles: Query<&mut Style>,
+    mut commands: Commands,
+) {
+    for (entity, direction) in &q_changed_overrides {
+        if let Ok(mut style) = q_styles.get_mut(entity) {
+            let direction = direction.0.into();
+            if style.direction != direction {
+                style.direction = direction;
+            }
+        }
+    }
+
+    let full_update = r_default.is_changed()
+        || !q_changed_overrides.is_empty()
+        || q_removed_overrides.read().count() > 0;
+
+    let outdated = nodes.outdated();
+    let entities: Vec<Entity> = match full_update {
+        true => nodes.q_nodes.iter().collect(),
+        false => outdated,
+    };
+
+    for entity in entities {
+        let direction = LayoutDirection::resolve(entity, r_default.0, &q_overrides, &q_parents);
+        let has_state = q_pseudo_states
+            .get(entity)
+            .is_ok_and(|states| states.has(&PseudoState::RightToLeft));
+
+        if direction.is_right_to_left() && !has_state {
+            commands
+                .entity(entity)
+                .add_pseudo_state(PseudoState::RightToLeft);
+        } else if !direction.is_right_to_left() && has_state {
+            commands
+                .entity(entity)
+                .remove_pseudo_state(PseudoState::RightToLeft);
+        }
+    }
+}
+
+/// Horizontal direction of the UI. Widgets with directional layout (anchors, fill, chevrons,
+/// submenu opening side) mirror themselves when it is [`LayoutDirection::RightToLeft`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum LayoutDirection {
+    #[default]
+    LeftToRight,
+    RightToLeft,
+}
+
+impl From<LayoutDirection> for Direction {
+    fn from(value: LayoutDirection) -> Self {
+        match value {
+            LayoutDirection::LeftToRight => Direction::LeftToRight,
+            LayoutDirection::RightToLeft => Direction::RightToLeft,
+        }
+    }
+}
+
+impl LayoutDirection {
+    pub fn is_right_to_left(&self) -> bool {
+        *self == LayoutDirection::RightToLeft
+    }
+
+    /// Resolves the direction of an entity from the closest [`LayoutDirectionOverride`] in its
+    /// ancestry (inclusive), or the default direction if there is none
+    pub fn resolve(
+        entity: Entity,
+        default: LayoutDirection,
+        q_overrides: &Query<&LayoutDirectionOverride>,
+        q_parents: &Query<&Parent>,
+    ) -> LayoutDirection {
+        if let Ok(direction) = q_overrides.get(entity) {
+            return direction.0;
+        }
+
+        q_parents
+            .iter_ancestors(entity)
+            .find_map(|ancestor| q_overrides.get(ancestor).ok())
+            .map_or(default, |direction| direction.0)
+    }
+
+    /// Same as [`LayoutDirection::resolve`], for use in deferred styles and commands
+    pub fn of(entity: Entity, world: &World) -> LayoutDirection {
+        let mut current = Some(entity);
+        while let Some(entity) = current {
+            if let Some(direction) = world.get::<LayoutDirectionOverride>(entity) {
+                return direction.0;
+            }
+
+            current = world.get::<Parent>(entity).map(|parent| parent.get());
+        }
+
+        world
+            .get_resource::<DefaultLayoutDirection>()
+            .map_or(LayoutDirection::default(), |direction| direction.0)
+    }
+}
+
+/// The layout direction of every UI node without a [`LayoutDirectionOverride`] in its ancestry.
+/// Set it to [`LayoutDirection::RightToLeft`] for RTL locales.
+#[derive(Resource, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct DefaultLayoutDirection(pub LayoutDirection);
+
+/// Overrides the layout direction of the entity and its descendants. The entity's
+/// `Style::direction` is kept in sync with it.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct LayoutDirectionOverride(pub LayoutDirection);
+
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 pub struct VisibilityToPseudoState;
@@ -227,12 +385,15 @@ pub enum PseudoState {
     OddChild,
     LayoutRow,
     LayoutColumn,
+    RightToLeft,
     OverflowX,
     OverflowY,
     Folded,
     Open,
     Closed,
     Error,
+    DropAccepted,
+    DropRejected,
     Resizable(CardinalDirection),
     Custom(String),
 }
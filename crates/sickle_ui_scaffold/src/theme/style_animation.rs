@@ -1,4 +1,8 @@
-use std::{time::Duration, vec};
+use std::{
+    fmt::{Debug, Formatter, Result},
+    time::Duration,
+    vec,
+};
 
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -244,6 +248,9 @@ pub struct AnimationSettings {
     pub pressed: Option<LoopedAnimationConfig>,
     #[reflect(default)]
     pub delete_on_entered: bool,
+    #[reflect(ignore)]
+    #[serde(skip)]
+    pub on_complete: Option<AnimationCompleteCallback>,
 }
 
 macro_rules! transition_animation_setter {
@@ -343,6 +350,7 @@ impl AnimationSettings {
         self.hover = other.hover;
         self.pressed = other.pressed;
         self.delete_on_entered = other.delete_on_entered;
+        self.on_complete = other.on_complete;
 
         self
     }
@@ -377,6 +385,14 @@ impl AnimationSettings {
         self
     }
 
+    /// Calls `callback` each time the attribute reaches its target state
+    /// or completes an iteration of a looped animation, see [`AnimationCompleted`]
+    pub fn on_complete(&mut self, callback: fn(&AnimationCompleted, &mut World)) -> &mut Self {
+        self.on_complete = AnimationCompleteCallback(callback).into();
+
+        self
+    }
+
     pub fn to_tween(&self, flux_interaction: &FluxInteraction) -> Option<AnimationConfig> {
         match flux_interaction {
             FluxInteraction::None => self.enter,
@@ -460,6 +476,41 @@ impl AnimationSettings {
     }
 }
 
+#[derive(Clone, Copy)]
+pub struct AnimationCompleteCallback(pub fn(&AnimationCompleted, &mut World));
+
+impl Debug for AnimationCompleteCallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("AnimationCompleteCallback").finish()
+    }
+}
+
+impl PartialEq for AnimationCompleteCallback {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 as usize == other.0 as usize
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AnimationCompletion {
+    /// The transition to the target state finished, or there was none to play
+    Transition,
+    /// The looped animation of the target state finished the iteration
+    Loop(u8),
+}
+
+/// Sent when an animated attribute reaches its target state or completes an iteration
+/// of a looped animation. Changing to a state the attribute already holds is not reported.
+#[derive(Event, Clone, Copy, Debug, PartialEq)]
+pub struct AnimationCompleted {
+    /// The entity with the `DynamicStyle`
+    pub entity: Entity,
+    /// The entity the animated attribute is applied to
+    pub target: Entity,
+    pub state: InteractionStyle,
+    pub completion: AnimationCompletion,
+}
+
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct AnimationState {
     result: AnimationResult,
@@ -471,6 +522,14 @@ impl AnimationState {
         self.result.extract(bundle)
     }
 
+    pub fn iteration(&self) -> u8 {
+        self.iteration
+    }
+
+    pub fn is_holding(&self, style: InteractionStyle) -> bool {
+        self.result == AnimationResult::Hold(style)
+    }
+
     pub fn is_entering(&self) -> bool {
         match self.result {
             AnimationResult::Hold(style) => style == InteractionStyle::Enter,
@@ -43,6 +43,7 @@ impl Scheme {
 }
 
 #[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
 pub struct ThemeData {
     pub active_scheme: Scheme,
     pub colors: ThemeColors,
@@ -1,15 +1,16 @@
 use std::marker::PhantomData;
 
 use bevy::{
+    a11y::{accesskit::NodeBuilder, AccessibilityNode},
     core::Name,
     ecs::{
-        component::ComponentInfo,
+        component::{Component, ComponentInfo},
         entity::Entity,
-        query::With,
+        query::{Added, Changed, Or, With},
         system::{Command, Commands, EntityCommand, EntityCommands},
         world::{Mut, World},
     },
-    hierarchy::{Children, Parent},
+    hierarchy::Children,
     log::{info, warn},
     text::{Text, TextSection, TextStyle},
     ui::{Interaction, UiSurface},
@@ -22,7 +23,6 @@ use crate::{
     },
     prelude::UiUtils,
     theme::prelude::*,
-    ui_style::builder::StyleBuilder,
 };
 
 struct SetTextSections {
@@ -54,6 +54,101 @@ impl SetTextSectionsExt for EntityCommands<'_> {
     }
 }
 
+struct SetAccessibilityNode {
+    node: NodeBuilder,
+    name_from: Option<Entity>,
+}
+
+impl EntityCommand for SetAccessibilityNode {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let mut node = self.node;
+
+        if let Some(name_from) = self.name_from {
+            if let Some(name) = world
+                .get::<Text>(name_from)
+                .and_then(AccessibleNameFrom::name)
+            {
+                node.set_name(name);
+            }
+        }
+
+        let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+            warn!(
+                "Failed to set accessibility node on entity {:?}: Entity does not exist!",
+                entity
+            );
+            return;
+        };
+
+        // NOTE: Bounds are only recalculated when the node moves, so they must carry over
+        if let Some(mut accessibility_node) = entity_mut.get_mut::<AccessibilityNode>() {
+            if let Some(bounds) = accessibility_node.bounds() {
+                node.set_bounds(bounds);
+            }
+
+            *accessibility_node = AccessibilityNode::from(node);
+        } else {
+            entity_mut.insert(AccessibilityNode::from(node));
+        }
+
+        match self.name_from {
+            Some(name_from) => {
+                entity_mut.insert(AccessibleNameFrom(name_from));
+            }
+            None => {
+                entity_mut.remove::<AccessibleNameFrom>();
+            }
+        }
+    }
+}
+
+/// The entity whose text names the entity's accessibility node, i.e. a widget's label.
+/// Inserted by [`SetAccessibilityNodeExt::set_accessibility_node`], so the name can be kept in
+/// sync when the text changes later, like when it is translated.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct AccessibleNameFrom(pub Entity);
+
+impl AccessibleNameFrom {
+    /// The accessible name of a text, `None` if it is empty
+    pub fn name(text: &Text) -> Option<String> {
+        let name: String = text
+            .sections
+            .iter()
+            .map(|section| section.value.as_str())
+            .collect();
+
+        (!name.is_empty()).then_some(name)
+    }
+}
+
+/// Query filter for widgets whose accessibility node must be rebuilt: the widget changed,
+/// or its entity just received an `AccessibilityNode`.
+pub type AccessibilityNodeOutdated<W> = Or<(Changed<W>, Added<AccessibilityNode>)>;
+
+pub trait SetAccessibilityNodeExt {
+    /// Replaces the entity's accessibility node. When `name_from` is set, the node
+    /// is named after the text of that entity, i.e. a widget's label.
+    fn set_accessibility_node(
+        &mut self,
+        node: NodeBuilder,
+        name_from: impl Into<Option<Entity>>,
+    ) -> &mut Self;
+}
+
+impl SetAccessibilityNodeExt for EntityCommands<'_> {
+    fn set_accessibility_node(
+        &mut self,
+        node: NodeBuilder,
+        name_from: impl Into<Option<Entity>>,
+    ) -> &mut Self {
+        self.add(SetAccessibilityNode {
+            node,
+            name_from: name_from.into(),
+        });
+        self
+    }
+}
+
 struct SetText {
     text: String,
     style: TextStyle,
@@ -349,29 +444,8 @@ where
             None => &empty_pseudo_state,
         };
 
-        // Default -> General (App-wide) -> Specialized (Screen) theming is a reasonable guess.
-        // Round to 4, which is the first growth step.
-        // TODO: Cache most common theme count in theme data.
-        let mut themes: Vec<(&Theme<C>, Option<Entity>)> = Vec::with_capacity(4);
-        // Add own theme
-        if let Some(own_theme) = world.get::<Theme<C>>(entity) {
-            themes.push((own_theme, Some(entity)));
-        }
-
-        // Add all ancestor themes
-        let mut current_ancestor = entity;
-        while let Some(parent) = world.get::<Parent>(current_ancestor) {
-            current_ancestor = parent.get();
-            if let Some(ancestor_theme) = world.get::<Theme<C>>(current_ancestor) {
-                themes.push((ancestor_theme, Some(current_ancestor)));
-            }
-        }
-
         let default_theme = C::default_theme();
-        if let Some(ref default_theme) = default_theme {
-            themes.push((default_theme, None));
-        }
-
+        let themes = Theme::<C>::applied_themes(entity, &default_theme, world);
         if themes.len() == 0 {
             warn!(
                 "Theme missing for component {} on entity: {:?}",
@@ -381,89 +455,8 @@ where
             return;
         }
 
-        // The list contains themes in reverse order of application
-        themes.reverse();
-
-        // Assuming we have a base style and two-three pseudo state style is a reasonable guess.
-        // TODO: Cache most common pseudo theme count in theme data.
-        let mut pseudo_themes: Vec<(&PseudoTheme<C>, Option<Entity>)> =
-            Vec::with_capacity(themes.len() * 4);
-
-        for (theme, source_entity) in &themes {
-            if let Some(base_theme) = theme.pseudo_themes().iter().find(|pt| pt.is_base_theme()) {
-                pseudo_themes.push((base_theme, *source_entity));
-            }
-        }
-
-        if pseudo_states.len() > 0 {
-            for i in 0..pseudo_states.len() {
-                for (theme, source_entity) in &themes {
-                    theme
-                        .pseudo_themes()
-                        .iter()
-                        .filter(|pt| pt.count_match(pseudo_states) == i + 1)
-                        .for_each(|pt| pseudo_themes.push((pt, *source_entity)));
-                }
-            }
-        }
-
-        // Merge base attributes on top of the default and down the chain, overwriting per-attribute at each level
-        let styles: Vec<(Option<Entity>, DynamicStyle)> = pseudo_themes
-            .iter()
-            .map(
-                |(pseudo_theme, source_entity)| match pseudo_theme.builder().clone() {
-                    DynamicStyleBuilder::Static(style) => vec![(None, style.clone())],
-                    DynamicStyleBuilder::StyleBuilder(builder) => {
-                        let mut style_builder = StyleBuilder::new();
-                        builder(&mut style_builder, &theme_data);
-
-                        style_builder.convert_with(&context)
-                    }
-                    DynamicStyleBuilder::ContextStyleBuilder(builder) => {
-                        let mut style_builder = StyleBuilder::new();
-                        builder(&mut style_builder, &context, &theme_data);
-
-                        style_builder.convert_with(&context)
-                    }
-                    DynamicStyleBuilder::WorldStyleBuilder(builder) => {
-                        let mut style_builder = StyleBuilder::new();
-                        builder(&mut style_builder, entity, &context, world);
-
-                        style_builder.convert_with(&context)
-                    }
-                    DynamicStyleBuilder::InfoWorldStyleBuilder(builder) => {
-                        let mut style_builder = StyleBuilder::new();
-                        builder(
-                            &mut style_builder,
-                            *source_entity,
-                            pseudo_theme.state(),
-                            entity,
-                            &context,
-                            world,
-                        );
-
-                        style_builder.convert_with(&context)
-                    }
-                },
-            )
-            .filter(|e_to_dys| e_to_dys.len() > 0)
-            .fold(
-                Vec::with_capacity(context.contexts().len() + 1),
-                |mut acc, context_styles| {
-                    for context_style in context_styles {
-                        let index = acc.iter().position(|entry| entry.0 == context_style.0);
-                        match index {
-                            Some(index) => {
-                                let (_, prev_entry) = acc[index].clone();
-                                acc[index].1 = prev_entry.merge(context_style.1);
-                            }
-                            None => acc.push(context_style),
-                        }
-                    }
-
-                    acc
-                },
-            );
+        let pseudo_themes = Theme::<C>::applied_pseudo_themes(&themes, pseudo_states);
+        let styles = Theme::<C>::build_styles(&pseudo_themes, entity, &context, &theme_data, world);
 
         let mut cleanup_main_style = true;
         let mut unstyled_entities: Vec<Entity> = context
@@ -12,8 +12,14 @@ use generated::LockableStyleAttribute;
 
 pub mod prelude {
     pub use super::{
-        attribute::{AnimatedVals, InteractiveVals},
-        builder::StyleBuilder,
+        attribute::{
+            AnimatedVals, CustomAnimatedStyleAttribute, CustomInteractiveStyleAttribute,
+            CustomKeyframesStyleAttribute, CustomStaticStyleAttribute, DeclaredStyleAttribute,
+            InteractiveVals,
+        },
+        builder::{
+            AnimatedStyleBuilder, InteractiveStyleBuilder, KeyframesStyleBuilder, StyleBuilder,
+        },
         generated::*,
         manual::*,
         *,
@@ -105,6 +111,10 @@ impl LockedStyleAttributes {
     pub fn contains(&self, attr: LockableStyleAttribute) -> bool {
         self.0.contains(&attr)
     }
+
+    pub fn iter(&self) -> impl Iterator<Item = &LockableStyleAttribute> {
+        self.0.iter()
+    }
 }
 
 impl From<LockableStyleAttribute> for HashSet<LockableStyleAttribute> {
@@ -1,4 +1,5 @@
 use std::{
+    any::{Any, TypeId},
     fmt::{Debug, Formatter, Result},
     sync::Arc,
 };
@@ -149,17 +150,110 @@ impl<T: Lerp + Default + Clone + PartialEq> AnimatedVals<T> {
     }
 }
 
+/// The identity and value of a custom attribute declared with `#[derive(StyleCommands)]`.
+///
+/// Declared attributes replace each other and keep their animation state the same way
+/// built-in attributes do, instead of being compared by callback.
+#[derive(Clone)]
+pub struct DeclaredStyleAttribute {
+    key: TypeId,
+    value: Arc<dyn Any + Send + Sync>,
+    value_eq: fn(&dyn Any, &dyn Any) -> bool,
+}
+
+impl DeclaredStyleAttribute {
+    /// `K` identifies the attribute, `value` is compared to decide if it changed
+    pub fn new<K: 'static, V: PartialEq + Send + Sync + 'static>(value: V) -> Self {
+        Self {
+            key: TypeId::of::<K>(),
+            value: Arc::new(value),
+            value_eq: declared_value_eq::<V>,
+        }
+    }
+
+    pub fn key(&self) -> TypeId {
+        self.key
+    }
+}
+
+impl PartialEq for DeclaredStyleAttribute {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && (self.value_eq)(self.value.as_ref(), other.value.as_ref())
+    }
+}
+
+fn declared_value_eq<V: PartialEq + 'static>(l0: &dyn Any, r0: &dyn Any) -> bool {
+    match (l0.downcast_ref::<V>(), r0.downcast_ref::<V>()) {
+        (Some(l0), Some(r0)) => l0 == r0,
+        _ => false,
+    }
+}
+
+/// Custom attributes are logically equal if they are the same declared attribute,
+/// or the same callback otherwise.
+fn custom_logical_eq<T: ?Sized>(
+    l_declared: &Option<DeclaredStyleAttribute>,
+    l_callback: &Arc<T>,
+    r_declared: &Option<DeclaredStyleAttribute>,
+    r_callback: &Arc<T>,
+) -> bool {
+    match (l_declared, r_declared) {
+        (Some(l0), Some(r0)) => l0.key == r0.key,
+        (None, None) => Arc::ptr_eq(l_callback, r_callback),
+        _ => false,
+    }
+}
+
+fn custom_eq<T: ?Sized>(
+    l_declared: &Option<DeclaredStyleAttribute>,
+    l_callback: &Arc<T>,
+    r_declared: &Option<DeclaredStyleAttribute>,
+    r_callback: &Arc<T>,
+) -> bool {
+    match (l_declared, r_declared) {
+        (Some(l0), Some(r0)) => l0 == r0,
+        (None, None) => Arc::ptr_eq(l_callback, r_callback),
+        _ => false,
+    }
+}
+
 #[derive(Clone)]
 pub struct CustomStaticStyleAttribute {
     pub callback: Arc<dyn Fn(Entity, &mut World) + Send + Sync + 'static>,
+    pub declared: Option<DeclaredStyleAttribute>,
 }
 
 impl CustomStaticStyleAttribute {
     pub fn new(callback: impl Fn(Entity, &mut World) + Send + Sync + 'static) -> Self {
         Self {
             callback: Arc::new(callback),
+            declared: None,
         }
     }
+
+    pub fn declared(
+        declared: DeclaredStyleAttribute,
+        callback: impl Fn(Entity, &mut World) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            callback: Arc::new(callback),
+            declared: declared.into(),
+        }
+    }
+
+    pub fn logical_eq(&self, other: &Self) -> bool {
+        custom_logical_eq(
+            &self.declared,
+            &self.callback,
+            &other.declared,
+            &other.callback,
+        )
+    }
+
+    /// The key of the declared attribute, if any
+    pub fn declared_key(&self) -> Option<TypeId> {
+        self.declared.as_ref().map(DeclaredStyleAttribute::key)
+    }
 }
 
 impl Debug for CustomStaticStyleAttribute {
@@ -170,13 +264,19 @@ impl Debug for CustomStaticStyleAttribute {
 
 impl PartialEq for CustomStaticStyleAttribute {
     fn eq(&self, other: &Self) -> bool {
-        Arc::ptr_eq(&self.callback, &other.callback)
+        custom_eq(
+            &self.declared,
+            &self.callback,
+            &other.declared,
+            &other.callback,
+        )
     }
 }
 
 #[derive(Clone)]
 pub struct CustomInteractiveStyleAttribute {
     pub callback: Arc<dyn Fn(Entity, FluxInteraction, &mut World) + Send + Sync + 'static>,
+    pub declared: Option<DeclaredStyleAttribute>,
 }
 
 impl CustomInteractiveStyleAttribute {
@@ -185,8 +285,33 @@ impl CustomInteractiveStyleAttribute {
     ) -> Self {
         Self {
             callback: Arc::new(callback),
+            declared: None,
+        }
+    }
+
+    pub fn declared(
+        declared: DeclaredStyleAttribute,
+        callback: impl Fn(Entity, FluxInteraction, &mut World) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            callback: Arc::new(callback),
+            declared: declared.into(),
         }
     }
+
+    pub fn logical_eq(&self, other: &Self) -> bool {
+        custom_logical_eq(
+            &self.declared,
+            &self.callback,
+            &other.declared,
+            &other.callback,
+        )
+    }
+
+    /// The key of the declared attribute, if any
+    pub fn declared_key(&self) -> Option<TypeId> {
+        self.declared.as_ref().map(DeclaredStyleAttribute::key)
+    }
 }
 
 impl Debug for CustomInteractiveStyleAttribute {
@@ -197,13 +322,19 @@ impl Debug for CustomInteractiveStyleAttribute {
 
 impl PartialEq for CustomInteractiveStyleAttribute {
     fn eq(&self, other: &Self) -> bool {
-        Arc::ptr_eq(&self.callback, &other.callback)
+        custom_eq(
+            &self.declared,
+            &self.callback,
+            &other.declared,
+            &other.callback,
+        )
     }
 }
 
 #[derive(Clone)]
 pub struct CustomAnimatedStyleAttribute {
     pub callback: Arc<dyn Fn(Entity, AnimationState, &mut World) + Send + Sync + 'static>,
+    pub declared: Option<DeclaredStyleAttribute>,
 }
 
 impl CustomAnimatedStyleAttribute {
@@ -212,8 +343,33 @@ impl CustomAnimatedStyleAttribute {
     ) -> Self {
         Self {
             callback: Arc::new(callback),
+            declared: None,
+        }
+    }
+
+    pub fn declared(
+        declared: DeclaredStyleAttribute,
+        callback: impl Fn(Entity, AnimationState, &mut World) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            callback: Arc::new(callback),
+            declared: declared.into(),
         }
     }
+
+    pub fn logical_eq(&self, other: &Self) -> bool {
+        custom_logical_eq(
+            &self.declared,
+            &self.callback,
+            &other.declared,
+            &other.callback,
+        )
+    }
+
+    /// The key of the declared attribute, if any
+    pub fn declared_key(&self) -> Option<TypeId> {
+        self.declared.as_ref().map(DeclaredStyleAttribute::key)
+    }
 }
 
 impl Debug for CustomAnimatedStyleAttribute {
@@ -224,7 +380,79 @@ impl Debug for CustomAnimatedStyleAttribute {
 
 impl PartialEq for CustomAnimatedStyleAttribute {
     fn eq(&self, other: &Self) -> bool {
-        Arc::ptr_eq(&self.callback, &other.callback)
+        custom_eq(
+            &self.declared,
+            &self.callback,
+            &other.declared,
+            &other.callback,
+        )
+    }
+}
+
+/// A custom keyframe animation of `duration` seconds, the callback receives the playhead
+#[derive(Clone)]
+pub struct CustomKeyframesStyleAttribute {
+    pub callback: Arc<dyn Fn(Entity, f32, &mut World) + Send + Sync + 'static>,
+    pub duration: f32,
+    pub declared: Option<DeclaredStyleAttribute>,
+}
+
+impl CustomKeyframesStyleAttribute {
+    pub fn new(
+        duration: f32,
+        callback: impl Fn(Entity, f32, &mut World) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            callback: Arc::new(callback),
+            duration,
+            declared: None,
+        }
+    }
+
+    pub fn declared(
+        declared: DeclaredStyleAttribute,
+        duration: f32,
+        callback: impl Fn(Entity, f32, &mut World) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            callback: Arc::new(callback),
+            duration,
+            declared: declared.into(),
+        }
+    }
+
+    pub fn logical_eq(&self, other: &Self) -> bool {
+        custom_logical_eq(
+            &self.declared,
+            &self.callback,
+            &other.declared,
+            &other.callback,
+        )
+    }
+
+    /// The key of the declared attribute, if any
+    pub fn declared_key(&self) -> Option<TypeId> {
+        self.declared.as_ref().map(DeclaredStyleAttribute::key)
+    }
+}
+
+impl Debug for CustomKeyframesStyleAttribute {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("CustomKeyframesStyleAttribute")
+            .field("duration", &self.duration)
+            .finish()
+    }
+}
+
+impl PartialEq for CustomKeyframesStyleAttribute {
+    fn eq(&self, other: &Self) -> bool {
+        self.duration == other.duration
+            && custom_eq(
+                &self.declared,
+                &self.callback,
+                &other.declared,
+                &other.callback,
+            )
     }
 }
 
@@ -259,3 +487,14 @@ impl EntityCommand for ApplyCustomAnimatadStyleAttribute {
         (self.callback.callback)(id, self.current_state, world);
     }
 }
+
+pub struct ApplyCustomKeyframesStyleAttribute {
+    pub callback: CustomKeyframesStyleAttribute,
+    pub playhead: f32,
+}
+
+impl EntityCommand for ApplyCustomKeyframesStyleAttribute {
+    fn apply(self, id: Entity, world: &mut World) {
+        (self.callback.callback)(id, self.playhead, world);
+    }
+}
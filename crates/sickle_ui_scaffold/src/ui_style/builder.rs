@@ -2,7 +2,11 @@ use bevy::prelude::*;
 
 use crate::theme::prelude::*;
 
-use super::{attribute::CustomAnimatedStyleAttribute, generated::*, LogicalEq};
+use super::{
+    attribute::{CustomAnimatedStyleAttribute, CustomKeyframesStyleAttribute},
+    generated::*,
+    LogicalEq,
+};
 
 pub struct InteractiveStyleBuilder<'a> {
     pub style_builder: &'a mut StyleBuilder,
@@ -45,6 +49,47 @@ impl AnimatedStyleBuilder<'_> {
     }
 }
 
+pub struct KeyframesStyleBuilder<'a> {
+    pub style_builder: &'a mut StyleBuilder,
+}
+
+impl KeyframesStyleBuilder<'_> {
+    pub fn add_and_extract_settings(
+        &mut self,
+        attribute: DynamicStyleAttribute,
+    ) -> &mut KeyframeSettings {
+        let index = self.style_builder.add(attribute.clone());
+
+        let DynamicStyleAttribute::Keyframes {
+            controller: KeyframesController {
+                ref mut settings, ..
+            },
+            ..
+        } = self.style_builder.attributes[index].attribute
+        else {
+            unreachable!();
+        };
+
+        settings
+    }
+
+    /// `duration` is the length of the animation in seconds, `callback` receives the playhead
+    pub fn custom(
+        &mut self,
+        duration: f32,
+        callback: impl Fn(Entity, f32, &mut World) + Send + Sync + 'static,
+    ) -> &mut KeyframeSettings {
+        let attribute = DynamicStyleAttribute::Keyframes {
+            attribute: KeyframesStyleAttribute::Custom(CustomKeyframesStyleAttribute::new(
+                duration, callback,
+            )),
+            controller: KeyframesController::default(),
+        };
+
+        self.add_and_extract_settings(attribute)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct ContextStyleAttributeConfig {
     placement: Option<&'static str>,
@@ -148,6 +193,12 @@ impl StyleBuilder {
         }
     }
 
+    pub fn keyframes(&mut self) -> KeyframesStyleBuilder {
+        KeyframesStyleBuilder {
+            style_builder: self,
+        }
+    }
+
     /// Switch context of styling by changing the placement of the DynamicStyle and the target of interaction styling.
     /// Values are mapped to the UiContext of the themed component. `None` placement refers to the main entity.
     /// `None` target refers to the current placement entity.
@@ -7,10 +7,11 @@ use crate::{flux_interaction::FluxInteraction, theme::prelude::*};
 use super::{
     attribute::{
         ApplyCustomAnimatadStyleAttribute, ApplyCustomInteractiveStyleAttribute,
-        ApplyCustomStaticStyleAttribute, CustomAnimatedStyleAttribute,
-        CustomInteractiveStyleAttribute, CustomStaticStyleAttribute, InteractiveVals,
+        ApplyCustomKeyframesStyleAttribute, ApplyCustomStaticStyleAttribute,
+        CustomAnimatedStyleAttribute, CustomInteractiveStyleAttribute,
+        CustomKeyframesStyleAttribute, CustomStaticStyleAttribute, InteractiveVals,
     },
-    builder::{AnimatedStyleBuilder, InteractiveStyleBuilder, StyleBuilder},
+    builder::{AnimatedStyleBuilder, InteractiveStyleBuilder, KeyframesStyleBuilder, StyleBuilder},
     manual::{ImageSource, SetAbsolutePositionExt, SetFluxInteractionExt, SetImageExt},
     AnimatedVals, LockedStyleAttributes, LogicalEq, TrackedStyleState, UiStyle, UiStyleUnchecked,
 };
@@ -1,17 +1,19 @@
-//! [Epilepsy WARNING] An example using the widget library to test performance for DynamicStyles and Theme application.
+//! An example using the widget library to test performance for DynamicStyles and Theme application.
+//! Edit `ThemeData` in the resource inspector to re-theme all boxes at once.
 use bevy::{
     diagnostic::{FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin},
     prelude::*,
-    time::Stopwatch,
+    reflect::TypePath,
 };
 
 use sickle_macros::UiContext;
 use sickle_math::ease::Ease;
 use sickle_ui::{
+    dev_panels::resource_inspector::{ResourceInspectorPlugin, UiResourceInspectorExt},
     theme::{
         pseudo_state::{PseudoState, PseudoStates},
         style_animation::AnimationLoop,
-        theme_data::{Contrast, Scheme, ThemeData},
+        theme_data::ThemeData,
         ComponentThemePlugin, DefaultTheme, PseudoTheme, Theme, UiContext,
     },
     ui_builder::{UiBuilder, UiBuilderExt, UiRoot},
@@ -39,39 +41,14 @@ fn main() {
         .add_plugins(SickleUiPlugin)
         .init_resource::<IconCache>()
         .add_plugins(ComponentThemePlugin::<ThemeTestBox>::new())
+        .add_plugins(ResourceInspectorPlugin)
         .add_systems(Startup, setup)
-        .add_systems(Update, (tick_dynamic_style_stopwatch, toggle_theme_data))
         .run();
 }
 
-fn tick_dynamic_style_stopwatch(time: Res<Time<Real>>, mut q_stopwatch: Query<&mut TestStopwatch>) {
-    let mut stopwatch = q_stopwatch.single_mut();
-    stopwatch.0.tick(time.delta());
-}
-
-fn toggle_theme_data(
-    mut theme_data: ResMut<ThemeData>,
-    mut q_stopwatch: Query<&mut TestStopwatch>,
-) {
-    let mut stopwatch = q_stopwatch.single_mut();
-
-    if stopwatch.0.elapsed_secs() > 0.2 {
-        stopwatch.0.reset();
-
-        if theme_data.active_scheme.is_dark() {
-            theme_data.active_scheme = Scheme::Light(Contrast::Standard);
-        } else {
-            theme_data.active_scheme = Scheme::Dark(Contrast::Standard);
-        }
-    }
-}
-
 #[derive(Component)]
 pub struct UiCamera;
 
-#[derive(Component)]
-pub struct TestStopwatch(Stopwatch);
-
 #[derive(Resource, Debug, Default, Reflect)]
 #[reflect(Resource)]
 struct IconCache(Vec<Handle<Image>>);
@@ -265,13 +242,10 @@ fn setup(
 
     commands.ui_builder(UiRoot).container(
         (
-            TestStopwatch(Stopwatch::new()),
             NodeBundle {
                 style: Style {
                     width: Val::Percent(100.0),
                     height: Val::Percent(100.0),
-                    flex_direction: FlexDirection::Column,
-                    flex_wrap: FlexWrap::Wrap,
                     flex_grow: 1.,
                     justify_self: JustifySelf::Stretch,
                     align_self: AlignSelf::Stretch,
@@ -283,37 +257,49 @@ fn setup(
             TargetCamera(main_camera),
         ),
         |container| {
-            // container.spawn((NodeBundle::default(), ThemeTestBox));
-            for i in 0..14 {
-                let mut row = container.row(|row| {
-                    let mut column = row.column(|column| {
-                        column.row(|inner_row| {
-                            for _ in 0..12 {
-                                spawn_test_content(inner_row);
-                            }
+            container.column(|boxes| {
+                boxes.style().flex_grow(1.).flex_wrap(FlexWrap::Wrap);
+
+                // boxes.spawn((NodeBundle::default(), ThemeTestBox));
+                for i in 0..14 {
+                    let mut row = boxes.row(|row| {
+                        let mut column = row.column(|column| {
+                            column.row(|inner_row| {
+                                for _ in 0..12 {
+                                    spawn_test_content(inner_row);
+                                }
+                            });
                         });
-                    });
-                    column.style().width(Val::Percent(50.));
+                        column.style().width(Val::Percent(50.));
 
-                    if i % 3 == 0 {
-                        column.insert(ThemeTestBox::third_theme());
-                    }
+                        if i % 3 == 0 {
+                            column.insert(ThemeTestBox::third_theme());
+                        }
 
-                    row.column(|column| {
-                        column.row(|inner_row| {
-                            for _ in 0..12 {
-                                spawn_test_content(inner_row);
-                            }
-                        });
-                    })
-                    .style()
-                    .width(Val::Percent(50.));
-                });
+                        row.column(|column| {
+                            column.row(|inner_row| {
+                                for _ in 0..12 {
+                                    spawn_test_content(inner_row);
+                                }
+                            });
+                        })
+                        .style()
+                        .width(Val::Percent(50.));
+                    });
 
-                if i % 2 == 1 {
-                    row.insert(ThemeTestBox::second_theme());
+                    if i % 2 == 1 {
+                        row.insert(ThemeTestBox::second_theme());
+                    }
                 }
-            }
+            });
+
+            container
+                .column(|column| {
+                    column.resource_inspector(Some(ThemeData::type_path().into()));
+                })
+                .style()
+                .width(Val::Px(400.))
+                .height(Val::Percent(100.));
         },
     );
 }
@@ -4,6 +4,7 @@ use bevy::prelude::*;
 use sickle_ui::{
     dev_panels::{
         hierarchy::{HierarchyTreeViewPlugin, UiHierarchyExt},
+        layout_debug_overlay::LayoutDebugOverlayPlugin,
         scene_view::{SceneView, SceneViewPlugin, SpawnSceneViewPreUpdate, UiSceneViewExt},
     },
     prelude::*,
@@ -28,6 +29,7 @@ fn main() {
         .init_state::<Page>()
         .add_plugins(HierarchyTreeViewPlugin)
         .add_plugins(SceneViewPlugin)
+        .add_plugins(LayoutDebugOverlayPlugin)
         .add_systems(Startup, setup.in_set(UiStartupSet))
         .add_systems(OnEnter(Page::Layout), layout_showcase)
         .add_systems(OnExit(Page::Layout), clear_content_on_menu_change)
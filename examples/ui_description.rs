@@ -0,0 +1,62 @@
+//! Spawns a settings screen described in `assets/examples/settings.ui.ron`.
+//!
+//! Run with `cargo run --example ui_description --features bevy/file_watcher` and edit the
+//! file while the example runs to see the screen rebuilt.
+use bevy::prelude::*;
+
+use sickle_ui::{
+    prelude::*,
+    ui_builder::{UiBuilderExt, UiRoot},
+    ui_description::{UiDescriptionExt, UiDescriptionSpawned},
+    SickleUiPlugin,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Sickle UI - UI Description".into(),
+                resolution: (1280., 720.).into(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(SickleUiPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (log_spawned, log_changes))
+        .run();
+}
+
+fn setup(asset_server: Res<AssetServer>, mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+    commands
+        .ui_builder(UiRoot)
+        .ui_description(asset_server.load("examples/settings.ui.ron"))
+        .style()
+        .height(Val::Percent(100.));
+}
+
+fn log_spawned(mut events: EventReader<UiDescriptionSpawned>) {
+    for event in events.read() {
+        info!("Spawned settings into {:?}", event.entity);
+    }
+}
+
+fn log_changes(
+    mut sliders: EventReader<SliderChanged>,
+    mut checkboxes: EventReader<CheckboxChanged>,
+    q_names: Query<&Name>,
+) {
+    let name = |entity: Entity| match q_names.get(entity) {
+        Ok(name) => name.to_string(),
+        Err(_) => format!("{:?}", entity),
+    };
+
+    for event in sliders.read() {
+        info!("{} changed to {}", name(event.entity), event.value);
+    }
+
+    for event in checkboxes.read() {
+        info!("{} changed to {}", name(event.entity), event.value);
+    }
+}
@@ -1,3 +1,7 @@
 pub mod entity_component_list;
+pub mod entity_inspector;
 pub mod hierarchy;
+pub mod layout_debug_overlay;
+pub mod resource_inspector;
 pub mod scene_view;
+pub mod style_inspector;
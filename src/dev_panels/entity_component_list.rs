@@ -97,9 +97,10 @@ impl Plugin for EntityComponentTagPlugin {
     }
 }
 
-#[derive(Component, Clone, Debug, Reflect)]
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct EntityComponentTag {
+    #[context("Label")]
     label: Entity,
 }
 
@@ -117,26 +118,7 @@ impl DefaultTheme for EntityComponentTag {
     }
 }
 
-impl UiContext for EntityComponentTag {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            EntityComponentTag::LABEL => Ok(self.label),
-            _ => Err(format!(
-                "{} doesn't exists for EntityComponentTag. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
-    }
-
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![EntityComponentTag::LABEL]
-    }
-}
-
 impl EntityComponentTag {
-    pub const LABEL: &'static str = "Label";
-
     pub fn theme() -> Theme<EntityComponentTag> {
         let base_theme = PseudoTheme::deferred(None, EntityComponentTag::primary_style);
         Theme::new(vec![base_theme])
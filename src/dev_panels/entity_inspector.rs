@@ -0,0 +1,1340 @@
+use std::any::TypeId;
+
+use bevy::{
+    ecs::{
+        component::{ComponentId, Tick},
+        system::CommandQueue,
+    },
+    input::mouse::MouseScrollUnit,
+    prelude::*,
+    reflect::{
+        DynamicEnum, DynamicStruct, DynamicTuple, DynamicVariant, List, ReflectMut, ReflectRef,
+        TypeInfo, TypeRegistry, VariantInfo,
+    },
+    ui::RelativeCursorPosition,
+    utils::HashSet,
+};
+
+use sickle_macros::UiContext;
+use sickle_ui_scaffold::{prelude::*, ui_commands::UpdateTextExt};
+
+use crate::widgets::{
+    binding::{BindableWidget, BindingSource, WidgetBinding, WidgetBindingPlugin},
+    inputs::{checkbox::UiCheckboxExt, dropdown::UiDropdownExt},
+    layout::{
+        column::UiColumnExt,
+        container::UiContainerExt,
+        foldable::{Foldable, UiFoldableExt},
+        label::{LabelConfig, UiLabelExt},
+        row::UiRowExt,
+    },
+    ValueChangeSource, WidgetEvent, WidgetEventPlugin,
+};
+
+pub struct EntityInspectorPlugin;
+
+impl Plugin for EntityInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            ComponentThemePlugin::<InspectorNumberField>::default(),
+            WidgetEventPlugin::<InspectorNumberFieldChanged>::default(),
+            WidgetBindingPlugin::<InspectorNumberField>::default(),
+        ))
+        .add_systems(
+            Update,
+            (
+                update_number_field_on_drag.after(DraggableUpdate),
+                update_number_field_on_scroll.after(ScrollableUpdate),
+                update_number_field_readout,
+                handle_list_button_press,
+                update_entity_inspectors,
+                update_inspector_value_labels,
+            )
+                .chain(),
+        );
+    }
+}
+
+fn update_entity_inspectors(world: &mut World) {
+    let this_run = world.read_change_tick();
+    let inspectors: Vec<(Entity, Option<Entity>, bool)> = world
+        .query::<(Entity, Ref<EntityInspector>)>()
+        .iter(world)
+        .map(|(entity, inspector)| (entity, inspector.entity, inspector.is_changed()))
+        .collect();
+
+    for (container, selected, changed) in inspectors {
+        let components = selected.map_or_else(Vec::new, |selected| {
+            inspected_component_ids(selected, world)
+        });
+
+        // Safe unwrap: queried above
+        let inspector = world.get::<EntityInspector>(container).unwrap();
+        let components_changed = selected.is_some_and(|selected| {
+            inspector.components != components
+                || inspector.last_checked.map_or(true, |last_checked| {
+                    any_component_changed(selected, &components, last_checked, this_run, world)
+                })
+        });
+
+        let mut inspector = world.get_mut::<EntityInspector>(container).unwrap();
+        let inspector = inspector.bypass_change_detection();
+        inspector.last_checked = Some(this_run);
+        if !changed && !components_changed {
+            continue;
+        }
+        inspector.components = components;
+
+        let shape = selected.and_then(|selected| inspected_shape(selected, world));
+        let mut inspector = world.get_mut::<EntityInspector>(container).unwrap();
+        let inspector = inspector.bypass_change_detection();
+        if !changed && inspector.shape == shape {
+            continue;
+        }
+        inspector.shape = shape;
+
+        rebuild_entity_inspector(container, selected, world);
+    }
+}
+
+fn inspected_component_ids(entity: Entity, world: &World) -> Vec<ComponentId> {
+    if world.get_entity(entity).is_none() {
+        return Vec::new();
+    }
+
+    world
+        .inspect_entity(entity)
+        .iter()
+        .map(|info| info.id())
+        .collect()
+}
+
+/// Whether any of the entity's `components` changed since `last_checked`.
+/// The shape of the entity only needs to be collected again if they did.
+fn any_component_changed(
+    entity: Entity,
+    components: &[ComponentId],
+    last_checked: Tick,
+    this_run: Tick,
+    world: &World,
+) -> bool {
+    let Some(entity_ref) = world.get_entity(entity) else {
+        return false;
+    };
+
+    components.iter().any(|component| {
+        entity_ref
+            .get_change_ticks_by_id(*component)
+            .is_some_and(|ticks| ticks.is_changed(last_checked, this_run))
+    })
+}
+
+/// Structural fingerprint of the inspected entity: its components, list lengths and active enum
+/// variants. The inspector is rebuilt when this changes; field values are synced via bindings.
+fn inspected_shape(entity: Entity, world: &World) -> Option<Vec<usize>> {
+    let entity_ref = world.get_entity(entity)?;
+    let registry = world.resource::<AppTypeRegistry>().read();
+
+    let mut shape = Vec::new();
+    for info in world.inspect_entity(entity) {
+        shape.push(info.id().index());
+
+        let Some(reflected) = info
+            .type_id()
+            .and_then(|type_id| registry.get_type_data::<ReflectComponent>(type_id))
+            .and_then(|reflect_component| reflect_component.reflect(entity_ref))
+        else {
+            continue;
+        };
+
+        collect_shape(reflected, &mut shape);
+    }
+
+    Some(shape)
+}
+
+/// Appends the list lengths and active enum variants of `value` to `shape`
+pub(crate) fn collect_shape(value: &dyn Reflect, shape: &mut Vec<usize>) {
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => value.iter_fields().for_each(|f| collect_shape(f, shape)),
+        ReflectRef::TupleStruct(value) => value.iter_fields().for_each(|f| collect_shape(f, shape)),
+        ReflectRef::Tuple(value) => value.iter_fields().for_each(|f| collect_shape(f, shape)),
+        ReflectRef::List(value) => {
+            shape.push(value.len());
+            value.iter().for_each(|f| collect_shape(f, shape));
+        }
+        ReflectRef::Array(value) => value.iter().for_each(|f| collect_shape(f, shape)),
+        ReflectRef::Enum(value) => {
+            shape.push(value.variant_index());
+            value
+                .iter_fields()
+                .for_each(|f| collect_shape(f.value(), shape));
+        }
+        ReflectRef::Map(value) => shape.push(value.len()),
+        ReflectRef::Value(_) => (),
+    }
+}
+
+fn rebuild_entity_inspector(container: Entity, selected: Option<Entity>, world: &mut World) {
+    let values = match selected {
+        Some(selected) if world.get_entity(selected).is_some() => {
+            inspected_components(selected, world)
+        }
+        _ => Vec::new(),
+    };
+
+    rebuild_inspector(container, values, world);
+}
+
+fn inspected_components(entity: Entity, world: &World) -> Vec<InspectedValue> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+
+    world
+        .inspect_entity(entity)
+        .into_iter()
+        .map(|info| {
+            let name = UiUtils::simplify_component_name(info);
+            let target = info
+                .type_id()
+                .and_then(|type_id| registry.get_type_data::<ReflectComponent>(type_id))
+                .map(|reflect_component| {
+                    InspectorTarget::Component(entity, reflect_component.clone())
+                });
+
+            let fields = InspectorItem::fields_of(target.as_ref(), world, &registry);
+
+            InspectedValue {
+                target,
+                key_prefix: name.clone(),
+                items: vec![InspectorItem::Group {
+                    name,
+                    path: String::new(),
+                    fields,
+                }],
+            }
+        })
+        .collect()
+}
+
+/// A reflected value and the editors of its fields
+pub(crate) struct InspectedValue {
+    pub target: Option<InspectorTarget>,
+    pub key_prefix: String,
+    pub items: Vec<InspectorItem>,
+}
+
+/// Replaces the content of an inspector `container` with editors for `values`.
+/// Sections that were open before the rebuild remain open.
+pub(crate) fn rebuild_inspector(container: Entity, values: Vec<InspectedValue>, world: &mut World) {
+    let open_sections: HashSet<String> = world
+        .query::<(&InspectorSection, &Foldable)>()
+        .iter(world)
+        .filter(|(section, foldable)| section.inspector == container && foldable.open)
+        .map(|(section, _)| section.key.clone())
+        .collect();
+
+    let mut queue = CommandQueue::default();
+    let mut commands = Commands::new(&mut queue, world);
+
+    commands.entity(container).despawn_descendants();
+    let mut builder = commands.ui_builder(container);
+    for value in values {
+        let context = InspectorContext {
+            inspector: container,
+            target: value.target,
+            key_prefix: value.key_prefix,
+            open_sections: &open_sections,
+        };
+
+        for item in value.items {
+            spawn_inspector_item(&mut builder, item, &context);
+        }
+    }
+
+    queue.apply(world);
+}
+
+/// Editor description of a reflected value, collected before the inspector is spawned
+pub(crate) enum InspectorItem {
+    Bool {
+        name: String,
+        path: String,
+        value: bool,
+    },
+    Number {
+        name: String,
+        path: String,
+        kind: NumberKind,
+        value: f64,
+    },
+    Enum {
+        name: String,
+        path: String,
+        variants: Vec<String>,
+        selected: usize,
+        fields: Vec<InspectorItem>,
+    },
+    Group {
+        name: String,
+        path: String,
+        fields: Vec<InspectorItem>,
+    },
+    List {
+        name: String,
+        path: String,
+        items: Vec<InspectorItem>,
+    },
+    Value {
+        name: String,
+        path: String,
+        text: String,
+    },
+}
+
+impl InspectorItem {
+    pub(crate) fn fields_of(
+        target: Option<&InspectorTarget>,
+        world: &World,
+        registry: &TypeRegistry,
+    ) -> Vec<InspectorItem> {
+        let Some(reflected) = target.and_then(|target| target.reflect(world)) else {
+            return Vec::new();
+        };
+
+        match reflected.reflect_ref() {
+            ReflectRef::Enum(_) | ReflectRef::Value(_) => {
+                vec![InspectorItem::from_reflect(
+                    "value", reflected, "", registry,
+                )]
+            }
+            _ => InspectorItem::children_of(reflected, "", registry),
+        }
+    }
+
+    fn children_of(value: &dyn Reflect, path: &str, registry: &TypeRegistry) -> Vec<InspectorItem> {
+        match value.reflect_ref() {
+            ReflectRef::Struct(value) => (0..value.field_len())
+                .filter_map(|i| Some((value.name_at(i)?, value.field_at(i)?)))
+                .map(|(name, field)| {
+                    InspectorItem::from_reflect(name, field, &join_path(path, name), registry)
+                })
+                .collect(),
+            ReflectRef::TupleStruct(value) => value
+                .iter_fields()
+                .enumerate()
+                .map(|(i, field)| {
+                    let name = i.to_string();
+                    let path = join_path(path, &name);
+                    InspectorItem::from_reflect(&name, field, &path, registry)
+                })
+                .collect(),
+            ReflectRef::Tuple(value) => value
+                .iter_fields()
+                .enumerate()
+                .map(|(i, field)| {
+                    let name = i.to_string();
+                    let path = join_path(path, &name);
+                    InspectorItem::from_reflect(&name, field, &path, registry)
+                })
+                .collect(),
+            ReflectRef::List(value) => value
+                .iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    let path = format!("{}[{}]", path, i);
+                    InspectorItem::from_reflect(&format!("[{}]", i), item, &path, registry)
+                })
+                .collect(),
+            ReflectRef::Array(value) => value
+                .iter()
+                .enumerate()
+                .map(|(i, item)| {
+                    let path = format!("{}[{}]", path, i);
+                    InspectorItem::from_reflect(&format!("[{}]", i), item, &path, registry)
+                })
+                .collect(),
+            ReflectRef::Enum(value) => (0..value.field_len())
+                .filter_map(|i| value.field_at(i).map(|field| (i, field)))
+                .map(|(i, field)| {
+                    let name = value.name_at(i).map_or_else(|| i.to_string(), String::from);
+                    let path = join_path(path, &name);
+                    InspectorItem::from_reflect(&name, field, &path, registry)
+                })
+                .collect(),
+            ReflectRef::Map(_) | ReflectRef::Value(_) => Vec::new(),
+        }
+    }
+
+    fn from_reflect(
+        name: &str,
+        value: &dyn Reflect,
+        path: &str,
+        registry: &TypeRegistry,
+    ) -> InspectorItem {
+        let name = name.to_string();
+        let path = path.to_string();
+
+        if let Some(value) = value.downcast_ref::<bool>() {
+            return InspectorItem::Bool {
+                name,
+                path,
+                value: *value,
+            };
+        }
+
+        if let Some((kind, value)) = NumberKind::read(value) {
+            return InspectorItem::Number {
+                name,
+                path,
+                kind,
+                value,
+            };
+        }
+
+        match value.reflect_ref() {
+            ReflectRef::Struct(_)
+            | ReflectRef::TupleStruct(_)
+            | ReflectRef::Tuple(_)
+            | ReflectRef::Array(_) => InspectorItem::Group {
+                fields: InspectorItem::children_of(value, &path, registry),
+                name,
+                path,
+            },
+            ReflectRef::List(_) => InspectorItem::List {
+                items: InspectorItem::children_of(value, &path, registry),
+                name,
+                path,
+            },
+            ReflectRef::Enum(enum_value) => InspectorItem::Enum {
+                variants: variant_names(value),
+                selected: enum_value.variant_index(),
+                fields: InspectorItem::children_of(value, &path, registry),
+                name,
+                path,
+            },
+            ReflectRef::Map(_) | ReflectRef::Value(_) => InspectorItem::Value {
+                name,
+                path,
+                text: format!("{:?}", value),
+            },
+        }
+    }
+}
+
+fn variant_names(value: &dyn Reflect) -> Vec<String> {
+    let Some(TypeInfo::Enum(info)) = value.get_represented_type_info() else {
+        return Vec::new();
+    };
+
+    info.iter()
+        .map(|variant| variant.name().to_string())
+        .collect()
+}
+
+/// Builds `variant` of the enum `value`. Fields are kept if the current variant has a field of
+/// the same name (or position) and type, others are set to their default value.
+fn enum_variant(
+    value: &dyn Reflect,
+    variant: &str,
+    registry: &TypeRegistry,
+) -> Option<DynamicEnum> {
+    let ReflectRef::Enum(current) = value.reflect_ref() else {
+        return None;
+    };
+    let Some(TypeInfo::Enum(info)) = value.get_represented_type_info() else {
+        return None;
+    };
+
+    let field_value =
+        |existing: Option<&dyn Reflect>, type_id: TypeId| match existing.filter(|field| {
+            field
+                .get_represented_type_info()
+                .is_some_and(|info| info.type_id() == type_id)
+        }) {
+            Some(field) => Some(field.clone_value()),
+            None => registry
+                .get_type_data::<ReflectDefault>(type_id)
+                .map(|reflect_default| reflect_default.default()),
+        };
+
+    let dynamic_variant = match info.variant(variant)? {
+        VariantInfo::Unit(_) => DynamicVariant::Unit,
+        VariantInfo::Tuple(info) => {
+            let mut fields = DynamicTuple::default();
+            for (i, field) in info.iter().enumerate() {
+                fields.insert_boxed(field_value(current.field_at(i), field.type_id())?);
+            }
+
+            DynamicVariant::Tuple(fields)
+        }
+        VariantInfo::Struct(info) => {
+            let mut fields = DynamicStruct::default();
+            for field in info.iter() {
+                let value = field_value(current.field(field.name()), field.type_id())?;
+                fields.insert_boxed(field.name(), value);
+            }
+
+            DynamicVariant::Struct(fields)
+        }
+    };
+
+    Some(DynamicEnum::new(variant, dynamic_variant))
+}
+
+fn join_path(path: &str, field: &str) -> String {
+    match path.is_empty() {
+        true => field.to_string(),
+        false => format!("{}.{}", path, field),
+    }
+}
+
+/// The reflected value an inspector edits
+#[derive(Clone)]
+pub(crate) enum InspectorTarget {
+    Component(Entity, ReflectComponent),
+    Resource(ReflectResource),
+}
+
+impl InspectorTarget {
+    pub(crate) fn reflect<'w>(&self, world: &'w World) -> Option<&'w dyn Reflect> {
+        match self {
+            InspectorTarget::Component(entity, reflect_component) => {
+                reflect_component.reflect(world.get_entity(*entity)?)
+            }
+            InspectorTarget::Resource(reflect_resource) => reflect_resource.reflect(world),
+        }
+    }
+
+    /// Calls `modify` with the field at `path`, which returns whether it changed the field.
+    /// Returns `false` if the field doesn't exist.
+    fn modify_field(
+        &self,
+        world: &mut World,
+        path: &str,
+        modify: impl FnOnce(&mut dyn Reflect) -> bool,
+    ) -> bool {
+        match self {
+            InspectorTarget::Component(entity, reflect_component) => {
+                let Some(mut entity) = world.get_entity_mut(*entity) else {
+                    return false;
+                };
+                let Some(target) = reflect_component.reflect_mut(&mut entity) else {
+                    return false;
+                };
+
+                modify_reflected(target, path, modify)
+            }
+            InspectorTarget::Resource(reflect_resource) => {
+                let Some(target) = reflect_resource.reflect_mut(world) else {
+                    return false;
+                };
+
+                modify_reflected(target, path, modify)
+            }
+        }
+    }
+}
+
+fn modify_reflected(
+    mut target: Mut<dyn Reflect>,
+    path: &str,
+    modify: impl FnOnce(&mut dyn Reflect) -> bool,
+) -> bool {
+    let Some(field) = reflect_field_mut(target.bypass_change_detection(), path) else {
+        return false;
+    };
+
+    if modify(field) {
+        target.set_changed();
+    }
+
+    true
+}
+
+struct InspectorContext<'a> {
+    inspector: Entity,
+    target: Option<InspectorTarget>,
+    key_prefix: String,
+    open_sections: &'a HashSet<String>,
+}
+
+impl InspectorContext<'_> {
+    fn source(&self, path: &str, kind: InspectorFieldKind) -> Option<WidgetBinding> {
+        Some(WidgetBinding::new(InspectorFieldSource {
+            target: self.target.clone()?,
+            path: path.to_string(),
+            kind,
+        }))
+    }
+
+    fn key(&self, path: &str) -> String {
+        format!("{}.{}", self.key_prefix, path)
+    }
+}
+
+fn spawn_inspector_item(
+    builder: &mut UiBuilder<Entity>,
+    item: InspectorItem,
+    context: &InspectorContext,
+) {
+    match item {
+        InspectorItem::Bool { name, path, value } => {
+            let mut checkbox = builder.checkbox(name, value);
+            if let Some(binding) = context.source(&path, InspectorFieldKind::Bool) {
+                checkbox.insert(binding);
+            }
+        }
+        InspectorItem::Number {
+            name,
+            path,
+            kind,
+            value,
+        } => {
+            builder.inspector_row(name, |row| {
+                let mut field = row.inspector_number_field(kind, value);
+                if let Some(binding) = context.source(&path, InspectorFieldKind::Number(kind)) {
+                    field.insert(binding);
+                }
+            });
+        }
+        InspectorItem::Enum {
+            name,
+            path,
+            variants,
+            selected,
+            fields,
+        } => {
+            let spawn_variant_row = |builder: &mut UiBuilder<Entity>, name: String| {
+                builder.inspector_row(name, |row| {
+                    let kind = InspectorFieldKind::Variant(variants.clone());
+                    let mut dropdown = row.dropdown(variants.clone(), selected);
+                    if let Some(binding) = context.source(&path, kind) {
+                        dropdown.insert(binding);
+                    }
+                });
+            };
+
+            if fields.is_empty() {
+                spawn_variant_row(builder, name);
+                return;
+            }
+
+            let key = context.key(&path);
+            builder
+                .foldable(
+                    name,
+                    context.open_sections.contains(&key),
+                    false,
+                    |section| {
+                        spawn_variant_row(section, "variant".into());
+                        for field in fields {
+                            spawn_inspector_item(section, field, context);
+                        }
+                    },
+                )
+                .insert(InspectorSection {
+                    inspector: context.inspector,
+                    key,
+                });
+        }
+        InspectorItem::Group { name, path, fields } => {
+            let key = context.key(&path);
+            let empty = fields.is_empty();
+            builder
+                .foldable(
+                    name,
+                    context.open_sections.contains(&key),
+                    empty,
+                    |section| {
+                        for field in fields {
+                            spawn_inspector_item(section, field, context);
+                        }
+                    },
+                )
+                .insert(InspectorSection {
+                    inspector: context.inspector,
+                    key,
+                });
+        }
+        InspectorItem::List { name, path, items } => {
+            let key = context.key(&path);
+            let name = format!("{} ({})", name, items.len());
+            builder
+                .foldable(
+                    name,
+                    context.open_sections.contains(&key),
+                    false,
+                    |section| {
+                        for (index, item) in items.into_iter().enumerate() {
+                            section.row(|row| {
+                                row.style().align_items(AlignItems::FlexStart);
+                                row.column(|column| {
+                                    column.style().flex_grow(1.);
+                                    spawn_inspector_item(column, item, context);
+                                });
+                                row.inspector_list_button(
+                                    "-",
+                                    context,
+                                    &path,
+                                    InspectorListAction::Remove(index),
+                                );
+                            });
+                        }
+
+                        section.inspector_list_button(
+                            "+",
+                            context,
+                            &path,
+                            InspectorListAction::Add,
+                        );
+                    },
+                )
+                .insert(InspectorSection {
+                    inspector: context.inspector,
+                    key,
+                });
+        }
+        InspectorItem::Value { name, path, text } => {
+            builder.inspector_row(name, |row| {
+                let label = row
+                    .label(LabelConfig {
+                        label: text,
+                        ..default()
+                    })
+                    .id();
+
+                if let Some(target) = context.target.clone() {
+                    row.commands()
+                        .entity(label)
+                        .insert(InspectorValueLabel { target, path });
+                }
+            });
+        }
+    }
+}
+
+fn update_inspector_value_labels(world: &mut World) {
+    let labels: Vec<(Entity, String)> = world
+        .query::<(Entity, &InspectorValueLabel, &Text)>()
+        .iter(world)
+        .filter_map(|(label, value_label, text)| {
+            let target = value_label.target.reflect(world)?;
+            let value = reflect_field(target, &value_label.path)?;
+            let debug = format!("{:?}", value);
+
+            match text.sections.first() {
+                Some(section) if section.value == debug => None,
+                _ => Some((label, debug)),
+            }
+        })
+        .collect();
+
+    let mut queue = CommandQueue::default();
+    let mut commands = Commands::new(&mut queue, world);
+    for (label, text) in labels {
+        commands.entity(label).update_text(text);
+    }
+    queue.apply(world);
+}
+
+fn handle_list_button_press(world: &mut World) {
+    let pressed: Vec<InspectorListButton> = world
+        .query::<(&InspectorListButton, Ref<FluxInteraction>)>()
+        .iter(world)
+        .filter(|(_, interaction)| interaction.is_changed() && interaction.is_released())
+        .map(|(button, _)| button.clone())
+        .collect();
+
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    for button in pressed {
+        button.target.modify_field(world, &button.path, |field| {
+            let ReflectMut::List(list) = field.reflect_mut() else {
+                return false;
+            };
+
+            match button.action {
+                InspectorListAction::Add => {
+                    let item = match list.len() {
+                        0 => default_list_item(list, &registry),
+                        len => list.get(len - 1).map(|last| last.clone_value()),
+                    };
+
+                    let Some(item) = item else {
+                        warn!("Cannot add an item to {}: no default value", button.path);
+                        return false;
+                    };
+
+                    list.push(item);
+                }
+                InspectorListAction::Remove(index) => {
+                    if index >= list.len() {
+                        return false;
+                    }
+
+                    list.remove(index);
+                }
+            }
+
+            true
+        });
+    }
+}
+
+fn default_list_item(list: &dyn List, registry: &TypeRegistry) -> Option<Box<dyn Reflect>> {
+    let Some(TypeInfo::List(info)) = list.get_represented_type_info() else {
+        return None;
+    };
+
+    registry
+        .get_type_data::<ReflectDefault>(info.item_type_id())
+        .map(|reflect_default| reflect_default.default())
+}
+
+fn reflect_field<'a>(value: &'a dyn Reflect, path: &str) -> Option<&'a dyn Reflect> {
+    match path.is_empty() {
+        true => Some(value),
+        false => value.reflect_path(path).ok(),
+    }
+}
+
+fn reflect_field_mut<'a>(value: &'a mut dyn Reflect, path: &str) -> Option<&'a mut dyn Reflect> {
+    match path.is_empty() {
+        true => Some(value),
+        false => value.reflect_path_mut(path).ok(),
+    }
+}
+
+#[derive(Clone, Debug)]
+enum InspectorFieldKind {
+    Bool,
+    Number(NumberKind),
+    Variant(Vec<String>),
+}
+
+impl InspectorFieldKind {
+    /// Converts the widget's `value` to a value that can be applied to `field`
+    fn field_value(
+        &self,
+        value: &dyn Reflect,
+        field: &dyn Reflect,
+        registry: &TypeRegistry,
+    ) -> Option<Box<dyn Reflect>> {
+        match self {
+            InspectorFieldKind::Bool => Some(value.clone_value()),
+            InspectorFieldKind::Number(kind) => Some(kind.write(*value.downcast_ref::<f64>()?)),
+            InspectorFieldKind::Variant(variants) => {
+                let Some(index) = value.downcast_ref::<Option<usize>>()? else {
+                    return None;
+                };
+                let variant = variants.as_slice().get(*index)?;
+
+                Some(Box::new(enum_variant(field, variant, registry)?))
+            }
+        }
+    }
+}
+
+/// Binds an inspector widget to a field of a component or resource, converting between the
+/// field's type and the widget's value.
+struct InspectorFieldSource {
+    target: InspectorTarget,
+    path: String,
+    kind: InspectorFieldKind,
+}
+
+impl BindingSource for InspectorFieldSource {
+    fn read(&self, world: &mut World) -> Option<Box<dyn Reflect>> {
+        let field = reflect_field(self.target.reflect(world)?, &self.path)?;
+
+        match &self.kind {
+            InspectorFieldKind::Bool => Some(Box::new(*field.downcast_ref::<bool>()?)),
+            InspectorFieldKind::Number(_) => Some(Box::new(NumberKind::read(field)?.1)),
+            InspectorFieldKind::Variant(_) => {
+                let ReflectRef::Enum(value) = field.reflect_ref() else {
+                    return None;
+                };
+
+                Some(Box::new(Some(value.variant_index())))
+            }
+        }
+    }
+
+    fn write(&self, world: &mut World, value: &dyn Reflect) -> bool {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+
+        let mut converted = false;
+        let found = self.target.modify_field(world, &self.path, |field| {
+            let Some(value) = self.kind.field_value(value, field, &registry) else {
+                return false;
+            };
+
+            converted = true;
+            if field.reflect_partial_eq(value.as_ref()).unwrap_or(false) {
+                return false;
+            }
+
+            field.apply(value.as_ref());
+            true
+        });
+
+        found && converted
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum NumberKind {
+    F32,
+    F64,
+    I8,
+    I16,
+    I32,
+    I64,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    Usize,
+}
+
+macro_rules! number_kinds {
+    ($($kind:ident => $type:ty),*) => {
+        impl NumberKind {
+            fn read(value: &dyn Reflect) -> Option<(NumberKind, f64)> {
+                $(
+                    if let Some(value) = value.downcast_ref::<$type>() {
+                        return Some((NumberKind::$kind, *value as f64));
+                    }
+                )*
+
+                None
+            }
+
+            fn write(&self, value: f64) -> Box<dyn Reflect> {
+                match self {
+                    $(NumberKind::$kind => Box::new(value as $type),)*
+                }
+            }
+
+            fn range(&self) -> (f64, f64) {
+                match self {
+                    $(NumberKind::$kind => (<$type>::MIN as f64, <$type>::MAX as f64),)*
+                }
+            }
+        }
+    };
+}
+
+number_kinds!(
+    F32 => f32,
+    F64 => f64,
+    I8 => i8,
+    I16 => i16,
+    I32 => i32,
+    I64 => i64,
+    Isize => isize,
+    U8 => u8,
+    U16 => u16,
+    U32 => u32,
+    U64 => u64,
+    Usize => usize
+);
+
+impl NumberKind {
+    fn is_integer(&self) -> bool {
+        !matches!(self, NumberKind::F32 | NumberKind::F64)
+    }
+
+    /// Value change per pixel dragged
+    fn drag_step(&self) -> f64 {
+        match self.is_integer() {
+            true => 0.25,
+            false => 0.01,
+        }
+    }
+
+    /// Value change per line scrolled
+    fn scroll_step(&self) -> f64 {
+        match self.is_integer() {
+            true => 1.,
+            false => 0.1,
+        }
+    }
+}
+
+fn update_number_field_on_drag(
+    mut q_fields: Query<(Entity, &mut InspectorNumberField, &Draggable), Changed<Draggable>>,
+    mut events: EventWriter<InspectorNumberFieldChanged>,
+) {
+    for (entity, mut field, draggable) in &mut q_fields {
+        let previous = field.value;
+        match draggable.state {
+            DragState::DragStart => {
+                field.base_value = field.value.into();
+                field.remainder = 0.;
+            }
+            DragState::Dragging => {
+                let Some(diff) = draggable.diff else {
+                    continue;
+                };
+
+                let change = diff.x as f64 * field.kind.drag_step();
+                field.change_by(change);
+            }
+            DragState::DragCanceled => {
+                if let Some(base_value) = field.base_value {
+                    field.value = base_value;
+                }
+            }
+            _ => (),
+        }
+
+        field.report_user_change(entity, previous, &mut events);
+    }
+}
+
+fn update_number_field_on_scroll(
+    mut q_fields: Query<(Entity, &mut InspectorNumberField, &Scrollable), Changed<Scrollable>>,
+    mut events: EventWriter<InspectorNumberFieldChanged>,
+) {
+    for (entity, mut field, scrollable) in &mut q_fields {
+        let Some((_, diff, unit)) = scrollable.last_change() else {
+            continue;
+        };
+
+        let lines = match unit {
+            MouseScrollUnit::Line => -diff,
+            MouseScrollUnit::Pixel => -diff / Scrollable::LINE_SIZE,
+        };
+
+        let previous = field.value;
+        let change = lines as f64 * field.kind.scroll_step();
+        field.change_by(change);
+        field.report_user_change(entity, previous, &mut events);
+    }
+}
+
+fn update_number_field_readout(
+    q_fields: Query<&InspectorNumberField, Changed<InspectorNumberField>>,
+    mut commands: Commands,
+) {
+    for field in &q_fields {
+        commands
+            .entity(field.readout)
+            .update_text(field.formatted_value());
+    }
+}
+
+/// Tracks inspected entity and its structure. Set `entity` to inspect another entity.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct EntityInspector {
+    pub entity: Option<Entity>,
+    #[reflect(ignore)]
+    shape: Option<Vec<usize>>,
+    #[reflect(ignore)]
+    components: Vec<ComponentId>,
+    #[reflect(ignore)]
+    last_checked: Option<Tick>,
+}
+
+/// Foldable section of the inspector. Open sections remain open when the inspector is rebuilt.
+#[derive(Component, Clone, Debug)]
+struct InspectorSection {
+    inspector: Entity,
+    key: String,
+}
+
+/// Read-only field of the inspector, refreshed with the debug output of the field
+#[derive(Component, Clone)]
+struct InspectorValueLabel {
+    target: InspectorTarget,
+    path: String,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum InspectorListAction {
+    Add,
+    Remove(usize),
+}
+
+#[derive(Component, Clone)]
+struct InspectorListButton {
+    target: InspectorTarget,
+    path: String,
+    action: InspectorListAction,
+}
+
+/// Sent when the value of an inspector number field is dragged or scrolled
+#[derive(Event, Clone, Copy, Debug)]
+pub struct InspectorNumberFieldChanged {
+    pub entity: Entity,
+    pub value: f64,
+    pub source: ValueChangeSource,
+}
+
+impl WidgetEvent for InspectorNumberFieldChanged {
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
+/// A numeric field that is changed by dragging horizontally or scrolling over it
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
+#[reflect(Component)]
+pub struct InspectorNumberField {
+    kind: NumberKind,
+    value: f64,
+    base_value: Option<f64>,
+    remainder: f64,
+    #[context("Readout")]
+    readout: Entity,
+}
+
+impl Default for InspectorNumberField {
+    fn default() -> Self {
+        Self {
+            kind: NumberKind::F32,
+            value: 0.,
+            base_value: None,
+            remainder: 0.,
+            readout: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl BindableWidget for InspectorNumberField {
+    type Value = f64;
+    type Changed = InspectorNumberFieldChanged;
+
+    fn bound_value(&self) -> f64 {
+        self.value
+    }
+
+    fn set_bound_value(&mut self, value: f64) {
+        self.value = value;
+    }
+
+    fn user_edit(event: &InspectorNumberFieldChanged) -> Option<f64> {
+        (event.source == ValueChangeSource::User).then_some(event.value)
+    }
+}
+
+impl DefaultTheme for InspectorNumberField {
+    fn default_theme() -> Option<Theme<InspectorNumberField>> {
+        InspectorNumberField::theme().into()
+    }
+}
+
+impl InspectorNumberField {
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Integer fields accumulate fractional changes until they add up to a whole step
+    fn change_by(&mut self, change: f64) {
+        let (min, max) = self.kind.range();
+        let change = match self.kind.is_integer() {
+            true => {
+                let total = self.remainder + change;
+                self.remainder = total.fract();
+                total.trunc()
+            }
+            false => change,
+        };
+
+        if change != 0. {
+            self.value = (self.value + change).clamp(min, max);
+        }
+    }
+
+    fn report_user_change(
+        &self,
+        entity: Entity,
+        previous: f64,
+        events: &mut EventWriter<InspectorNumberFieldChanged>,
+    ) {
+        if self.value == previous {
+            return;
+        }
+
+        events.send(InspectorNumberFieldChanged {
+            entity,
+            value: self.value,
+            source: ValueChangeSource::User,
+        });
+    }
+
+    fn formatted_value(&self) -> String {
+        match self.kind.is_integer() {
+            true => format!("{}", self.value.round() as i128),
+            false => format!("{:.3}", self.value),
+        }
+    }
+
+    pub fn theme() -> Theme<InspectorNumberField> {
+        let base_theme = PseudoTheme::deferred(None, InspectorNumberField::primary_style);
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+        let font = theme_data
+            .text
+            .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
+
+        style_builder
+            .min_width(Val::Px(theme_spacing.areas.extra_large))
+            .padding(UiRect::horizontal(Val::Px(theme_spacing.gaps.small)))
+            .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
+            .border_color(colors.accent(Accent::Outline))
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.container(Container::SurfaceMid),
+                hover: colors.container(Container::SurfaceHigh).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(InspectorNumberField::READOUT)
+            .sized_font(font)
+            .font_color(colors.on(On::Surface));
+    }
+
+    fn frame() -> impl Bundle {
+        (
+            Name::new("Inspector Number Field"),
+            ButtonBundle::default(),
+            TrackedInteraction::default(),
+            Draggable::default(),
+            RelativeCursorPosition::default(),
+            Scrollable::default(),
+        )
+    }
+}
+
+trait UiInspectorExt {
+    fn inspector_row(
+        &mut self,
+        name: String,
+        spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
+    ) -> UiBuilder<'_, Entity>;
+
+    fn inspector_number_field(&mut self, kind: NumberKind, value: f64) -> UiBuilder<'_, Entity>;
+
+    fn inspector_list_button(
+        &mut self,
+        label: &str,
+        context: &InspectorContext,
+        path: &str,
+        action: InspectorListAction,
+    ) -> UiBuilder<'_, Entity>;
+}
+
+impl UiInspectorExt for UiBuilder<'_, Entity> {
+    fn inspector_row(
+        &mut self,
+        name: String,
+        spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
+    ) -> UiBuilder<'_, Entity> {
+        self.row(|row| {
+            row.style().align_items(AlignItems::Center);
+            row.label(LabelConfig {
+                label: name,
+                margin: UiRect::horizontal(Val::Px(5.)),
+                ..default()
+            })
+            .style()
+            .min_width(Val::Px(80.));
+
+            spawn_children(row);
+        })
+    }
+
+    fn inspector_number_field(&mut self, kind: NumberKind, value: f64) -> UiBuilder<'_, Entity> {
+        let mut field = InspectorNumberField {
+            kind,
+            value,
+            ..default()
+        };
+
+        let formatted = field.formatted_value();
+        let mut widget = self.container(InspectorNumberField::frame(), |container| {
+            field.readout = container
+                .label(LabelConfig {
+                    label: formatted,
+                    ..default()
+                })
+                .id();
+        });
+
+        widget.insert(field);
+
+        widget
+    }
+
+    fn inspector_list_button(
+        &mut self,
+        label: &str,
+        context: &InspectorContext,
+        path: &str,
+        action: InspectorListAction,
+    ) -> UiBuilder<'_, Entity> {
+        let mut button = self.container(
+            (
+                Name::new(format!("Inspector List Button [{}]", label)),
+                ButtonBundle::default(),
+                TrackedInteraction::default(),
+            ),
+            |button| {
+                button.label(LabelConfig {
+                    label: label.into(),
+                    margin: UiRect::horizontal(Val::Px(5.)),
+                    ..default()
+                });
+            },
+        );
+
+        if let Some(target) = context.target.clone() {
+            button.insert(InspectorListButton {
+                target,
+                path: path.into(),
+                action,
+            });
+        }
+
+        button
+    }
+}
+
+pub trait UiEntityInspectorExt {
+    /// Inspector of the reflected components of an entity. Structs are shown as foldable
+    /// sections, and bools, numbers, unit enums and lists can be edited in place.
+    ///
+    /// Edits are written back through `ReflectComponent`. Components must be registered
+    /// with `#[reflect(Component)]` to show their fields.
+    fn entity_inspector(&mut self, entity: Option<Entity>) -> UiBuilder<'_, Entity>;
+}
+
+impl UiEntityInspectorExt for UiBuilder<'_, Entity> {
+    fn entity_inspector(&mut self, entity: Option<Entity>) -> UiBuilder<'_, Entity> {
+        self.column(|column| {
+            column
+                .insert((
+                    Name::new("Entity Inspector"),
+                    EntityInspector {
+                        entity,
+                        shape: None,
+                        components: Vec::new(),
+                        last_checked: None,
+                    },
+                ))
+                .style()
+                .width(Val::Percent(100.));
+        })
+    }
+}
@@ -1,21 +1,30 @@
-use bevy::prelude::*;
+use bevy::{
+    ecs::system::SystemParam,
+    input::InputSystem,
+    prelude::*,
+    render::primitives::Aabb,
+    utils::{HashMap, HashSet},
+    window::PrimaryWindow,
+};
 
-use sickle_ui_scaffold::prelude::*;
+use sickle_ui_scaffold::{prelude::*, ui_commands::UpdateTextExt};
 
 use crate::widgets::{
+    inputs::text_input::{TextInput, UiTextInputExt},
     layout::{
         column::UiColumnExt,
         foldable::{Foldable, UiFoldableExt},
         panel::UiPanelExt,
         row::UiRowExt,
-        scroll_view::UiScrollViewExt,
+        scroll_view::{ScrollIntoViewExt, UiScrollViewExt},
         sized_zone::{SizedZoneConfig, UiSizedZoneExt},
     },
-    menus::menu_item::{MenuItem, MenuItemConfig, UiMenuItemExt},
+    menus::toggle_menu_item::{ToggleMenuItem, ToggleMenuItemConfig, UiToggleMenuItemExt},
 };
 
-use super::entity_component_list::{
-    EntityComponentList, EntityComponentListPlugin, UiEntityComponentListExt,
+use super::{
+    entity_inspector::{EntityInspector, EntityInspectorPlugin, UiEntityInspectorExt},
+    style_inspector::{StyleInspector, StyleInspectorPlugin, UiStyleInspectorExt},
 };
 
 // TODO: Move to subapp? to separate inspection from UI entities
@@ -23,37 +32,52 @@ pub struct HierarchyTreeViewPlugin;
 
 impl Plugin for HierarchyTreeViewPlugin {
     fn build(&self, app: &mut App) {
-        if !app.is_plugin_added::<EntityComponentListPlugin>() {
-            app.add_plugins(EntityComponentListPlugin);
+        if !app.is_plugin_added::<EntityInspectorPlugin>() {
+            app.add_plugins(EntityInspectorPlugin);
         }
 
-        app.add_systems(
-            PreUpdate,
-            (
-                refresh_hierarchy_on_press,
-                initialize_hierarchy_tree_view,
-                update_hierarchy_on_foldable_change,
-                update_hierarchy_selection,
-                update_hierarchy_node_style,
-                update_entity_component_list,
-            )
-                .chain()
-                .in_set(HierarchyPreUpdate),
-        );
+        if !app.is_plugin_added::<StyleInspectorPlugin>() {
+            app.add_plugins(StyleInspectorPlugin);
+        }
+
+        app.configure_sets(PreUpdate, HierarchyPreUpdate.after(InputSystem))
+            .add_systems(
+                PreUpdate,
+                (
+                    initialize_hierarchy_tree_view,
+                    update_hierarchy_search,
+                    pick_hierarchy_entity,
+                    update_hierarchy_nodes,
+                    update_hierarchy_node_labels,
+                    update_hierarchy_selection,
+                    update_hierarchy_node_style,
+                    reveal_hierarchy_selection,
+                    update_inspectors,
+                )
+                    .chain()
+                    .in_set(HierarchyPreUpdate),
+            );
     }
 }
 
 #[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
 pub struct HierarchyPreUpdate;
 
+/// Queries needed to spawn the nodes of the hierarchy tree
+#[derive(SystemParam)]
+struct HierarchyTree<'w, 's> {
+    q_hierarchy: Query<'w, 's, &'static HierarchyContainer>,
+    q_children: Query<'w, 's, &'static Children>,
+    q_name: Query<'w, 's, &'static Name>,
+}
+
 fn initialize_hierarchy_tree_view(
     q_hierarchy_nodes: Query<(Entity, &HierarchyNodeContainer), Added<HierarchyNodeContainer>>,
-    q_hierarchy: Query<&HierarchyContainer>,
-    q_name: Query<&Name>,
+    tree: HierarchyTree,
     mut commands: Commands,
 ) {
     for (entity, node_container) in &q_hierarchy_nodes {
-        let Ok(hierarchy) = q_hierarchy.get(node_container.hierarchy) else {
+        let Ok(hierarchy) = tree.q_hierarchy.get(node_container.hierarchy) else {
             warn!(
                 "Hierarchy node container {:?} missing main container {:?}",
                 entity, node_container.hierarchy
@@ -64,45 +88,282 @@ fn initialize_hierarchy_tree_view(
         let mut container = commands.ui_builder(entity);
         spawn_hierarchy_level(
             node_container.hierarchy,
+            hierarchy,
             hierarchy.root,
             &mut container,
-            &q_name,
+            &tree,
         );
     }
 }
 
-fn refresh_hierarchy_on_press(
-    q_menu_items: Query<(&MenuItem, &RefreshHierarchyButton), Changed<MenuItem>>,
-    q_name: Query<&Name>,
-    mut q_hierarchy: Query<&mut HierarchyContainer>,
-    mut commands: Commands,
-) {
-    for (menu_item, refresh_button) in &q_menu_items {
-        if menu_item.interacted() {
-            let Ok(mut hierarchy) = q_hierarchy.get_mut(refresh_button.hierarchy) else {
+fn update_hierarchy_search(world: &mut World) {
+    let searches: Vec<(Entity, String)> = world
+        .query_filtered::<(&TextInput, &HierarchySearchInput), Changed<TextInput>>()
+        .iter(world)
+        .map(|(input, search)| (search.hierarchy, input.value.trim().to_lowercase()))
+        .collect();
+
+    for (hierarchy_id, search) in searches {
+        let Some(hierarchy) = world.get::<HierarchyContainer>(hierarchy_id) else {
+            continue;
+        };
+
+        // Nothing to reset, i.e. when picking an entity cleared the search
+        if search.is_empty() && hierarchy.filter.is_none() {
+            continue;
+        }
+
+        let (filter, expanded) = match search.is_empty() {
+            true => (None, HashSet::new()),
+            false => {
+                let (visible, expanded) = search_hierarchy(hierarchy.root, &search, world);
+                (visible.into(), expanded)
+            }
+        };
+
+        // Safe unwrap: checked above
+        let mut hierarchy = world.get_mut::<HierarchyContainer>(hierarchy_id).unwrap();
+        hierarchy.filter = filter;
+        hierarchy.expanded = expanded.clone();
+
+        // Sync every node with the new filter, opening the ancestors of matches
+        for (node, mut foldable) in world
+            .query::<(&HierarchyNode, &mut Foldable)>()
+            .iter_mut(world)
+            .filter(|(node, _)| node.hierarchy == hierarchy_id)
+        {
+            foldable.open |= expanded.contains(&node.entity);
+        }
+    }
+}
+
+/// Returns the entities to show (matches and their ancestors) and the ones to open
+/// (the ancestors of matches) in the hierarchy of `root`.
+fn search_hierarchy(
+    root: Entity,
+    search: &str,
+    world: &World,
+) -> (HashSet<Entity>, HashSet<Entity>) {
+    let mut visible: HashSet<Entity> = HashSet::from([root]);
+    let mut expanded: HashSet<Entity> = HashSet::new();
+
+    let mut stack = vec![root];
+    while let Some(entity) = stack.pop() {
+        if let Some(children) = world.get::<Children>(entity) {
+            stack.extend(children.iter());
+        }
+
+        if entity == root || !matches_search(entity, search, world) {
+            continue;
+        }
+
+        visible.insert(entity);
+        let mut current = world.get::<Parent>(entity).map(|parent| parent.get());
+        while let Some(ancestor) = current {
+            visible.insert(ancestor);
+            if !expanded.insert(ancestor) || ancestor == root {
+                break;
+            }
+
+            current = world.get::<Parent>(ancestor).map(|parent| parent.get());
+        }
+    }
+
+    (visible, expanded)
+}
+
+/// Matches the lowercase `search` against the entity's `Name`, id and component types
+fn matches_search(entity: Entity, search: &str, world: &World) -> bool {
+    if let Some(name) = world.get::<Name>(entity) {
+        if name.as_str().to_lowercase().contains(search) {
+            return true;
+        }
+    }
+
+    if format!("{:?}", entity).contains(search) || entity.index().to_string() == search {
+        return true;
+    }
+
+    world.inspect_entity(entity).iter().any(|info| {
+        UiUtils::simplify_component_name(info)
+            .to_lowercase()
+            .contains(search)
+    })
+}
+
+/// Finds the UI node or mesh under the cursor
+#[derive(SystemParam)]
+struct EntityPicker<'w, 's> {
+    q_window: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    q_ui_nodes: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Node,
+            &'static GlobalTransform,
+            &'static ViewVisibility,
+        ),
+    >,
+    q_cameras: Query<'w, 's, (&'static Camera, &'static GlobalTransform)>,
+    q_bounds: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Aabb,
+            &'static GlobalTransform,
+            &'static ViewVisibility,
+        ),
+    >,
+    q_parent: Query<'w, 's, &'static Parent>,
+}
+
+impl EntityPicker<'_, '_> {
+    fn cursor(&self) -> Option<Vec2> {
+        self.q_window
+            .get_single()
+            .ok()
+            .and_then(|window| window.cursor_position())
+    }
+
+    /// Picks the topmost UI node or the closest mesh under the cursor, in the hierarchy of `root`
+    fn pick(&self, cursor: Vec2, root: Entity) -> Option<Entity> {
+        let in_hierarchy = |entity: Entity| {
+            entity == root || self.q_parent.iter_ancestors(entity).any(|e| e == root)
+        };
+
+        // UI is rendered on top, so it takes precedence over meshes
+        self.q_ui_nodes
+            .iter()
+            .filter(|(entity, node, transform, visibility)| {
+                visibility.get()
+                    && node.logical_rect(transform).contains(cursor)
+                    && in_hierarchy(*entity)
+            })
+            .max_by_key(|(_, node, _, _)| node.stack_index())
+            .map(|(entity, _, _, _)| entity)
+            .or_else(|| self.pick_bounds(cursor, &in_hierarchy))
+    }
+
+    /// Picks the closest entity whose bounding box is under the cursor
+    fn pick_bounds(&self, cursor: Vec2, in_hierarchy: &dyn Fn(Entity) -> bool) -> Option<Entity> {
+        let mut closest: Option<(Entity, f32)> = None;
+        for (camera, camera_transform) in &self.q_cameras {
+            if !camera.is_active {
+                continue;
+            }
+
+            let Some(viewport) = camera.logical_viewport_rect() else {
                 continue;
             };
+            if !viewport.contains(cursor) {
+                continue;
+            }
 
-            hierarchy.selected = None;
+            let Some(ray) = camera.viewport_to_world(camera_transform, cursor - viewport.min)
+            else {
+                continue;
+            };
 
-            commands
-                .entity(refresh_button.container)
-                .despawn_descendants();
+            for (entity, aabb, transform, visibility) in &self.q_bounds {
+                if !visibility.get() || !in_hierarchy(entity) {
+                    continue;
+                }
 
-            let mut builder = commands.ui_builder(refresh_button.container);
+                let Some(distance) = ray_distance_to_bounds(ray, aabb, transform) else {
+                    continue;
+                };
 
-            spawn_hierarchy_level(
-                refresh_button.hierarchy,
-                hierarchy.root,
-                &mut builder,
-                &q_name,
-            );
+                if closest.map_or(true, |(_, closest)| distance < closest) {
+                    closest = (entity, distance).into();
+                }
+            }
+        }
+
+        closest.map(|(entity, _)| entity)
+    }
+}
 
-            break;
+fn pick_hierarchy_entity(
+    mut q_pick_buttons: Query<(&mut ToggleMenuItem, &HierarchyPickButton)>,
+    mut q_hierarchy: Query<&mut HierarchyContainer>,
+    mut q_hierarchy_nodes: Query<(&HierarchyNode, &mut Foldable)>,
+    mut q_search_inputs: Query<(&mut TextInput, &HierarchySearchInput)>,
+    picker: EntityPicker,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    for (mut pick_button, pick) in &mut q_pick_buttons {
+        if !pick_button.checked {
+            continue;
+        }
+
+        if keyboard_input.just_pressed(KeyCode::Escape) {
+            pick_button.checked = false;
+            continue;
+        }
+
+        if !mouse_input.just_pressed(MouseButton::Left) {
+            continue;
+        }
+
+        let Some(cursor) = picker.cursor() else {
+            continue;
+        };
+        let Ok(mut hierarchy) = q_hierarchy.get_mut(pick.hierarchy) else {
+            continue;
+        };
+
+        pick_button.checked = false;
+
+        let root = hierarchy.root;
+        let Some(picked) = picker.pick(cursor, root) else {
+            continue;
+        };
+
+        hierarchy.expanded = picker
+            .q_parent
+            .iter_ancestors(picked)
+            .take_while(|ancestor| *ancestor != root)
+            .chain([root])
+            .collect();
+        hierarchy.filter = None;
+        hierarchy.selected = picked.into();
+        hierarchy.reveal = picked.into();
+
+        // The search no longer applies: sync every node without the filter
+        for (node, mut foldable) in &mut q_hierarchy_nodes {
+            if node.hierarchy == pick.hierarchy {
+                foldable.open |= hierarchy.expanded.contains(&node.entity);
+            }
+        }
+
+        for (mut input, search) in &mut q_search_inputs {
+            if search.hierarchy == pick.hierarchy && !input.value.is_empty() {
+                input.value.clear();
+            }
         }
     }
 }
 
+fn ray_distance_to_bounds(ray: Ray3d, aabb: &Aabb, transform: &GlobalTransform) -> Option<f32> {
+    let world_to_local = transform.affine().inverse();
+    let origin = world_to_local.transform_point3(ray.origin);
+    let direction = world_to_local.transform_vector3(*ray.direction);
+
+    let inverse_direction = direction.recip();
+    let to_min = (Vec3::from(aabb.min()) - origin) * inverse_direction;
+    let to_max = (Vec3::from(aabb.max()) - origin) * inverse_direction;
+    let near = to_min.min(to_max).max_element();
+    let far = to_min.max(to_max).min_element();
+
+    match near <= far && far >= 0. {
+        true => near.max(0.).into(),
+        false => None,
+    }
+}
+
 fn update_hierarchy_selection(
     q_hierarchy_nodes: Query<(&FluxInteraction, &HierarchyNode), Changed<FluxInteraction>>,
     mut q_hierarchy: Query<&mut HierarchyContainer>,
@@ -120,96 +381,231 @@ fn update_hierarchy_selection(
     }
 }
 
-fn update_entity_component_list(
-    q_hierarchies: Query<&mut HierarchyContainer, Changed<HierarchyContainer>>,
-    mut q_entity_component_list: Query<&mut EntityComponentList>,
+fn reveal_hierarchy_selection(
+    mut q_hierarchy: Query<(Entity, &mut HierarchyContainer)>,
+    q_hierarchy_nodes: Query<(Entity, &HierarchyNode, &Foldable)>,
+    q_parent: Query<&Parent>,
+    mut commands: Commands,
 ) {
-    for hierarchy in &q_hierarchies {
-        let Ok(mut component_list) = q_entity_component_list.get_mut(hierarchy.component_list)
-        else {
+    for (entity, mut hierarchy) in &mut q_hierarchy {
+        let Some(reveal) = hierarchy.reveal else {
             continue;
         };
 
-        if component_list.entity != hierarchy.selected {
-            component_list.entity = hierarchy.selected;
+        let nodes: HashMap<Entity, (Entity, bool)> = q_hierarchy_nodes
+            .iter()
+            .filter(|(_, node, _)| node.hierarchy == entity)
+            .map(|(node_id, node, foldable)| (node.entity, (node_id, foldable.open)))
+            .collect();
+
+        if let Some((node, _)) = nodes.get(&reveal) {
+            commands.entity(*node).scroll_into_view();
+            hierarchy.bypass_change_detection().reveal = None;
+            continue;
+        }
+
+        // Nodes are spawned one level per frame. Keep waiting while the closest ancestor
+        // with a node is open, otherwise the node will not appear.
+        let pending = q_parent
+            .iter_ancestors(reveal)
+            .find_map(|ancestor| nodes.get(&ancestor))
+            .is_some_and(|(_, open)| *open)
+            && hierarchy.is_visible(reveal);
+        if !pending {
+            hierarchy.bypass_change_detection().reveal = None;
         }
     }
 }
 
-fn update_hierarchy_on_foldable_change(
-    mut q_foldables: Query<(&HierarchyNode, &mut Foldable), Changed<Foldable>>,
-    q_children: Query<&Children>,
-    q_name: Query<&Name>,
+fn update_inspectors(
+    q_hierarchies: Query<&mut HierarchyContainer, Changed<HierarchyContainer>>,
+    mut q_entity_inspector: Query<&mut EntityInspector>,
+    mut q_style_inspector: Query<&mut StyleInspector>,
+) {
+    for hierarchy in &q_hierarchies {
+        if let Ok(mut inspector) = q_entity_inspector.get_mut(hierarchy.inspector) {
+            if inspector.entity != hierarchy.selected {
+                inspector.entity = hierarchy.selected;
+            }
+        }
+
+        if let Ok(mut inspector) = q_style_inspector.get_mut(hierarchy.style_inspector) {
+            if inspector.entity != hierarchy.selected {
+                inspector.entity = hierarchy.selected;
+            }
+        }
+    }
+}
+
+/// Keeps the child nodes of open hierarchy nodes in sync with the children of their entities.
+/// Nodes are only spawned or despawned for the entities that were added or removed.
+fn update_hierarchy_nodes(
+    mut q_hierarchy_nodes: Query<(Entity, &HierarchyNode, &mut Foldable)>,
+    q_changed_children: Query<Entity, Changed<Children>>,
+    mut removed_children: RemovedComponents<Children>,
+    q_node_targets: Query<&HierarchyNode>,
+    tree: HierarchyTree,
     mut commands: Commands,
 ) {
-    for (hierarchy_node, mut foldable) in &mut q_foldables {
-        if foldable.empty {
+    let changed_nodes: HashSet<Entity> = q_hierarchy_nodes
+        .iter_mut()
+        .filter(|(_, _, foldable)| foldable.is_changed())
+        .map(|(node_id, _, _)| node_id)
+        .collect();
+    let changed_entities: HashSet<Entity> = q_changed_children
+        .iter()
+        .chain(removed_children.read())
+        .collect();
+
+    if changed_nodes.is_empty() && changed_entities.is_empty() {
+        return;
+    }
+
+    for (node_id, node, mut foldable) in &mut q_hierarchy_nodes {
+        if !changed_nodes.contains(&node_id) && !changed_entities.contains(&node.entity) {
             continue;
         }
 
-        commands.entity(foldable.container()).despawn_descendants();
+        let Ok(hierarchy) = tree.q_hierarchy.get(node.hierarchy) else {
+            continue;
+        };
 
-        if foldable.open {
-            if let Ok(children) = q_children.get(hierarchy_node.entity) {
-                let mut builder = commands.ui_builder(foldable.container());
-                for child in children.iter() {
-                    spawn_hierarchy_level(hierarchy_node.hierarchy, *child, &mut builder, &q_name);
-                }
-            } else {
-                foldable.empty = true;
+        let children = hierarchy.visible_children(node.entity, &tree.q_children);
+        let empty = children.is_empty();
+        if foldable.empty != empty {
+            foldable.empty = empty;
+        }
+
+        let container = foldable.container();
+        if empty || !foldable.open {
+            commands.entity(container).despawn_descendants();
+            continue;
+        }
+
+        let current_nodes: Vec<Entity> = match tree.q_children.get(container) {
+            Ok(nodes) => nodes
+                .iter()
+                .filter(|node| q_node_targets.contains(**node))
+                .copied()
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        let existing: HashMap<Entity, Entity> = current_nodes
+            .iter()
+            .filter_map(|node| q_node_targets.get(*node).ok().map(|n| (n.entity, *node)))
+            .collect();
+
+        for (entity, node) in existing.iter() {
+            if !children.contains(entity) {
+                commands.entity(*node).despawn_recursive();
             }
-        } else if q_children.get(hierarchy_node.entity).is_err() {
-            foldable.empty = true;
+        }
+
+        let nodes: Vec<Entity> = children
+            .iter()
+            .map(|child| match existing.get(child) {
+                Some(node) => *node,
+                None => spawn_hierarchy_level(
+                    node.hierarchy,
+                    hierarchy,
+                    *child,
+                    &mut commands.ui_builder(container),
+                    &tree,
+                ),
+            })
+            .collect();
+
+        if nodes != current_nodes {
+            commands.entity(container).replace_children(&nodes);
+        }
+    }
+}
+
+fn update_hierarchy_node_labels(
+    q_changed_names: Query<Entity, Changed<Name>>,
+    q_hierarchy_nodes: Query<(&HierarchyNode, &Foldable)>,
+    q_name: Query<&Name>,
+    mut commands: Commands,
+) {
+    let changed: HashSet<Entity> = q_changed_names.iter().collect();
+    if changed.is_empty() {
+        return;
+    }
+
+    for (node, foldable) in &q_hierarchy_nodes {
+        if changed.contains(&node.entity) {
+            commands
+                .entity(foldable.label())
+                .update_text(hierarchy_node_label(node.entity, &q_name));
         }
     }
 }
 
-// TODO: Refresh the hierarchy automatically
-// TODO: Rework hierarchy: use treeview with node callbacks, add search, pop-out,
-// anchestor access, theme, separate world for layout (or filter itself) etc. Tag open entities per hierarchy
+// TODO: Rework hierarchy: use treeview with node callbacks, pop-out,
+// anchestor access, theme, separate world for layout (or filter itself) etc.
 fn update_hierarchy_node_style(
-    q_hierarchies: Query<(Entity, &HierarchyContainer), Changed<HierarchyContainer>>,
-    q_hierarchy_nodes: Query<(Entity, &HierarchyNode)>,
+    q_hierarchies: Query<Ref<HierarchyContainer>>,
+    q_hierarchy_nodes: Query<(Entity, Ref<HierarchyNode>)>,
     mut commands: Commands,
 ) {
-    for (entity, hierarchy) in &q_hierarchies {
-        for (menu_item, hierarchy_node) in q_hierarchy_nodes
-            .iter()
-            .filter(|(_, node)| node.hierarchy == entity)
-        {
-            let color = match hierarchy.selected {
-                Some(selected) => match hierarchy_node.entity == selected {
-                    true => Color::GRAY,
-                    false => Color::NONE,
-                },
-                None => Color::NONE,
-            };
-            commands.style(menu_item).background_color(color);
+    for (menu_item, hierarchy_node) in &q_hierarchy_nodes {
+        let Ok(hierarchy) = q_hierarchies.get(hierarchy_node.hierarchy) else {
+            continue;
+        };
+
+        if !hierarchy.is_changed() && !hierarchy_node.is_added() {
+            continue;
         }
+
+        let color = match hierarchy.selected {
+            Some(selected) => match hierarchy_node.entity == selected {
+                true => Color::GRAY,
+                false => Color::NONE,
+            },
+            None => Color::NONE,
+        };
+        commands.style(menu_item).background_color(color);
+    }
+}
+
+fn hierarchy_node_label(entity: Entity, q_name: &Query<&Name>) -> String {
+    match q_name.get(entity) {
+        Ok(name) => format!("[{:?}] {}", entity, name),
+        Err(_) => format!("[{:?}]", entity),
     }
 }
 
 fn spawn_hierarchy_level(
-    hierarchy: Entity,
+    hierarchy_id: Entity,
+    hierarchy: &HierarchyContainer,
     entity: Entity,
     container: &mut UiBuilder<'_, Entity>,
-    q_name: &Query<&Name>,
-) {
-    let name = match q_name.get(entity) {
-        Ok(name) => format!("[{:?}] {}", entity, name),
-        Err(_) => format!("[{:?}]", entity),
-    };
+    tree: &HierarchyTree,
+) -> Entity {
+    let empty = hierarchy
+        .visible_children(entity, &tree.q_children)
+        .is_empty();
+    let open = !empty && hierarchy.expanded.contains(&entity);
 
     // TODO: move style to theme
     container
-        .foldable(name, false, false, |foldable| {
-            foldable
-                .style()
-                .margin(UiRect::left(Val::Px(10.)))
-                .border(UiRect::left(Val::Px(1.)))
-                .border_color(Color::rgba(0.98, 0.92, 0.84, 0.25));
+        .foldable(
+            hierarchy_node_label(entity, &tree.q_name),
+            open,
+            empty,
+            |foldable| {
+                foldable
+                    .style()
+                    .margin(UiRect::left(Val::Px(10.)))
+                    .border(UiRect::left(Val::Px(1.)))
+                    .border_color(Color::rgba(0.98, 0.92, 0.84, 0.25));
+            },
+        )
+        .insert(HierarchyNode {
+            hierarchy: hierarchy_id,
+            entity,
         })
-        .insert(HierarchyNode { hierarchy, entity });
+        .id()
 }
 
 #[derive(Component, Debug, Reflect)]
@@ -220,9 +616,14 @@ struct HierarchyNodeContainer {
 
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
-struct RefreshHierarchyButton {
+struct HierarchySearchInput {
+    hierarchy: Entity,
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct HierarchyPickButton {
     hierarchy: Entity,
-    container: Entity,
 }
 
 #[derive(Component, Debug, Reflect)]
@@ -243,10 +644,45 @@ impl HierarchyNode {
 pub struct HierarchyContainer {
     root: Entity,
     selected: Option<Entity>,
-    component_list: Entity,
+    inspector: Entity,
+    style_inspector: Entity,
+    reveal: Option<Entity>,
+    #[reflect(ignore)]
+    filter: Option<HashSet<Entity>>,
+    #[reflect(ignore)]
+    expanded: HashSet<Entity>,
+}
+
+impl HierarchyContainer {
+    pub fn selected(&self) -> Option<Entity> {
+        self.selected
+    }
+
+    fn visible_children(&self, entity: Entity, q_children: &Query<&Children>) -> Vec<Entity> {
+        let Ok(children) = q_children.get(entity) else {
+            return Vec::new();
+        };
+
+        children
+            .iter()
+            .filter(|child| self.is_visible(**child))
+            .copied()
+            .collect()
+    }
+
+    fn is_visible(&self, entity: Entity) -> bool {
+        self.filter
+            .as_ref()
+            .map_or(true, |filter| filter.contains(&entity))
+    }
 }
 
 pub trait UiHierarchyExt {
+    /// Tree view of the entity hierarchy under `root_entity`, with the entity and style
+    /// inspectors of the selected entity. The tree follows changes of the hierarchy as they happen.
+    ///
+    /// The search box filters by `Name`, entity id or component type. Toggling "Pick" selects
+    /// the next UI node or mesh (by its bounding box) clicked under the root.
     fn hierarchy_for(&mut self, root_entity: Entity) -> UiBuilder<Entity>;
 }
 
@@ -257,28 +693,32 @@ impl UiHierarchyExt for UiBuilder<'_, Entity> {
             let main_zone = column
                 .sized_zone(
                     SizedZoneConfig {
-                        size: 70.,
+                        size: 50.,
                         min_size: 200.,
                     },
                     |zone| {
                         let hierarchy_id = zone.id();
-                        let mut refresh_button = Entity::PLACEHOLDER;
                         zone.panel("Hierarchy content".into(), |panel| {
                             panel
                                 .row(|row| {
-                                    refresh_button = row
-                                        .menu_item(MenuItemConfig {
-                                            name: "Refresh".into(),
-                                            trailing_icon: IconData::Image(
-                                                "embedded://sickle_ui/icons/redo_white.png".into(),
-                                                Color::WHITE,
-                                            ),
-                                            ..default()
+                                    row.style().align_items(AlignItems::Center);
+
+                                    row.text_input("", "Search name, id or component")
+                                        .insert(HierarchySearchInput {
+                                            hierarchy: hierarchy_id,
                                         })
                                         .style()
-                                        .margin(UiRect::bottom(Val::Px(5.)))
-                                        .width(Val::Percent(100.))
-                                        .id();
+                                        .flex_grow(1.);
+
+                                    row.toggle_menu_item(ToggleMenuItemConfig {
+                                        name: "Pick".into(),
+                                        ..default()
+                                    })
+                                    .insert(
+                                        HierarchyPickButton {
+                                            hierarchy: hierarchy_id,
+                                        },
+                                    );
                                 })
                                 .style()
                                 .border(UiRect::bottom(Val::Px(1.)))
@@ -286,33 +726,38 @@ impl UiHierarchyExt for UiBuilder<'_, Entity> {
                                 .border_color(Color::ANTIQUE_WHITE);
 
                             panel.scroll_view(None, |scroll_view| {
-                                let node_container = scroll_view
-                                    .column(|_| {})
-                                    .insert(HierarchyNodeContainer {
-                                        hierarchy: hierarchy_id,
-                                    })
-                                    .id();
-
-                                scroll_view.commands().entity(refresh_button).insert(
-                                    RefreshHierarchyButton {
-                                        hierarchy: hierarchy_id,
-                                        container: node_container,
-                                    },
-                                );
+                                scroll_view.column(|_| {}).insert(HierarchyNodeContainer {
+                                    hierarchy: hierarchy_id,
+                                });
                             });
                         });
                     },
                 )
                 .id();
 
-            let mut component_list = Entity::PLACEHOLDER;
+            let mut inspector = Entity::PLACEHOLDER;
+            column.sized_zone(
+                SizedZoneConfig {
+                    size: 25.,
+                    ..default()
+                },
+                |zone| {
+                    zone.scroll_view(None, |scroll_view| {
+                        inspector = scroll_view.entity_inspector(None).id();
+                    });
+                },
+            );
+
+            let mut style_inspector = Entity::PLACEHOLDER;
             column.sized_zone(
                 SizedZoneConfig {
                     size: 25.,
                     ..default()
                 },
                 |zone| {
-                    component_list = zone.entity_component_list(None).id();
+                    zone.scroll_view(None, |scroll_view| {
+                        style_inspector = scroll_view.style_inspector(None).id();
+                    });
                 },
             );
 
@@ -321,7 +766,11 @@ impl UiHierarchyExt for UiBuilder<'_, Entity> {
                 HierarchyContainer {
                     root: root_entity,
                     selected: None,
-                    component_list,
+                    inspector,
+                    style_inspector,
+                    reveal: None,
+                    filter: None,
+                    expanded: HashSet::new(),
                 },
             ));
         })
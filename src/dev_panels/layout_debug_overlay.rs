@@ -0,0 +1,581 @@
+use bevy::{ecs::system::SystemParam, prelude::*, utils::HashMap, window::PrimaryWindow};
+
+use sickle_ui_scaffold::prelude::*;
+
+use crate::widgets::layout::container::UiContainerExt;
+
+const OVERLAY_Z_INDEX: i32 = 1000000;
+const TOOLTIP_OFFSET: f32 = 16.;
+const OUTLINE_COLOR: Color = Color::rgba(0.95, 0.35, 0.65, 0.6);
+const MARGIN_COLOR: Color = Color::rgba(0.98, 0.65, 0.35, 0.5);
+const BORDER_COLOR: Color = Color::rgba(0.99, 0.86, 0.55, 0.5);
+const PADDING_COLOR: Color = Color::rgba(0.76, 0.81, 0.54, 0.5);
+const CONTENT_COLOR: Color = Color::rgba(0.55, 0.71, 0.78, 0.5);
+const SIZE_LABEL_FONT_SIZE: f32 = 10.;
+
+pub struct LayoutDebugOverlayPlugin;
+
+impl Plugin for LayoutDebugOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LayoutDebugOverlay>()
+            .register_type::<LayoutDebugOverlay>()
+            .add_systems(
+                Update,
+                (
+                    toggle_layout_debug_overlay,
+                    update_layout_debug_overlay_root,
+                    update_layout_debug_outlines,
+                    update_layout_debug_inspection,
+                )
+                    .chain()
+                    .in_set(LayoutDebugOverlayUpdate),
+            );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct LayoutDebugOverlayUpdate;
+
+/// Outlines every UI `Node` of the primary window, labeled with its computed size. Outlines are
+/// only updated for nodes whose layout changed. The hovered node is shown with its
+/// margin, border and padding bands, along with its `PseudoStates`, `DynamicStyle`
+/// attributes and `LockedStyleAttributes`.
+///
+/// Static attributes are removed from `DynamicStyle` once applied, so only interactive,
+/// animated and keyframe attributes are listed. See the style inspector for the full theme.
+#[derive(Resource, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct LayoutDebugOverlay {
+    pub enabled: bool,
+    /// Key that toggles `enabled`, if any
+    pub toggle_key: Option<KeyCode>,
+}
+
+impl Default for LayoutDebugOverlay {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            toggle_key: KeyCode::F12.into(),
+        }
+    }
+}
+
+fn toggle_layout_debug_overlay(
+    mut overlay: ResMut<LayoutDebugOverlay>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    let Some(toggle_key) = overlay.toggle_key else {
+        return;
+    };
+
+    if keyboard_input.just_pressed(toggle_key) {
+        overlay.enabled = !overlay.enabled;
+    }
+}
+
+fn update_layout_debug_overlay_root(
+    overlay: Res<LayoutDebugOverlay>,
+    q_root: Query<Entity, With<LayoutDebugOverlayRoot>>,
+    mut commands: Commands,
+) {
+    if !overlay.is_changed() {
+        return;
+    }
+
+    match (overlay.enabled, q_root.iter().next()) {
+        (true, None) => spawn_layout_debug_overlay(&mut commands),
+        (false, Some(root)) => commands.entity(root).despawn_recursive(),
+        _ => (),
+    }
+}
+
+type LayoutChanged = Or<(
+    Changed<Node>,
+    Changed<GlobalTransform>,
+    Changed<InheritedVisibility>,
+    Changed<CalculatedClip>,
+)>;
+
+/// Queries of the outlined nodes, i.e. every node except the overlay's own
+#[derive(SystemParam)]
+struct OutlinedNodes<'w, 's> {
+    q_nodes: Query<
+        'w,
+        's,
+        (
+            &'static Node,
+            &'static GlobalTransform,
+            &'static InheritedVisibility,
+            Option<&'static CalculatedClip>,
+        ),
+        Without<LayoutDebugOverlayNode>,
+    >,
+    q_changed: Query<'w, 's, Entity, (With<Node>, Without<LayoutDebugOverlayNode>, LayoutChanged)>,
+    q_all: Query<'w, 's, Entity, (With<Node>, Without<LayoutDebugOverlayNode>)>,
+}
+
+fn update_layout_debug_outlines(
+    mut q_root: Query<&mut LayoutDebugOverlayRoot>,
+    nodes: OutlinedNodes,
+    mut r_removed: RemovedComponents<Node>,
+    mut q_outlines: Query<(&mut Style, &LayoutDebugOutline)>,
+    mut q_labels: Query<&mut Text, With<LayoutDebugOverlayNode>>,
+    mut commands: Commands,
+) {
+    let Some(mut root) = q_root.iter_mut().next() else {
+        return;
+    };
+
+    for entity in r_removed.read() {
+        if let Some(outline) = root.outlines.remove(&entity) {
+            commands.entity(outline).despawn_recursive();
+        }
+    }
+
+    // A new overlay outlines every node, then only the nodes whose layout changed
+    let changed: Vec<Entity> = match root.is_added() {
+        true => nodes.q_all.iter().collect(),
+        false => nodes.q_changed.iter().collect(),
+    };
+
+    if !changed.is_empty() {
+        root.layout_changed = true;
+    }
+
+    for entity in changed {
+        let Ok((node, transform, visibility, clip)) = nodes.q_nodes.get(entity) else {
+            continue;
+        };
+
+        let rect = match clip {
+            Some(clip) => node.logical_rect(transform).intersect(clip.clip),
+            None => node.logical_rect(transform),
+        };
+        let size = node.size();
+        let size_label = format!("{:.0} x {:.0}", size.x, size.y);
+        let mut style = box_style(rect, Vec4::ONE);
+        if !visibility.get() || rect.is_empty() {
+            style.display = Display::None;
+        }
+
+        if let Some(outline) = root.outlines.get(&entity) {
+            let Ok((mut outline_style, outline)) = q_outlines.get_mut(*outline) else {
+                continue;
+            };
+
+            outline_style.set_if_neq(style);
+            if let Ok(mut text) = q_labels.get_mut(outline.label) {
+                if text.sections[0].value != size_label {
+                    text.sections[0].value = size_label;
+                }
+            }
+
+            continue;
+        }
+
+        if style.display == Display::None {
+            continue;
+        }
+
+        let mut label = Entity::PLACEHOLDER;
+        let outline = commands
+            .ui_builder(root.outline_container)
+            .container(
+                (
+                    NodeBundle {
+                        style,
+                        border_color: OUTLINE_COLOR.into(),
+                        ..default()
+                    },
+                    LayoutDebugOverlayNode,
+                ),
+                |outline| {
+                    label = outline
+                        .spawn((
+                            TextBundle::from_section(
+                                size_label,
+                                TextStyle {
+                                    font_size: SIZE_LABEL_FONT_SIZE,
+                                    color: OUTLINE_COLOR,
+                                    ..default()
+                                },
+                            ),
+                            LayoutDebugOverlayNode,
+                        ))
+                        .id();
+                },
+            )
+            .id();
+
+        commands
+            .entity(outline)
+            .insert(LayoutDebugOutline { label });
+        root.outlines.insert(entity, outline);
+    }
+}
+
+fn update_layout_debug_inspection(world: &mut World) {
+    let Some(root) = world
+        .query::<&LayoutDebugOverlayRoot>()
+        .iter(world)
+        .next()
+        .cloned()
+    else {
+        return;
+    };
+
+    let Ok(window) = world
+        .query_filtered::<&Window, With<PrimaryWindow>>()
+        .get_single(world)
+    else {
+        return;
+    };
+    let window_size = Vec2::new(window.width(), window.height());
+    let cursor = window.cursor_position();
+
+    // The hovered node is only searched again when the cursor moved or the layout changed
+    let hovered = match cursor != root.cursor || root.layout_changed {
+        true => cursor.and_then(|cursor| {
+            world
+                .query_filtered::<(
+                    Entity,
+                    &Node,
+                    &GlobalTransform,
+                    &InheritedVisibility,
+                    Option<&CalculatedClip>,
+                ), Without<LayoutDebugOverlayNode>>()
+                .iter(world)
+                .filter(|(_, node, transform, visibility, clip)| {
+                    visibility.get()
+                        && node.logical_rect(transform).contains(cursor)
+                        && clip.map_or(true, |clip| clip.clip.contains(cursor))
+                })
+                .max_by_key(|(_, node, _, _, _)| node.stack_index())
+                .map(|(entity, node, transform, _, _)| (entity, node.logical_rect(transform)))
+        }),
+        false => root.hovered,
+    };
+
+    if let Some(mut root) = world
+        .query::<&mut LayoutDebugOverlayRoot>()
+        .iter_mut(world)
+        .next()
+    {
+        root.cursor = cursor;
+        root.hovered = hovered;
+        root.layout_changed = false;
+    }
+
+    let (Some(cursor), Some((entity, rect))) = (cursor, hovered) else {
+        for band in root.bands.iter().chain([&root.tooltip]) {
+            if let Some(mut style) = world.get_mut::<Style>(*band) {
+                if style.display != Display::None {
+                    style.display = Display::None;
+                }
+            }
+        }
+
+        return;
+    };
+
+    let margin = UiUtils::margin_as_px(entity, world);
+    let border = UiUtils::border_as_px(entity, world);
+    let padding = UiUtils::padding_as_px(entity, world);
+
+    // Band rects, from the outside in: margin, border, padding and content box
+    let margin_box = Rect {
+        min: rect.min - Vec2::new(margin.w, margin.x),
+        max: rect.max + Vec2::new(margin.y, margin.z),
+    };
+    let padding_box = Rect {
+        min: rect.min + Vec2::new(border.w, border.x),
+        max: rect.max - Vec2::new(border.y, border.z),
+    };
+    let content_box = Rect {
+        min: padding_box.min + Vec2::new(padding.w, padding.x),
+        max: padding_box.max - Vec2::new(padding.y, padding.z),
+    };
+    let band_styles = [
+        box_style(margin_box, margin),
+        box_style(rect, border),
+        box_style(padding_box, padding),
+        box_style(content_box, Vec4::ZERO),
+    ];
+
+    for (band, style) in root.bands.iter().zip(band_styles) {
+        if let Some(mut band_style) = world.get_mut::<Style>(*band) {
+            band_style.set_if_neq(style);
+        }
+    }
+
+    let description = describe_node(entity, rect, margin, border, padding, world);
+    if let Some(mut text) = world.get_mut::<Text>(root.tooltip_text) {
+        if text.sections[0].value != description {
+            text.sections[0].value = description;
+        }
+    }
+
+    // Keep the tooltip inside the window, flipping it to the other side of the cursor
+    let tooltip_size = world
+        .get::<Node>(root.tooltip)
+        .map_or(Vec2::ZERO, |node| node.size());
+    let mut position = cursor + Vec2::splat(TOOLTIP_OFFSET);
+    if position.x + tooltip_size.x > window_size.x {
+        position.x = (cursor.x - TOOLTIP_OFFSET - tooltip_size.x).max(0.);
+    }
+    if position.y + tooltip_size.y > window_size.y {
+        position.y = (cursor.y - TOOLTIP_OFFSET - tooltip_size.y).max(0.);
+    }
+
+    if let Some(mut style) = world.get_mut::<Style>(root.tooltip) {
+        let mut tooltip_style = style.clone();
+        tooltip_style.display = Display::Flex;
+        tooltip_style.left = Val::Px(position.x);
+        tooltip_style.top = Val::Px(position.y);
+        style.set_if_neq(tooltip_style);
+    }
+}
+
+fn describe_node(
+    entity: Entity,
+    rect: Rect,
+    margin: Vec4,
+    border: Vec4,
+    padding: Vec4,
+    world: &World,
+) -> String {
+    let mut lines = vec![
+        match world.get::<Name>(entity) {
+            Some(name) => format!("{} [{:?}]", name, entity),
+            None => format!("[{:?}]", entity),
+        },
+        format!("size: {:.1} x {:.1}", rect.width(), rect.height()),
+        format!("margin: {}", describe_edges(margin)),
+        format!("border: {}", describe_edges(border)),
+        format!("padding: {}", describe_edges(padding)),
+    ];
+
+    let pseudo_states = match world.get::<PseudoStates>(entity) {
+        Some(pseudo_states) if !pseudo_states.is_empty() => pseudo_states
+            .get()
+            .iter()
+            .map(|state| format!("{:?}", state))
+            .collect::<Vec<String>>()
+            .join(", "),
+        _ => "none".into(),
+    };
+    lines.push(format!("pseudo states: {}", pseudo_states));
+
+    match world.get::<DynamicStyle>(entity) {
+        Some(style) if !style.attributes().is_empty() => {
+            lines.push("dynamic style (non-static):".into());
+            lines.extend(
+                style
+                    .attributes()
+                    .iter()
+                    .map(|attribute| format!("  {}", describe_attribute(attribute))),
+            );
+        }
+        _ => lines.push("dynamic style (non-static): none".into()),
+    }
+
+    let mut locked: Vec<String> = world
+        .get::<LockedStyleAttributes>(entity)
+        .map(|locked| {
+            locked
+                .iter()
+                .map(|attribute| format!("{:?}", attribute))
+                .collect()
+        })
+        .unwrap_or_default();
+    locked.sort();
+    lines.push(match locked.is_empty() {
+        true => "locked: none".into(),
+        false => format!("locked: {}", locked.join(", ")),
+    });
+
+    lines.join("\n")
+}
+
+/// Edge sizes in CSS order: top, right, bottom, left
+fn describe_edges(edges: Vec4) -> String {
+    format!("{} {} {} {}", edges.x, edges.y, edges.z, edges.w)
+}
+
+pub(crate) fn describe_attribute(context_attribute: &ContextStyleAttribute) -> String {
+    let description = match context_attribute.attribute() {
+        DynamicStyleAttribute::Static(attribute) => format!("{:?}", attribute),
+        DynamicStyleAttribute::Interactive(attribute) => {
+            format!("{:?} (interactive)", attribute)
+        }
+        DynamicStyleAttribute::Animated { attribute, .. } => {
+            format!("{:?} (animated)", attribute)
+        }
+        DynamicStyleAttribute::Keyframes { attribute, .. } => {
+            format!("{:?} (keyframes)", attribute)
+        }
+    };
+
+    match context_attribute.target() {
+        Some(target) => format!("{} -> [{:?}]", description, target),
+        None => description,
+    }
+}
+
+/// Absolute style covering `rect`, with `border` in the order: Top, Right, Bottom, Left
+fn box_style(rect: Rect, border: Vec4) -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        left: Val::Px(rect.min.x),
+        top: Val::Px(rect.min.y),
+        width: Val::Px(rect.width()),
+        height: Val::Px(rect.height()),
+        border: UiRect {
+            left: Val::Px(border.w),
+            right: Val::Px(border.y),
+            top: Val::Px(border.x),
+            bottom: Val::Px(border.z),
+        },
+        ..default()
+    }
+}
+
+fn spawn_layout_debug_overlay(commands: &mut Commands) {
+    let mut outline_container = Entity::PLACEHOLDER;
+    let mut bands = [Entity::PLACEHOLDER; 4];
+    let mut tooltip = Entity::PLACEHOLDER;
+    let mut tooltip_text = Entity::PLACEHOLDER;
+
+    let full_size = Style {
+        position_type: PositionType::Absolute,
+        width: Val::Percent(100.),
+        height: Val::Percent(100.),
+        ..default()
+    };
+
+    commands
+        .ui_builder(UiRoot)
+        .container(
+            (
+                Name::new("Layout Debug Overlay"),
+                NodeBundle {
+                    style: full_size.clone(),
+                    z_index: ZIndex::Global(OVERLAY_Z_INDEX),
+                    ..default()
+                },
+                LayoutDebugOverlayNode,
+            ),
+            |container| {
+                outline_container = container
+                    .spawn((
+                        NodeBundle {
+                            style: full_size,
+                            ..default()
+                        },
+                        LayoutDebugOverlayNode,
+                    ))
+                    .id();
+
+                let band_colors = [MARGIN_COLOR, BORDER_COLOR, PADDING_COLOR];
+                for (band, color) in bands.iter_mut().zip(band_colors) {
+                    *band = container
+                        .spawn((
+                            NodeBundle {
+                                style: Style {
+                                    display: Display::None,
+                                    ..default()
+                                },
+                                border_color: color.into(),
+                                ..default()
+                            },
+                            LayoutDebugOverlayNode,
+                        ))
+                        .id();
+                }
+
+                bands[3] = container
+                    .spawn((
+                        NodeBundle {
+                            style: Style {
+                                display: Display::None,
+                                ..default()
+                            },
+                            background_color: CONTENT_COLOR.into(),
+                            ..default()
+                        },
+                        LayoutDebugOverlayNode,
+                    ))
+                    .id();
+
+                tooltip = container
+                    .container(
+                        (
+                            NodeBundle {
+                                style: Style {
+                                    display: Display::None,
+                                    position_type: PositionType::Absolute,
+                                    padding: UiRect::all(Val::Px(6.)),
+                                    ..default()
+                                },
+                                background_color: Color::rgba(0.1, 0.1, 0.1, 0.9).into(),
+                                ..default()
+                            },
+                            LayoutDebugOverlayNode,
+                        ),
+                        |tooltip| {
+                            tooltip_text = tooltip
+                                .spawn((
+                                    TextBundle::from_section(
+                                        "",
+                                        TextStyle {
+                                            font_size: 12.,
+                                            color: Color::WHITE,
+                                            ..default()
+                                        },
+                                    ),
+                                    LayoutDebugOverlayNode,
+                                ))
+                                .id();
+                        },
+                    )
+                    .id();
+            },
+        )
+        .insert(LayoutDebugOverlayRoot {
+            outline_container,
+            outlines: HashMap::new(),
+            bands,
+            tooltip,
+            tooltip_text,
+            cursor: None,
+            hovered: None,
+            layout_changed: false,
+        });
+}
+
+/// Marks the entities of the overlay, so they are not outlined themselves
+#[derive(Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+struct LayoutDebugOverlayNode;
+
+/// Outline of a node, with the label showing the node's computed size
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct LayoutDebugOutline {
+    label: Entity,
+}
+
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+struct LayoutDebugOverlayRoot {
+    outline_container: Entity,
+    /// Outline of each node, by node entity
+    outlines: HashMap<Entity, Entity>,
+    bands: [Entity; 4],
+    tooltip: Entity,
+    tooltip_text: Entity,
+    cursor: Option<Vec2>,
+    hovered: Option<(Entity, Rect)>,
+    /// Set when outlines were updated since the hovered node was last searched
+    layout_changed: bool,
+}
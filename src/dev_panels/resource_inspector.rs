@@ -0,0 +1,342 @@
+use bevy::{ecs::system::CommandQueue, prelude::*};
+
+use sickle_ui_scaffold::prelude::*;
+
+use crate::widgets::{
+    layout::{
+        column::UiColumnExt,
+        panel::UiPanelExt,
+        row::UiRowExt,
+        scroll_view::UiScrollViewExt,
+        sized_zone::{SizedZoneConfig, UiSizedZoneExt},
+    },
+    menus::menu_item::{MenuItem, MenuItemConfig, UiMenuItemExt},
+};
+
+use super::entity_inspector::{
+    collect_shape, rebuild_inspector, EntityInspectorPlugin, InspectedValue, InspectorItem,
+    InspectorTarget,
+};
+
+pub struct ResourceInspectorPlugin;
+
+impl Plugin for ResourceInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<EntityInspectorPlugin>() {
+            app.add_plugins(EntityInspectorPlugin);
+        }
+
+        app.add_systems(
+            PreUpdate,
+            (
+                refresh_resource_list_on_press,
+                update_resource_selection,
+                update_resource_entry_style,
+            )
+                .chain()
+                .in_set(ResourceInspectorPreUpdate),
+        )
+        .add_systems(
+            Update,
+            (update_resource_lists, update_resource_inspectors).chain(),
+        );
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct ResourceInspectorPreUpdate;
+
+fn refresh_resource_list_on_press(
+    q_menu_items: Query<(&MenuItem, &RefreshResourceListButton), Changed<MenuItem>>,
+    mut q_inspectors: Query<&mut ResourceInspector>,
+) {
+    for (menu_item, refresh_button) in &q_menu_items {
+        if menu_item.interacted() {
+            let Ok(mut inspector) = q_inspectors.get_mut(refresh_button.inspector) else {
+                continue;
+            };
+
+            inspector.refresh_list = true;
+        }
+    }
+}
+
+fn update_resource_selection(
+    q_entries: Query<(&MenuItem, &ResourceListEntry), Changed<MenuItem>>,
+    mut q_inspectors: Query<&mut ResourceInspector>,
+) {
+    for (menu_item, entry) in &q_entries {
+        if menu_item.interacted() {
+            let Ok(mut inspector) = q_inspectors.get_mut(entry.inspector) else {
+                continue;
+            };
+
+            if inspector.selected.as_ref() != Some(&entry.type_path) {
+                inspector.selected = entry.type_path.clone().into();
+            }
+        }
+    }
+}
+
+fn update_resource_entry_style(
+    q_inspectors: Query<(Entity, &ResourceInspector), Changed<ResourceInspector>>,
+    q_entries: Query<(Entity, &ResourceListEntry)>,
+    mut commands: Commands,
+) {
+    for (entity, inspector) in &q_inspectors {
+        for (menu_item, entry) in q_entries
+            .iter()
+            .filter(|(_, entry)| entry.inspector == entity)
+        {
+            let color = match inspector.selected.as_ref() == Some(&entry.type_path) {
+                true => Color::GRAY,
+                false => Color::NONE,
+            };
+            commands.style(menu_item).background_color(color);
+        }
+    }
+}
+
+fn update_resource_lists(world: &mut World) {
+    let inspectors: Vec<(Entity, Entity)> = world
+        .query::<(Entity, &ResourceInspector)>()
+        .iter(world)
+        .filter(|(_, inspector)| inspector.refresh_list)
+        .map(|(entity, inspector)| (entity, inspector.list))
+        .collect();
+
+    if inspectors.is_empty() {
+        return;
+    }
+
+    let resources = inspectable_resources(world);
+
+    let mut queue = CommandQueue::default();
+    let mut commands = Commands::new(&mut queue, world);
+    for (inspector, list) in inspectors.iter().copied() {
+        commands.entity(list).despawn_descendants();
+
+        let mut builder = commands.ui_builder(list);
+        for (type_path, name) in resources.iter() {
+            builder
+                .menu_item(MenuItemConfig {
+                    name: name.clone(),
+                    ..default()
+                })
+                .insert(ResourceListEntry {
+                    inspector,
+                    type_path: type_path.clone(),
+                });
+        }
+    }
+    queue.apply(world);
+
+    // Marks the inspectors changed, so the new entries are styled
+    for (inspector, _) in inspectors {
+        if let Some(mut inspector) = world.get_mut::<ResourceInspector>(inspector) {
+            inspector.refresh_list = false;
+        }
+    }
+}
+
+/// Type path and name of registered resources that reflect `Resource` and exist in the world
+fn inspectable_resources(world: &World) -> Vec<(String, String)> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+
+    let mut resources: Vec<(String, String)> = registry
+        .iter()
+        .filter(|registration| {
+            registration
+                .data::<ReflectResource>()
+                .is_some_and(|reflect_resource| reflect_resource.reflect(world).is_some())
+        })
+        .map(|registration| {
+            let type_path_table = registration.type_info().type_path_table();
+            (
+                type_path_table.path().to_string(),
+                type_path_table.short_path().to_string(),
+            )
+        })
+        .collect();
+
+    resources.sort_by(|(_, a), (_, b)| a.cmp(b));
+    resources
+}
+
+fn update_resource_inspectors(world: &mut World) {
+    let inspectors: Vec<(Entity, Entity, Option<String>, bool)> = world
+        .query::<(Entity, Ref<ResourceInspector>)>()
+        .iter(world)
+        .map(|(entity, inspector)| {
+            (
+                entity,
+                inspector.fields,
+                inspector.selected.clone(),
+                inspector.is_changed(),
+            )
+        })
+        .collect();
+
+    for (entity, fields, selected, changed) in inspectors {
+        let target = selected
+            .as_ref()
+            .and_then(|type_path| resource_target(type_path, world));
+
+        let shape = target.as_ref().and_then(|target| {
+            let mut shape = Vec::new();
+            collect_shape(target.reflect(world)?, &mut shape);
+            Some(shape)
+        });
+
+        // Safe unwrap: queried above
+        let inspector = world.get::<ResourceInspector>(entity).unwrap();
+        if !changed && inspector.shape == shape {
+            continue;
+        }
+
+        world
+            .get_mut::<ResourceInspector>(entity)
+            .unwrap()
+            .bypass_change_detection()
+            .shape = shape;
+
+        let values = match (selected, target) {
+            (Some(type_path), Some(target)) => {
+                let registry = world.resource::<AppTypeRegistry>().clone();
+                let items = InspectorItem::fields_of(Some(&target), world, &registry.read());
+
+                vec![InspectedValue {
+                    target: Some(target),
+                    key_prefix: type_path,
+                    items,
+                }]
+            }
+            _ => Vec::new(),
+        };
+
+        rebuild_inspector(fields, values, world);
+    }
+}
+
+fn resource_target(type_path: &str, world: &World) -> Option<InspectorTarget> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let reflect_resource = registry
+        .get_with_type_path(type_path)?
+        .data::<ReflectResource>()?;
+
+    Some(InspectorTarget::Resource(reflect_resource.clone()))
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct RefreshResourceListButton {
+    inspector: Entity,
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+struct ResourceListEntry {
+    inspector: Entity,
+    type_path: String,
+}
+
+/// Lists the reflected resources of the world and edits the selected one.
+/// Set `selected` to the full type path of a resource to inspect it.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ResourceInspector {
+    pub selected: Option<String>,
+    list: Entity,
+    fields: Entity,
+    refresh_list: bool,
+    #[reflect(ignore)]
+    shape: Option<Vec<usize>>,
+}
+
+pub trait UiResourceInspectorExt {
+    /// Dev panel listing the resources that are registered with `#[reflect(Resource)]`.
+    /// The selected resource is shown and edited the same way as components in the
+    /// entity inspector. `selected` is the full type path of the resource shown initially.
+    fn resource_inspector(&mut self, selected: Option<String>) -> UiBuilder<'_, Entity>;
+}
+
+impl UiResourceInspectorExt for UiBuilder<'_, Entity> {
+    fn resource_inspector(&mut self, selected: Option<String>) -> UiBuilder<'_, Entity> {
+        self.column(|column| {
+            column.style().width(Val::Percent(100.));
+
+            let inspector_id = column.id();
+            let mut list = Entity::PLACEHOLDER;
+            let mut refresh_button = Entity::PLACEHOLDER;
+            column.sized_zone(
+                SizedZoneConfig {
+                    size: 40.,
+                    min_size: 100.,
+                },
+                |zone| {
+                    zone.panel("Resources".into(), |panel| {
+                        panel
+                            .row(|row| {
+                                refresh_button = row
+                                    .menu_item(MenuItemConfig {
+                                        name: "Refresh".into(),
+                                        trailing_icon: IconData::Image(
+                                            "embedded://sickle_ui/icons/redo_white.png".into(),
+                                            Color::WHITE,
+                                        ),
+                                        ..default()
+                                    })
+                                    .style()
+                                    .margin(UiRect::bottom(Val::Px(5.)))
+                                    .width(Val::Percent(100.))
+                                    .id();
+                            })
+                            .style()
+                            .border(UiRect::bottom(Val::Px(1.)))
+                            .margin(UiRect::bottom(Val::Px(10.)))
+                            .border_color(Color::ANTIQUE_WHITE);
+
+                        panel.scroll_view(None, |scroll_view| {
+                            list = scroll_view.column(|_| {}).id();
+                        });
+                    });
+                },
+            );
+
+            let mut fields = Entity::PLACEHOLDER;
+            column.sized_zone(
+                SizedZoneConfig {
+                    size: 60.,
+                    ..default()
+                },
+                |zone| {
+                    zone.scroll_view(None, |scroll_view| {
+                        fields = scroll_view
+                            .column(|column| {
+                                column.style().width(Val::Percent(100.));
+                            })
+                            .id();
+                    });
+                },
+            );
+
+            column
+                .commands()
+                .entity(refresh_button)
+                .insert(RefreshResourceListButton {
+                    inspector: inspector_id,
+                });
+
+            column.insert((
+                Name::new("Resource Inspector"),
+                ResourceInspector {
+                    selected,
+                    list,
+                    fields,
+                    refresh_list: true,
+                    shape: None,
+                },
+            ));
+        })
+    }
+}
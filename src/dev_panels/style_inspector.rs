@@ -0,0 +1,412 @@
+use bevy::{ecs::system::CommandQueue, prelude::*, utils::HashMap};
+
+use sickle_ui_scaffold::{
+    prelude::*,
+    theme::{ThemeExplanation, ThemeRegistry},
+    ui_commands::UpdateTextExt,
+};
+
+use crate::widgets::layout::{
+    column::UiColumnExt,
+    foldable::{Foldable, UiFoldableExt},
+    label::{LabelConfig, UiLabelExt},
+    row::UiRowExt,
+};
+
+use super::layout_debug_overlay::describe_attribute;
+
+pub struct StyleInspectorPlugin;
+
+impl Plugin for StyleInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (update_style_inspectors, update_animation_state_labels).chain(),
+        );
+    }
+}
+
+fn update_style_inspectors(world: &mut World) {
+    let inspectors: Vec<(Entity, Option<Entity>, bool)> = world
+        .query::<(Entity, Ref<StyleInspector>)>()
+        .iter(world)
+        .map(|(entity, inspector)| (entity, inspector.entity, inspector.is_changed()))
+        .collect();
+
+    for (container, selected, changed) in inspectors {
+        let items = match selected {
+            Some(selected) => explain_styles(selected, world)
+                .iter()
+                .map(|explanation| {
+                    StyleInspectorItem::from_explanation(explanation, selected, world)
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        // Safe unwrap: queried above
+        let inspector = world.get::<StyleInspector>(container).unwrap();
+        if !changed && inspector.items == items {
+            continue;
+        }
+
+        world
+            .get_mut::<StyleInspector>(container)
+            .unwrap()
+            .bypass_change_detection()
+            .items = items.clone();
+
+        rebuild_style_inspector(container, items, world);
+    }
+}
+
+/// Explains the themes of `entity`, and the themes of its ancestors that style it
+/// through one of their contexts.
+fn explain_styles(entity: Entity, world: &World) -> Vec<ThemeExplanation> {
+    let Some(registry) = world.get_resource::<ThemeRegistry>() else {
+        return Vec::new();
+    };
+
+    let mut explanations = registry.explain(entity, world);
+    let mut current_ancestor = entity;
+    while let Some(parent) = world.get::<Parent>(current_ancestor) {
+        current_ancestor = parent.get();
+        explanations.extend(
+            registry
+                .explain(current_ancestor, world)
+                .into_iter()
+                .filter(|explanation| {
+                    explanation.styles.iter().any(|context_style| {
+                        context_style.entity == entity
+                            || context_style
+                                .style
+                                .attributes()
+                                .iter()
+                                .any(|attribute| attribute.target() == Some(entity))
+                    })
+                }),
+        );
+    }
+
+    explanations
+}
+
+fn rebuild_style_inspector(container: Entity, items: Vec<StyleInspectorItem>, world: &mut World) {
+    let sections: HashMap<String, bool> = world
+        .query::<(&StyleInspectorSection, &Foldable)>()
+        .iter(world)
+        .filter(|(section, _)| section.inspector == container)
+        .map(|(section, foldable)| (section.key.clone(), foldable.open))
+        .collect();
+
+    let mut queue = CommandQueue::default();
+    let mut commands = Commands::new(&mut queue, world);
+
+    commands.entity(container).despawn_descendants();
+    let mut builder = commands.ui_builder(container);
+    for item in items {
+        spawn_style_inspector_item(&mut builder, item, container, &sections, true);
+    }
+
+    queue.apply(world);
+}
+
+fn spawn_style_inspector_item(
+    builder: &mut UiBuilder<Entity>,
+    item: StyleInspectorItem,
+    inspector: Entity,
+    sections: &HashMap<String, bool>,
+    top_level: bool,
+) {
+    match item {
+        StyleInspectorItem::Section { name, key, fields } => {
+            // New top level sections start open, nested ones closed
+            let open = sections.get(&key).copied().unwrap_or(top_level);
+            let empty = fields.is_empty();
+            builder
+                .foldable(name, open, empty, |section| {
+                    for field in fields {
+                        spawn_style_inspector_item(section, field, inspector, sections, false);
+                    }
+                })
+                .insert(StyleInspectorSection { inspector, key });
+        }
+        StyleInspectorItem::Row { name, text } => {
+            builder.row(|row| {
+                row.style().align_items(AlignItems::Center);
+                row.label(LabelConfig {
+                    label: name,
+                    margin: UiRect::horizontal(Val::Px(5.)),
+                    ..default()
+                })
+                .style()
+                .min_width(Val::Px(80.));
+                row.label(LabelConfig {
+                    label: text,
+                    ..default()
+                });
+            });
+        }
+        StyleInspectorItem::Text(text) => {
+            builder.label(LabelConfig {
+                label: text,
+                margin: UiRect::horizontal(Val::Px(5.)),
+                ..default()
+            });
+        }
+        StyleInspectorItem::AnimationState(label) => {
+            builder
+                .label(LabelConfig {
+                    margin: UiRect::left(Val::Px(15.)),
+                    ..default()
+                })
+                .insert(*label);
+        }
+    }
+}
+
+fn update_animation_state_labels(
+    q_labels: Query<(Entity, &AnimationStateLabel, &Text)>,
+    q_dynamic_styles: Query<&DynamicStyle>,
+    mut commands: Commands,
+) {
+    for (entity, label, text) in &q_labels {
+        let state = q_dynamic_styles
+            .get(label.placement)
+            .ok()
+            .and_then(|style| {
+                style.attributes().iter().find(|context_attribute| {
+                    context_attribute.target() == label.target
+                        && context_attribute.attribute().logical_eq(&label.attribute)
+                })
+            })
+            .and_then(|context_attribute| context_attribute.attribute().controller().ok());
+
+        let state = match state {
+            Some(controller) => format!("state: {:?}", controller.current_state()),
+            None => "state: not animating".into(),
+        };
+
+        match text.sections.first() {
+            Some(section) if section.value == state => (),
+            _ => {
+                commands.entity(entity).update_text(state);
+            }
+        }
+    }
+}
+
+fn entity_label(entity: Entity, world: &World) -> String {
+    match world.get::<Name>(entity) {
+        Some(name) => format!("[{:?}] {}", entity, name),
+        None => format!("[{:?}]", entity),
+    }
+}
+
+fn pseudo_state_list(states: &[PseudoState]) -> String {
+    states
+        .iter()
+        .map(|state| format!("{:?}", state))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Content of the inspector, collected before it is spawned. The inspector is only
+/// rebuilt when this changes; animation states are refreshed in place.
+#[derive(Clone, Debug, PartialEq)]
+enum StyleInspectorItem {
+    Section {
+        name: String,
+        key: String,
+        fields: Vec<StyleInspectorItem>,
+    },
+    Row {
+        name: String,
+        text: String,
+    },
+    Text(String),
+    AnimationState(Box<AnimationStateLabel>),
+}
+
+impl StyleInspectorItem {
+    fn from_explanation(explanation: &ThemeExplanation, selected: Entity, world: &World) -> Self {
+        let component = UiUtils::simplify_type_name(explanation.component);
+        let (name, key) = match explanation.entity == selected {
+            true => (component.clone(), component.clone()),
+            false => (
+                format!(
+                    "{} of {}",
+                    component,
+                    entity_label(explanation.entity, world)
+                ),
+                format!("{} of ancestor", component),
+            ),
+        };
+
+        let source_label = |source: Option<Entity>| match source {
+            Some(source) if source == explanation.entity => "own".to_string(),
+            Some(source) => entity_label(source, world),
+            None => "default".into(),
+        };
+
+        let themes = explanation
+            .themes
+            .iter()
+            .map(|source| source_label(*source))
+            .collect::<Vec<String>>()
+            .join(" > ");
+
+        let pseudo_states = match explanation.pseudo_states.is_empty() {
+            true => "none".into(),
+            false => pseudo_state_list(&explanation.pseudo_states),
+        };
+
+        let candidates = explanation
+            .candidates
+            .iter()
+            .map(|candidate| {
+                let state = match &candidate.state {
+                    Some(states) if !states.is_empty() => {
+                        format!("[{}]", pseudo_state_list(states))
+                    }
+                    _ => "base".into(),
+                };
+                let order = match candidate.applied {
+                    Some(index) => format!("#{}", index + 1),
+                    None => "skipped".into(),
+                };
+
+                StyleInspectorItem::Text(format!(
+                    "{} {} (score {}, {})",
+                    order,
+                    state,
+                    candidate.score,
+                    source_label(candidate.source)
+                ))
+            })
+            .collect();
+
+        let mut fields = vec![
+            StyleInspectorItem::Row {
+                name: "themes".into(),
+                text: themes,
+            },
+            StyleInspectorItem::Row {
+                name: "pseudo states".into(),
+                text: pseudo_states,
+            },
+            StyleInspectorItem::Section {
+                name: format!("Pseudo themes ({})", explanation.candidates.len()),
+                key: format!("{}/pseudo themes", key),
+                fields: candidates,
+            },
+        ];
+
+        for context_style in explanation.styles.iter() {
+            let placement = match context_style.context {
+                Some(context) => {
+                    format!("{} {}", context, entity_label(context_style.entity, world))
+                }
+                None => "self".into(),
+            };
+
+            let mut attributes = Vec::new();
+            for context_attribute in context_style.style.attributes() {
+                let styled_entity = context_attribute.target().unwrap_or(context_style.entity);
+                let locked = context_attribute
+                    .attribute()
+                    .lockable()
+                    .zip(world.get::<LockedStyleAttributes>(styled_entity))
+                    .is_some_and(|(lockable, locked)| locked.contains(lockable));
+
+                let description = describe_attribute(context_attribute);
+                attributes.push(StyleInspectorItem::Text(match locked {
+                    true => format!("{} - skipped, locked", description),
+                    false => description,
+                }));
+
+                if let DynamicStyleAttribute::Animated { attribute, .. } =
+                    context_attribute.attribute()
+                {
+                    attributes.push(StyleInspectorItem::AnimationState(Box::new(
+                        AnimationStateLabel {
+                            placement: context_style.entity,
+                            target: context_attribute.target(),
+                            attribute: DynamicStyleAttribute::Animated {
+                                attribute: attribute.clone(),
+                                controller: default(),
+                            },
+                        },
+                    )));
+                }
+            }
+
+            fields.push(StyleInspectorItem::Section {
+                name: format!("Style of {}", placement),
+                key: format!("{}/style {:?}", key, context_style.context),
+                fields: attributes,
+            });
+        }
+
+        StyleInspectorItem::Section { name, key, fields }
+    }
+}
+
+/// Foldable section of the inspector. Sections keep their state when the inspector is rebuilt.
+#[derive(Component, Clone, Debug)]
+struct StyleInspectorSection {
+    inspector: Entity,
+    key: String,
+}
+
+/// Shows the current `AnimationState` of an animated attribute
+#[derive(Component, Clone, Debug)]
+struct AnimationStateLabel {
+    placement: Entity,
+    target: Option<Entity>,
+    attribute: DynamicStyleAttribute,
+}
+
+impl PartialEq for AnimationStateLabel {
+    fn eq(&self, other: &Self) -> bool {
+        self.placement == other.placement
+            && self.target == other.target
+            && self.attribute.logical_eq(&other.attribute)
+    }
+}
+
+/// Explains the theming of the selected entity. Set `entity` to inspect another entity.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct StyleInspector {
+    pub entity: Option<Entity>,
+    #[reflect(ignore)]
+    items: Vec<StyleInspectorItem>,
+}
+
+pub trait UiStyleInspectorExt {
+    /// Dev panel that explains the styles of an entity. For each `Theme<C>` affecting it, it lists
+    /// the candidate `PseudoTheme`s with their `count_match` scores and order of application,
+    /// and the merged `DynamicStyle` of each context placement. Animated attributes show their
+    /// current `AnimationState`, attributes locked by `LockedStyleAttributes` are marked skipped.
+    ///
+    /// Themes of ancestors are included when they style the entity through a `UiContext`.
+    fn style_inspector(&mut self, entity: Option<Entity>) -> UiBuilder<'_, Entity>;
+}
+
+impl UiStyleInspectorExt for UiBuilder<'_, Entity> {
+    fn style_inspector(&mut self, entity: Option<Entity>) -> UiBuilder<'_, Entity> {
+        self.column(|column| {
+            column
+                .insert((
+                    Name::new("Style Inspector"),
+                    StyleInspector {
+                        entity,
+                        items: Vec::new(),
+                    },
+                ))
+                .style()
+                .width(Val::Percent(100.));
+        })
+    }
+}
@@ -2,6 +2,10 @@ mod assets;
 pub mod dev_panels;
 pub mod hierarchy_delay;
 pub mod input_extension;
+pub mod localization;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
+pub mod ui_description;
 pub mod widgets;
 
 use bevy::prelude::*;
@@ -13,8 +17,10 @@ use drop_interaction::DropInteractionPlugin;
 use flux_interaction::FluxInteractionPlugin;
 use hierarchy_delay::HierarchyDelayPlugin;
 use interactions::InteractionsPlugin;
+use localization::LocalizationPlugin;
 use scroll_interaction::ScrollInteractionPlugin;
 use theme::ThemePlugin;
+use ui_description::UiDescriptionPlugin;
 use widgets::WidgetsPlugin;
 
 pub use sickle_macros::*;
@@ -22,6 +28,9 @@ pub use sickle_math::*;
 pub use sickle_ui_scaffold::*;
 
 pub mod prelude {
+    pub use super::localization::{
+        Locale, LocaleProvider, LocalizationUpdate, LocalizedText, MessageCatalog,
+    };
     pub use super::widgets::prelude::*;
     pub use sickle_macros::*;
     pub use sickle_math::*;
@@ -43,9 +52,11 @@ impl Plugin for SickleUiPlugin {
             HierarchyDelayPlugin,
             FluxInteractionPlugin,
             InteractionsPlugin,
+            LocalizationPlugin,
             ScrollInteractionPlugin,
             WidgetsPlugin,
             ThemePlugin,
+            UiDescriptionPlugin,
         ));
     }
 }
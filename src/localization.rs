@@ -0,0 +1,328 @@
+use std::sync::Arc;
+
+use bevy::{a11y::AccessibilityNode, prelude::*, ui::widget::measure_text_system, utils::HashMap};
+
+use sickle_ui_scaffold::ui_commands::AccessibleNameFrom;
+
+pub struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Locale>().add_systems(
+            PostUpdate,
+            (update_localized_texts, update_accessible_names)
+                .chain()
+                .in_set(LocalizationUpdate)
+                .before(measure_text_system),
+        );
+    }
+}
+
+/// Localized texts are rendered before text measurement, so layout sees the translated text
+/// in the same frame the locale or the message changes.
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct LocalizationUpdate;
+
+fn update_localized_texts(
+    r_locale: Res<Locale>,
+    mut q_texts: Query<(Ref<LocalizedText>, &mut Text)>,
+) {
+    let locale_changed = r_locale.is_changed();
+
+    for (localized, mut text) in &mut q_texts {
+        if !locale_changed && !localized.is_changed() {
+            continue;
+        }
+
+        let value = r_locale.format(&localized);
+        if let Some(section) = text.sections.first_mut() {
+            if section.value != value {
+                section.value = value;
+            }
+        } else {
+            text.sections = vec![TextSection::new(value, TextStyle::default())];
+        }
+    }
+}
+
+/// Widgets name their accessibility node after their label when they update it. Labels are
+/// translated later, so the names are refreshed whenever a label's text changes.
+fn update_accessible_names(
+    q_texts: Query<Ref<Text>>,
+    mut q_nodes: Query<(&AccessibleNameFrom, &mut AccessibilityNode)>,
+) {
+    for (name_from, mut node) in &mut q_nodes {
+        let Ok(text) = q_texts.get(name_from.0) else {
+            continue;
+        };
+
+        if !text.is_changed() {
+            continue;
+        }
+
+        let name = AccessibleNameFrom::name(&text);
+        if node.name() == name.as_deref() {
+            continue;
+        }
+
+        match name {
+            Some(name) => node.set_name(name),
+            None => node.clear_name(),
+        }
+    }
+}
+
+/// A message reference that is resolved by the current [`Locale`] into the first section of the
+/// entity's [`Text`]. Re-rendered when either the message or the locale changes.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct LocalizedText {
+    id: String,
+    args: Vec<(String, String)>,
+    fallback: Option<String>,
+}
+
+impl LocalizedText {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            ..default()
+        }
+    }
+
+    /// Sets the value of the `{ $name }` placeable in the message
+    pub fn with_arg(mut self, name: impl Into<String>, value: impl ToString) -> Self {
+        self.set_arg(name, value);
+        self
+    }
+
+    /// Text displayed when no translation is found. Defaults to the message id.
+    pub fn with_fallback(self, fallback: impl Into<String>) -> Self {
+        Self {
+            fallback: Some(fallback.into()),
+            ..self
+        }
+    }
+
+    pub fn set_arg(&mut self, name: impl Into<String>, value: impl ToString) {
+        let name = name.into();
+        let value = value.to_string();
+
+        if let Some(arg) = self.args.iter_mut().find(|(arg, _)| *arg == name) {
+            arg.1 = value;
+        } else {
+            self.args.push((name, value));
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn args(&self) -> &[(String, String)] {
+        &self.args
+    }
+
+    /// The text used before the locale resolves the message, or when it has no translation
+    pub fn fallback(&self) -> String {
+        match &self.fallback {
+            Some(fallback) => format_pattern(fallback, &self.args),
+            None => self.id.clone(),
+        }
+    }
+}
+
+/// Source of translated messages for a [`Locale`]
+pub trait LocaleProvider: std::fmt::Debug + Send + Sync + 'static {
+    /// Returns the formatted message, or `None` if the locale has no such message
+    fn format(&self, locale: &str, id: &str, args: &[(String, String)]) -> Option<String>;
+}
+
+/// The active locale and the provider used to resolve [`LocalizedText`]s.
+/// Changing either re-renders every localized text.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct Locale {
+    current: String,
+    #[reflect(ignore)]
+    provider: Option<Arc<dyn LocaleProvider>>,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self {
+            current: "en-US".into(),
+            provider: None,
+        }
+    }
+}
+
+impl Locale {
+    pub fn new(current: impl Into<String>, provider: impl LocaleProvider) -> Self {
+        Self {
+            current: current.into(),
+            provider: Some(Arc::new(provider)),
+        }
+    }
+
+    pub fn current(&self) -> &str {
+        &self.current
+    }
+
+    pub fn set_current(&mut self, locale: impl Into<String>) {
+        let locale = locale.into();
+        if self.current != locale {
+            self.current = locale;
+        }
+    }
+
+    pub fn set_provider(&mut self, provider: impl LocaleProvider) {
+        self.provider = Some(Arc::new(provider));
+    }
+
+    pub fn format(&self, text: &LocalizedText) -> String {
+        let Some(provider) = &self.provider else {
+            return text.fallback();
+        };
+
+        provider
+            .format(&self.current, &text.id, &text.args)
+            .unwrap_or_else(|| text.fallback())
+    }
+}
+
+/// A simple in-memory [`LocaleProvider`] that reads Fluent-style `id = pattern` messages.
+///
+/// Supports comments, multiline patterns (indented continuation lines) and `{ $name }`
+/// placeables. Missing messages are looked up in the fallback locale, if set.
+#[derive(Clone, Debug, Default)]
+pub struct MessageCatalog {
+    locales: HashMap<String, HashMap<String, String>>,
+    fallback_locale: Option<String>,
+}
+
+impl MessageCatalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_fallback_locale(self, locale: impl Into<String>) -> Self {
+        Self {
+            fallback_locale: Some(locale.into()),
+            ..self
+        }
+    }
+
+    pub fn with_message(
+        mut self,
+        locale: impl Into<String>,
+        id: impl Into<String>,
+        pattern: impl Into<String>,
+    ) -> Self {
+        self.add_message(locale, id, pattern);
+        self
+    }
+
+    pub fn with_resource(mut self, locale: impl Into<String>, source: &str) -> Self {
+        self.add_resource(locale, source);
+        self
+    }
+
+    pub fn add_message(
+        &mut self,
+        locale: impl Into<String>,
+        id: impl Into<String>,
+        pattern: impl Into<String>,
+    ) {
+        self.locales
+            .entry(locale.into())
+            .or_default()
+            .insert(id.into(), pattern.into());
+    }
+
+    /// Adds all messages of a Fluent-style resource to the locale
+    pub fn add_resource(&mut self, locale: impl Into<String>, source: &str) {
+        let messages = self.locales.entry(locale.into()).or_default();
+        let mut current: Option<(String, String)> = None;
+
+        for line in source.lines() {
+            let trimmed = line.trim();
+            if trimmed.starts_with('#') {
+                continue;
+            }
+
+            let is_continuation = line.starts_with(char::is_whitespace) && !trimmed.is_empty();
+            if is_continuation {
+                if let Some((_, pattern)) = current.as_mut() {
+                    if !pattern.is_empty() {
+                        pattern.push('\n');
+                    }
+                    pattern.push_str(trimmed);
+                }
+
+                continue;
+            }
+
+            if let Some((id, pattern)) = current.take() {
+                messages.insert(id, pattern);
+            }
+
+            if let Some((id, pattern)) = trimmed.split_once('=') {
+                let id = id.trim();
+                if !id.is_empty() {
+                    current = Some((id.into(), pattern.trim().into()));
+                }
+            }
+        }
+
+        if let Some((id, pattern)) = current {
+            messages.insert(id, pattern);
+        }
+    }
+}
+
+impl LocaleProvider for MessageCatalog {
+    fn format(&self, locale: &str, id: &str, args: &[(String, String)]) -> Option<String> {
+        let pattern = self
+            .locales
+            .get(locale)
+            .and_then(|messages| messages.get(id))
+            .or_else(|| {
+                let fallback = self.fallback_locale.as_ref()?;
+                self.locales.get(fallback)?.get(id)
+            })?;
+
+        Some(format_pattern(pattern, args))
+    }
+}
+
+/// Replaces `{ $name }` placeables with their argument values. Unknown placeables are kept as is.
+fn format_pattern(pattern: &str, args: &[(String, String)]) -> String {
+    let mut result = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+
+        let Some(end) = rest[start..].find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+
+        let placeable = &rest[start..start + end + 1];
+        let name = placeable[1..placeable.len() - 1].trim();
+        let value = name
+            .strip_prefix('$')
+            .and_then(|name| args.iter().find(|(arg, _)| arg == name));
+
+        match value {
+            Some((_, value)) => result.push_str(value),
+            None => result.push_str(placeable),
+        }
+
+        rest = &rest[start + end + 1..];
+    }
+
+    result.push_str(rest);
+    result
+}
@@ -0,0 +1,393 @@
+//! Headless [`App`] for testing widgets without a GPU or an OS window.
+//!
+//! [`UiTestHarness`] runs the full UI pipeline (focus, interactions, theming and layout) against
+//! a fixed-size virtual window. Input is simulated by feeding the same events the windowing
+//! backend would send, so `Interaction`, `FluxInteraction`, `Draggable` and `Scrollable`
+//! behave as they do in a running app.
+//!
+//! The UI tree can be compared against checked-in text snapshots with
+//! [`assert_ui_snapshot!`](crate::assert_ui_snapshot), see [`snapshot`].
+//!
+//! Only compiled with the `test-support` feature, i.e. enable it on a dev-dependency.
+//!
+//! ## Example
+//! ```no_run
+//! use bevy::prelude::*;
+//! use sickle_ui::{prelude::*, test_support::UiTestHarness};
+//!
+//! let mut harness = UiTestHarness::new();
+//! let checkbox = harness.spawn_ui(|ui| {
+//!     let mut checkbox = Entity::PLACEHOLDER;
+//!     ui.column(|column| {
+//!         checkbox = column.checkbox(None, false).id();
+//!     });
+//!     checkbox
+//! });
+//!
+//! harness.click(checkbox);
+//! assert!(harness.world().get::<Checkbox>(checkbox).unwrap().checked);
+//! ```
+
+pub mod snapshot;
+
+use std::time::Duration;
+
+use bevy::{
+    audio::AudioPlugin,
+    ecs::system::CommandQueue,
+    input::{
+        keyboard::{Key, KeyboardInput, NativeKey},
+        mouse::{MouseButtonInput, MouseScrollUnit, MouseWheel},
+        touch::{TouchInput, TouchPhase},
+        ButtonState,
+    },
+    log::LogPlugin,
+    prelude::*,
+    render::{settings::WgpuSettings, RenderPlugin},
+    time::TimeUpdateStrategy,
+    window::{ExitCondition, PrimaryWindow, ReceivedCharacter, WindowResolution},
+    winit::WinitPlugin,
+};
+
+use sickle_ui_scaffold::{
+    theme::dynamic_style::DynamicStyle,
+    ui_builder::{UiBuilder, UiBuilderExt, UiRoot},
+    ui_style::builder::StyleBuilder,
+};
+
+use crate::SickleUiPlugin;
+
+/// A headless app with [`SickleUiPlugin`], a 2D camera and a virtual primary window.
+///
+/// Each update advances time by [`UiTestHarness::FRAME_DURATION`]. Input helpers run
+/// [`UiTestHarness::SETTLE_FRAMES`] updates after each input, so systems that are not ordered
+/// after the interaction updates also observe it before the helper returns.
+pub struct UiTestHarness {
+    app: App,
+    window: Entity,
+    ready: bool,
+}
+
+impl Default for UiTestHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UiTestHarness {
+    pub const WINDOW_SIZE: Vec2 = Vec2::new(800., 600.);
+    pub const FRAME_DURATION: Duration = Duration::from_nanos(16_666_667);
+    /// Updates run after each simulated input
+    pub const SETTLE_FRAMES: usize = 2;
+    /// Number of steps a `drag` moves the cursor over
+    pub const DRAG_STEPS: usize = 4;
+
+    pub fn new() -> Self {
+        Self::with_window_size(Self::WINDOW_SIZE)
+    }
+
+    /// Creates the harness with a virtual window of `size` logical pixels, at a scale factor of 1
+    pub fn with_window_size(size: Vec2) -> Self {
+        let mut app = App::new();
+        app.add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: Some(Window {
+                        resolution: WindowResolution::new(size.x, size.y)
+                            .with_scale_factor_override(1.),
+                        ..default()
+                    }),
+                    exit_condition: ExitCondition::DontExit,
+                    close_when_requested: false,
+                })
+                .set(RenderPlugin {
+                    render_creation: WgpuSettings {
+                        backends: None,
+                        ..default()
+                    }
+                    .into(),
+                    ..default()
+                })
+                .disable::<WinitPlugin>()
+                .disable::<AudioPlugin>()
+                .disable::<LogPlugin>(),
+        )
+        .add_plugins(SickleUiPlugin)
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Self::FRAME_DURATION));
+
+        app.world.spawn(Camera2dBundle::default());
+        let window = app
+            .world
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(&app.world);
+
+        Self {
+            app,
+            window,
+            ready: false,
+        }
+    }
+
+    /// The app under test. Plugins can be added until the first update.
+    pub fn app(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    pub fn world(&self) -> &World {
+        &self.app.world
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.app.world
+    }
+
+    /// The entity of the virtual window
+    pub fn window(&self) -> Entity {
+        self.window
+    }
+
+    /// Spawns UI from the root, then updates the app so the new nodes are laid out
+    pub fn spawn_ui<T>(&mut self, spawn: impl FnOnce(&mut UiBuilder<UiRoot>) -> T) -> T {
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &self.app.world);
+        let result = spawn(&mut commands.ui_builder(UiRoot));
+        queue.apply(&mut self.app.world);
+
+        self.update();
+        result
+    }
+
+    /// Spawns `bundle` as a root node styled by `style_builder`, then updates the app once
+    pub fn spawn_styled(&mut self, bundle: impl Bundle, style_builder: StyleBuilder) -> Entity {
+        self.spawn_ui(|ui| ui.spawn((bundle, DynamicStyle::from(style_builder))).id())
+    }
+
+    /// Records every `E` sent from the next update on, see [`UiTestHarness::collected_events`]
+    pub fn collect_events<E: Event + Clone>(&mut self) -> &mut Self {
+        self.app
+            .insert_resource(CollectedEvents::<E>(Vec::new()))
+            .add_systems(Last, collect_events::<E>);
+
+        self
+    }
+
+    /// Events recorded since [`UiTestHarness::collect_events`] was called, oldest first
+    pub fn collected_events<E: Event>(&self) -> &[E] {
+        &self
+            .app
+            .world
+            .get_resource::<CollectedEvents<E>>()
+            .expect("events are not collected, call `collect_events` first")
+            .0
+    }
+
+    /// Runs a single frame
+    pub fn update(&mut self) {
+        if !self.ready {
+            self.app.finish();
+            self.app.cleanup();
+            self.ready = true;
+        }
+
+        self.app.update();
+    }
+
+    pub fn run_frames(&mut self, frames: usize) {
+        for _ in 0..frames {
+            self.update();
+        }
+    }
+
+    fn settle(&mut self) {
+        self.run_frames(Self::SETTLE_FRAMES);
+    }
+
+    /// Logical rect of the node, as of the last layout
+    pub fn rect(&self, entity: Entity) -> Rect {
+        let node = self
+            .app
+            .world
+            .get::<Node>(entity)
+            .expect("entity has no Node");
+        let transform = self
+            .app
+            .world
+            .get::<GlobalTransform>(entity)
+            .expect("entity has no GlobalTransform");
+
+        node.logical_rect(transform)
+    }
+
+    /// The first entity with a [`Name`] equal to `name`
+    pub fn find_named(&mut self, name: &str) -> Option<Entity> {
+        self.app
+            .world
+            .query::<(Entity, &Name)>()
+            .iter(&self.app.world)
+            .find(|(_, entity_name)| entity_name.as_str() == name)
+            .map(|(entity, _)| entity)
+    }
+
+    pub fn center(&self, entity: Entity) -> Vec2 {
+        self.rect(entity).center()
+    }
+
+    /// Text snapshot of the node and its descendants, see [`snapshot::ui_snapshot`]
+    pub fn snapshot(&self, entity: Entity) -> String {
+        snapshot::ui_snapshot(entity, &self.app.world)
+    }
+
+    /// Moves the cursor to `position` in logical pixels, or out of the window with `None`
+    pub fn move_cursor(&mut self, position: impl Into<Option<Vec2>>) {
+        let position = position.into();
+        let mut window = self
+            .app
+            .world
+            .get_mut::<Window>(self.window)
+            .expect("virtual window missing");
+        window.set_cursor_position(position);
+
+        match position {
+            Some(position) => {
+                self.app.world.send_event(CursorMoved {
+                    window: self.window,
+                    position,
+                    delta: None,
+                });
+            }
+            None => {
+                self.app.world.send_event(CursorLeft {
+                    window: self.window,
+                });
+            }
+        }
+
+        self.settle();
+    }
+
+    /// Moves the cursor over the center of the node
+    pub fn hover(&mut self, entity: Entity) {
+        self.move_cursor(self.center(entity));
+    }
+
+    pub fn press_mouse(&mut self, button: MouseButton) {
+        self.send_mouse_button(button, ButtonState::Pressed);
+    }
+
+    pub fn release_mouse(&mut self, button: MouseButton) {
+        self.send_mouse_button(button, ButtonState::Released);
+    }
+
+    /// Hovers the node, then presses and releases the left mouse button over it
+    pub fn click(&mut self, entity: Entity) {
+        self.click_at(self.center(entity));
+    }
+
+    pub fn click_at(&mut self, position: Vec2) {
+        self.move_cursor(position);
+        self.press_mouse(MouseButton::Left);
+        self.release_mouse(MouseButton::Left);
+    }
+
+    /// Presses the left mouse button at `from`, moves the cursor to `to` over
+    /// [`UiTestHarness::DRAG_STEPS`] frames, then releases the button.
+    pub fn drag(&mut self, from: Vec2, to: Vec2) {
+        self.move_cursor(from);
+        self.press_mouse(MouseButton::Left);
+        for step in 1..=Self::DRAG_STEPS {
+            self.move_cursor(from.lerp(to, step as f32 / Self::DRAG_STEPS as f32));
+        }
+        self.release_mouse(MouseButton::Left);
+    }
+
+    /// Touches the window at `from`, moves the touch to `to` over
+    /// [`UiTestHarness::DRAG_STEPS`] frames, then lifts it. The cursor is moved out of the
+    /// window first, so the touch drives the UI focus.
+    pub fn touch_drag(&mut self, from: Vec2, to: Vec2) {
+        self.move_cursor(None);
+        self.send_touch(TouchPhase::Started, from);
+        for step in 1..=Self::DRAG_STEPS {
+            self.send_touch(
+                TouchPhase::Moved,
+                from.lerp(to, step as f32 / Self::DRAG_STEPS as f32),
+            );
+        }
+        self.send_touch(TouchPhase::Ended, to);
+    }
+
+    /// Presses all `keys` in the same frame, then releases them in the next one
+    pub fn press_keys(&mut self, keys: impl IntoIterator<Item = KeyCode>) {
+        let keys: Vec<KeyCode> = keys.into_iter().collect();
+        self.send_keys(&keys, ButtonState::Pressed);
+        self.send_keys(&keys, ButtonState::Released);
+    }
+
+    /// Sends the characters of `text` as received characters, as a keyboard layout would
+    pub fn type_text(&mut self, text: &str) {
+        for character in text.chars() {
+            self.app.world.send_event(ReceivedCharacter {
+                window: self.window,
+                char: character.to_string().into(),
+            });
+        }
+
+        self.settle();
+    }
+
+    /// Sends a mouse wheel event of `delta` pixels. Like a wheel, positive `y` scrolls up.
+    pub fn scroll(&mut self, delta: Vec2) {
+        self.app.world.send_event(MouseWheel {
+            unit: MouseScrollUnit::Pixel,
+            x: delta.x,
+            y: delta.y,
+            window: self.window,
+        });
+
+        self.settle();
+    }
+
+    fn send_touch(&mut self, phase: TouchPhase, position: Vec2) {
+        self.app.world.send_event(TouchInput {
+            phase,
+            position,
+            window: self.window,
+            force: None,
+            id: 0,
+        });
+
+        self.settle();
+    }
+
+    fn send_mouse_button(&mut self, button: MouseButton, state: ButtonState) {
+        self.app.world.send_event(MouseButtonInput {
+            button,
+            state,
+            window: self.window,
+        });
+
+        self.settle();
+    }
+
+    fn send_keys(&mut self, keys: &[KeyCode], state: ButtonState) {
+        for key_code in keys.iter().copied() {
+            self.app.world.send_event(KeyboardInput {
+                key_code,
+                logical_key: Key::Unidentified(NativeKey::Unidentified),
+                state,
+                window: self.window,
+            });
+        }
+
+        self.settle();
+    }
+}
+
+#[derive(Resource)]
+struct CollectedEvents<E: Event>(Vec<E>);
+
+fn collect_events<E: Event + Clone>(
+    mut events: EventReader<E>,
+    mut collected: ResMut<CollectedEvents<E>>,
+) {
+    collected.0.extend(events.read().cloned());
+}
@@ -0,0 +1,411 @@
+//! Text snapshots of the UI tree.
+//!
+//! [`ui_snapshot`] serializes a node and its descendants into a stable, indented text format:
+//! names, logical rects, `PseudoStates`, text content and the style values that differ from
+//! their defaults. [`assert_ui_snapshot!`](crate::assert_ui_snapshot) compares it against a
+//! checked-in `.snap` file, so theme and layout changes show up as line diffs in review.
+//!
+//! Snapshots are written (or overwritten) when the `SICKLE_UI_UPDATE_SNAPSHOTS` environment
+//! variable is set, e.g. `SICKLE_UI_UPDATE_SNAPSHOTS=1 cargo test`.
+//!
+//! ```text
+//! - Checkbox [Check]
+//!   rect: (4, 0) 392x32
+//!   states: Checked
+//!   height: 32px
+//!   align_items: Center
+//!   margin: 0px 4px 0px 4px
+//!   - Label
+//!     rect: (30, 9) 31x14
+//!     align_self: Center
+//!     text: "Check"
+//!     font: 14px #f0dfd7ff
+//! ```
+
+use std::{fs, path::Path};
+
+use bevy::prelude::*;
+
+use sickle_ui_scaffold::prelude::PseudoStates;
+
+/// Environment variable that accepts new snapshots instead of comparing against them
+pub const UPDATE_SNAPSHOTS_VAR: &str = "SICKLE_UI_UPDATE_SNAPSHOTS";
+
+/// Lines of unchanged context shown around differences
+const DIFF_CONTEXT: usize = 3;
+
+/// Asserts that the snapshot matches `tests/snapshots/<name>.snap` of the calling crate.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use sickle_ui::{assert_ui_snapshot, test_support::UiTestHarness};
+/// # let harness = UiTestHarness::new();
+/// # let root = Entity::PLACEHOLDER;
+/// assert_ui_snapshot!("checkbox_checked", harness.snapshot(root));
+/// ```
+#[macro_export]
+macro_rules! assert_ui_snapshot {
+    ($name:expr, $snapshot:expr) => {
+        $crate::test_support::snapshot::assert_snapshot(
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("snapshots"),
+            $name,
+            &$snapshot,
+        )
+    };
+}
+
+/// Compares `actual` against `<dir>/<name>.snap`, panicking with a line diff when they differ.
+/// Writes the snapshot instead if [`UPDATE_SNAPSHOTS_VAR`] is set.
+#[track_caller]
+pub fn assert_snapshot(dir: impl AsRef<Path>, name: &str, actual: &str) {
+    let path = dir.as_ref().join(format!("{}.snap", name));
+    let actual = normalize(actual);
+
+    if std::env::var_os(UPDATE_SNAPSHOTS_VAR).is_some() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("failed to create snapshot directory");
+        }
+        fs::write(&path, &actual).expect("failed to write snapshot");
+        return;
+    }
+
+    let Ok(expected) = fs::read_to_string(&path) else {
+        panic!(
+            "Snapshot {} does not exist. Rerun with {}=1 to create it:\n{}",
+            path.display(),
+            UPDATE_SNAPSHOTS_VAR,
+            actual
+        );
+    };
+
+    let expected = normalize(&expected);
+    if expected != actual {
+        panic!(
+            "Snapshot {} does not match. Rerun with {}=1 to accept the changes:\n{}",
+            path.display(),
+            UPDATE_SNAPSHOTS_VAR,
+            diff(&expected, &actual)
+        );
+    }
+}
+
+/// Serializes `entity` and its UI descendants. Children without a `Node` are skipped.
+pub fn ui_snapshot(entity: Entity, world: &World) -> String {
+    let mut lines = Vec::new();
+    push_node(entity, 0, world, &mut lines);
+
+    normalize(&lines.join("\n"))
+}
+
+fn push_node(entity: Entity, depth: usize, world: &World, lines: &mut Vec<String>) {
+    let indent = "  ".repeat(depth);
+    let name = match world.get::<Name>(entity) {
+        Some(name) => name.to_string(),
+        None => "Node".into(),
+    };
+    lines.push(format!("{}- {}", indent, name));
+
+    let mut property = |key: &str, value: String| {
+        lines.push(format!("{}  {}: {}", indent, key, value));
+    };
+
+    if let (Some(node), Some(transform)) = (
+        world.get::<Node>(entity),
+        world.get::<GlobalTransform>(entity),
+    ) {
+        let rect = node.logical_rect(transform);
+        property(
+            "rect",
+            format!(
+                "({}, {}) {}x{}",
+                number(rect.min.x),
+                number(rect.min.y),
+                number(rect.width()),
+                number(rect.height())
+            ),
+        );
+    }
+
+    if let Some(pseudo_states) = world.get::<PseudoStates>(entity) {
+        if !pseudo_states.is_empty() {
+            let mut states: Vec<String> = pseudo_states
+                .get()
+                .iter()
+                .map(|state| format!("{:?}", state))
+                .collect();
+            states.sort();
+            property("states", states.join(", "));
+        }
+    }
+
+    if let Some(visibility) = world.get::<Visibility>(entity) {
+        if *visibility != Visibility::Inherited {
+            property("visibility", format!("{:?}", visibility));
+        }
+    }
+
+    if let Some(style) = world.get::<Style>(entity) {
+        for (key, value) in style_values(style) {
+            property(key, value);
+        }
+    }
+
+    if let Some(background) = world.get::<BackgroundColor>(entity) {
+        if !background.0.is_fully_transparent() {
+            property("background_color", color(background.0));
+        }
+    }
+
+    let has_border = world
+        .get::<Style>(entity)
+        .is_some_and(|style| style.border != UiRect::all(Val::Px(0.)));
+    if let Some(border) = world.get::<BorderColor>(entity) {
+        if has_border && !border.0.is_fully_transparent() {
+            property("border_color", color(border.0));
+        }
+    }
+
+    if let Some(text) = world.get::<Text>(entity) {
+        let value: String = text
+            .sections
+            .iter()
+            .map(|section| section.value.as_str())
+            .collect();
+        property("text", format!("{:?}", value));
+
+        if let Some(section) = text.sections.first() {
+            property(
+                "font",
+                format!(
+                    "{}px {}",
+                    number(section.style.font_size),
+                    color(section.style.color)
+                ),
+            );
+        }
+    }
+
+    let Some(children) = world.get::<Children>(entity) else {
+        return;
+    };
+
+    for child in children.iter() {
+        if world.get::<Node>(*child).is_some() {
+            push_node(*child, depth + 1, world, lines);
+        }
+    }
+}
+
+/// Style values that differ from `Style::default()`
+fn style_values(style: &Style) -> Vec<(&'static str, String)> {
+    let default = Style::default();
+    let values = [
+        (
+            "display",
+            format!("{:?}", style.display),
+            format!("{:?}", default.display),
+        ),
+        (
+            "position_type",
+            format!("{:?}", style.position_type),
+            format!("{:?}", default.position_type),
+        ),
+        (
+            "overflow",
+            format!("{:?} {:?}", style.overflow.x, style.overflow.y),
+            format!("{:?} {:?}", default.overflow.x, default.overflow.y),
+        ),
+        ("left", val(style.left), val(default.left)),
+        ("right", val(style.right), val(default.right)),
+        ("top", val(style.top), val(default.top)),
+        ("bottom", val(style.bottom), val(default.bottom)),
+        ("width", val(style.width), val(default.width)),
+        ("height", val(style.height), val(default.height)),
+        ("min_width", val(style.min_width), val(default.min_width)),
+        ("min_height", val(style.min_height), val(default.min_height)),
+        ("max_width", val(style.max_width), val(default.max_width)),
+        ("max_height", val(style.max_height), val(default.max_height)),
+        (
+            "aspect_ratio",
+            format!("{:?}", style.aspect_ratio),
+            format!("{:?}", default.aspect_ratio),
+        ),
+        (
+            "align_items",
+            format!("{:?}", style.align_items),
+            format!("{:?}", default.align_items),
+        ),
+        (
+            "justify_items",
+            format!("{:?}", style.justify_items),
+            format!("{:?}", default.justify_items),
+        ),
+        (
+            "align_self",
+            format!("{:?}", style.align_self),
+            format!("{:?}", default.align_self),
+        ),
+        (
+            "justify_self",
+            format!("{:?}", style.justify_self),
+            format!("{:?}", default.justify_self),
+        ),
+        (
+            "align_content",
+            format!("{:?}", style.align_content),
+            format!("{:?}", default.align_content),
+        ),
+        (
+            "justify_content",
+            format!("{:?}", style.justify_content),
+            format!("{:?}", default.justify_content),
+        ),
+        ("margin", ui_rect(style.margin), ui_rect(default.margin)),
+        ("padding", ui_rect(style.padding), ui_rect(default.padding)),
+        ("border", ui_rect(style.border), ui_rect(default.border)),
+        (
+            "flex_direction",
+            format!("{:?}", style.flex_direction),
+            format!("{:?}", default.flex_direction),
+        ),
+        (
+            "flex_wrap",
+            format!("{:?}", style.flex_wrap),
+            format!("{:?}", default.flex_wrap),
+        ),
+        (
+            "flex_grow",
+            number(style.flex_grow),
+            number(default.flex_grow),
+        ),
+        (
+            "flex_shrink",
+            number(style.flex_shrink),
+            number(default.flex_shrink),
+        ),
+        ("flex_basis", val(style.flex_basis), val(default.flex_basis)),
+        ("row_gap", val(style.row_gap), val(default.row_gap)),
+        ("column_gap", val(style.column_gap), val(default.column_gap)),
+    ];
+
+    values
+        .into_iter()
+        .filter(|(_, value, default)| value != default)
+        .map(|(key, value, _)| (key, value))
+        .collect()
+}
+
+fn number(value: f32) -> String {
+    let rounded = (value * 100.).round() / 100.;
+    // Avoid "-0" from rounding tiny negative values
+    match rounded == 0. {
+        true => "0".into(),
+        false => format!("{}", rounded),
+    }
+}
+
+fn val(value: Val) -> String {
+    match value {
+        Val::Auto => "auto".into(),
+        Val::Px(value) => format!("{}px", number(value)),
+        Val::Percent(value) => format!("{}%", number(value)),
+        Val::Vw(value) => format!("{}vw", number(value)),
+        Val::Vh(value) => format!("{}vh", number(value)),
+        Val::VMin(value) => format!("{}vmin", number(value)),
+        Val::VMax(value) => format!("{}vmax", number(value)),
+    }
+}
+
+/// CSS-like shorthand: one value when all sides match, otherwise top, right, bottom, left
+fn ui_rect(rect: UiRect) -> String {
+    if rect.top == rect.right && rect.top == rect.bottom && rect.top == rect.left {
+        return val(rect.top);
+    }
+
+    format!(
+        "{} {} {} {}",
+        val(rect.top),
+        val(rect.right),
+        val(rect.bottom),
+        val(rect.left)
+    )
+}
+
+fn color(color: Color) -> String {
+    let [r, g, b, a] = color.as_rgba_u8();
+    format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+}
+
+fn normalize(text: &str) -> String {
+    let mut normalized: String = text
+        .lines()
+        .map(|line| line.trim_end())
+        .collect::<Vec<&str>>()
+        .join("\n");
+    normalized.push('\n');
+
+    normalized
+}
+
+/// Line diff of `expected` to `actual`, with removed lines prefixed by `-`, added lines by `+`.
+/// Unchanged lines further than [`DIFF_CONTEXT`] from a change are elided.
+fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    // Longest common subsequence lengths of the suffixes
+    let mut lengths = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lengths[i][j] = match expected[i] == actual[j] {
+                true => lengths[i + 1][j + 1] + 1,
+                false => lengths[i + 1][j].max(lengths[i][j + 1]),
+            };
+        }
+    }
+
+    let mut lines: Vec<(char, &str)> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            lines.push((' ', expected[i]));
+            i += 1;
+            j += 1;
+        } else if i < expected.len()
+            && (j == actual.len() || lengths[i + 1][j] >= lengths[i][j + 1])
+        {
+            lines.push(('-', expected[i]));
+            i += 1;
+        } else {
+            lines.push(('+', actual[j]));
+            j += 1;
+        }
+    }
+
+    let changed: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, (marker, _))| *marker != ' ')
+        .map(|(index, _)| index)
+        .collect();
+
+    let mut output = Vec::new();
+    let mut elided = false;
+    for (index, (marker, line)) in lines.iter().enumerate() {
+        let near_change = changed
+            .iter()
+            .any(|changed| index.abs_diff(*changed) <= DIFF_CONTEXT);
+
+        if near_change {
+            output.push(format!("{} {}", marker, line));
+            elided = false;
+        } else if !elided {
+            output.push("  ...".into());
+            elided = true;
+        }
+    }
+
+    output.join("\n")
+}
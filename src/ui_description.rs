@@ -0,0 +1,639 @@
+//! Declarative UI trees loaded from `.ui.ron` assets.
+//!
+//! A description is a tree of [`UiNodeDescription`]s. Each node names a widget with its config,
+//! an optional `Name` and a list of [`UiStyleOverride`]s. It is spawned through the same
+//! `Ui*Ext` builder traits used in code, so the widgets are themed and behave the same way.
+//!
+//! ```ron
+//! (
+//!     name: "Settings",
+//!     widget: Column,
+//!     style: [Width(Px(300.)), RowGap(Px(4.))],
+//!     children: [
+//!         (widget: Label(text: "Audio")),
+//!         (name: "Volume", widget: Slider(label: Some("Volume"), max: 100., value: 50.)),
+//!         (name: "Mute", widget: Checkbox(label: Some("Mute"))),
+//!         (widget: TabContainer, children: [
+//!             (widget: Tab(title: "General"), children: [
+//!                 (widget: Label(text: "General settings")),
+//!             ]),
+//!         ]),
+//!     ],
+//! )
+//! ```
+//!
+//! Fields with defaults can be left out. Style values use the RON form of their Bevy type, for
+//! example `Margin((left: Px(4.), right: Px(4.), top: Px(0.), bottom: Px(0.)))`.
+//!
+//! Spawn it with [`UiDescriptionExt::ui_description`]. The spawned tree is rebuilt whenever the
+//! asset changes, so with Bevy's `file_watcher` feature the file can be edited while the app
+//! runs. Look up spawned widgets by their `Name` after [`UiDescriptionSpawned`] is sent.
+
+use std::{any::TypeId, fmt, marker::PhantomData};
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+    prelude::*,
+    reflect::{serde::TypedReflectDeserializer, TypeRegistry, TypeRegistryArc},
+    utils::{BoxedFuture, HashSet},
+};
+use serde::{
+    de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+
+use sickle_ui_scaffold::{prelude::*, scroll_interaction::ScrollAxis};
+
+use crate::widgets::{
+    inputs::{
+        checkbox::UiCheckboxExt,
+        dropdown::UiDropdownExt,
+        radio_group::UiRadioGroupExt,
+        slider::{SliderAxis, SliderConfig, UiSliderExt},
+        text_input::UiTextInputExt,
+    },
+    layout::{
+        column::UiColumnExt,
+        container::UiContainerExt,
+        foldable::UiFoldableExt,
+        icon::UiIconExt,
+        label::{LabelConfig, UiLabelExt},
+        row::UiRowExt,
+        scroll_view::UiScrollViewExt,
+        tab_container::{UiTabContainerExt, UiTabContainerSubExt},
+    },
+};
+
+pub struct UiDescriptionPlugin;
+
+impl Plugin for UiDescriptionPlugin {
+    fn build(&self, app: &mut App) {
+        // Field types of the widget configs are not registered automatically
+        app.register_type::<Vec<String>>()
+            .register_type::<Option<String>>()
+            .register_type::<Option<usize>>()
+            .register_type::<Option<ScrollAxis>>()
+            .register_type::<SliderAxis>()
+            .register_type::<UiWidgetDescription>()
+            .register_type::<UiStyleOverride>()
+            .register_type::<UiDescriptionInstance>()
+            .init_asset::<UiDescription>()
+            .init_asset_loader::<UiDescriptionLoader>()
+            .add_event::<UiDescriptionSpawned>()
+            .add_systems(Update, spawn_ui_descriptions.in_set(UiDescriptionUpdate));
+    }
+}
+
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct UiDescriptionUpdate;
+
+fn spawn_ui_descriptions(
+    mut asset_events: EventReader<AssetEvent<UiDescription>>,
+    q_instances: Query<(Entity, Ref<UiDescriptionInstance>)>,
+    r_descriptions: Res<Assets<UiDescription>>,
+    mut spawned: EventWriter<UiDescriptionSpawned>,
+    mut commands: Commands,
+) {
+    let updated: HashSet<AssetId<UiDescription>> = asset_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    for (entity, instance) in &q_instances {
+        if !instance.is_changed() && !updated.contains(&instance.handle.id()) {
+            continue;
+        }
+
+        let Some(description) = r_descriptions.get(&instance.handle) else {
+            continue;
+        };
+
+        commands.entity(entity).despawn_descendants();
+        description.root.spawn(&mut commands.ui_builder(entity));
+
+        spawned.send(UiDescriptionSpawned { entity });
+    }
+}
+
+/// Sent when the tree of a [`UiDescriptionInstance`] is (re)spawned
+#[derive(Event, Clone, Copy, Debug)]
+pub struct UiDescriptionSpawned {
+    pub entity: Entity,
+}
+
+/// Holds the spawned tree of a [`UiDescription`]. Its children are despawned and
+/// rebuilt when the asset is loaded or modified.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct UiDescriptionInstance {
+    pub handle: Handle<UiDescription>,
+}
+
+/// A UI tree loaded from a `.ui.ron` file
+#[derive(Asset, TypePath, Clone, Debug)]
+pub struct UiDescription {
+    pub root: UiNodeDescription,
+}
+
+impl UiDescription {
+    /// Parses the RON of a description. Widgets and style values are deserialized by reflection,
+    /// so they use the RON representation of the Bevy types (`Px(10.)`, `Column`, ...).
+    /// Requires [`UiDescriptionPlugin`] to register them.
+    pub fn from_ron(ron: &[u8], registry: &TypeRegistry) -> Result<Self, UiDescriptionError> {
+        let mut deserializer = ron::de::Deserializer::from_bytes(ron)?;
+        let root = UiNodeDescriptionSeed { registry }
+            .deserialize(&mut deserializer)
+            .map_err(|error| deserializer.span_error(error))?;
+        deserializer
+            .end()
+            .map_err(|error| deserializer.span_error(error))?;
+
+        Ok(Self { root })
+    }
+}
+
+#[derive(Debug)]
+pub enum UiDescriptionError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+}
+
+impl fmt::Display for UiDescriptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UiDescriptionError::Io(error) => write!(f, "Failed to read UI description: {}", error),
+            UiDescriptionError::Ron(error) => {
+                write!(f, "Failed to parse UI description: {}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for UiDescriptionError {}
+
+impl From<std::io::Error> for UiDescriptionError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<ron::error::SpannedError> for UiDescriptionError {
+    fn from(value: ron::error::SpannedError) -> Self {
+        Self::Ron(value)
+    }
+}
+
+pub struct UiDescriptionLoader {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for UiDescriptionLoader {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        Self {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+impl AssetLoader for UiDescriptionLoader {
+    type Asset = UiDescription;
+    type Settings = ();
+    type Error = UiDescriptionError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<UiDescription, UiDescriptionError>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+
+            UiDescription::from_ron(&bytes, &self.type_registry.read())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ui.ron"]
+    }
+}
+
+/// A node of a [`UiDescription`]. `children` are spawned into container widgets and
+/// ignored by the others. The children of a `TabContainer` must be `Tab` nodes.
+#[derive(Clone, Debug, Default)]
+pub struct UiNodeDescription {
+    /// Replaces the `Name` the widget is spawned with
+    pub name: Option<String>,
+    pub widget: UiWidgetDescription,
+    /// Applied in order after the widget is spawned, like `.style()` calls in code
+    pub style: Vec<UiStyleOverride>,
+    pub children: Vec<UiNodeDescription>,
+}
+
+impl UiNodeDescription {
+    fn spawn(&self, builder: &mut UiBuilder<Entity>) {
+        let children = &self.children;
+        let spawn_children = |builder: &mut UiBuilder<Entity>| {
+            for child in children.iter() {
+                child.spawn(builder);
+            }
+        };
+
+        let mut widget = match &self.widget {
+            UiWidgetDescription::Container => {
+                builder.container(NodeBundle::default(), spawn_children)
+            }
+            UiWidgetDescription::Row => builder.row(spawn_children),
+            UiWidgetDescription::Column => builder.column(spawn_children),
+            UiWidgetDescription::Label { text } => builder.label(LabelConfig {
+                label: text.clone(),
+                ..default()
+            }),
+            UiWidgetDescription::Icon { path } => builder.icon(path.clone()),
+            UiWidgetDescription::Checkbox { label, checked } => {
+                builder.checkbox(label.clone(), *checked)
+            }
+            UiWidgetDescription::Slider {
+                label,
+                min,
+                max,
+                value,
+                show_current,
+                axis,
+            } => builder.slider(SliderConfig::new(
+                label.clone(),
+                *min,
+                *max,
+                *value,
+                *show_current,
+                *axis,
+            )),
+            UiWidgetDescription::Dropdown { options, value } => {
+                builder.dropdown(options.clone(), *value)
+            }
+            UiWidgetDescription::RadioGroup {
+                options,
+                value,
+                unselectable,
+            } => builder.radio_group(options.clone(), *value, *unselectable),
+            UiWidgetDescription::TextInput { value, placeholder } => {
+                builder.text_input(value.clone(), placeholder.clone())
+            }
+            UiWidgetDescription::ScrollView { restrict_to } => {
+                builder.scroll_view(*restrict_to, spawn_children)
+            }
+            UiWidgetDescription::Foldable { label, open } => {
+                builder.foldable(label.clone(), *open, children.is_empty(), spawn_children)
+            }
+            UiWidgetDescription::TabContainer => builder.tab_container(|tab_container| {
+                for tab in children.iter() {
+                    let UiWidgetDescription::Tab { title } = &tab.widget else {
+                        warn!(
+                            "Skipped {:?} in TabContainer: only Tab nodes can be added as tabs",
+                            tab.widget
+                        );
+                        continue;
+                    };
+
+                    // Name and style of a tab apply to its panel
+                    tab_container.add_tab(title.clone(), |panel| {
+                        tab.apply_overrides(panel);
+                        for child in tab.children.iter() {
+                            child.spawn(panel);
+                        }
+                    });
+                }
+            }),
+            UiWidgetDescription::Tab { title } => {
+                warn!(
+                    "Skipped tab {}: tabs must be children of a TabContainer",
+                    title
+                );
+                return;
+            }
+        };
+
+        self.apply_overrides(&mut widget);
+    }
+
+    fn apply_overrides(&self, widget: &mut UiBuilder<Entity>) {
+        if let Some(name) = &self.name {
+            widget.insert(Name::new(name.clone()));
+        }
+
+        for style_override in self.style.iter() {
+            StaticStyleAttribute::from(style_override.clone()).apply(&mut widget.style());
+        }
+    }
+}
+
+/// Deserializes a [`UiNodeDescription`]. The tree itself is plain serde, while widgets and
+/// style overrides are deserialized by reflection, so that Bevy types can be used in them.
+#[derive(Clone, Copy)]
+struct UiNodeDescriptionSeed<'a> {
+    registry: &'a TypeRegistry,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum UiNodeDescriptionField {
+    Name,
+    Widget,
+    Style,
+    Children,
+}
+
+impl UiNodeDescriptionSeed<'_> {
+    const FIELDS: &'static [&'static str] = &["name", "widget", "style", "children"];
+}
+
+impl<'de> DeserializeSeed<'de> for UiNodeDescriptionSeed<'_> {
+    type Value = UiNodeDescription;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_struct("UiNodeDescription", Self::FIELDS, self)
+    }
+}
+
+impl<'de> Visitor<'de> for UiNodeDescriptionSeed<'_> {
+    type Value = UiNodeDescription;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a UI node with a widget, and an optional name, style and children")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut node = UiNodeDescription::default();
+        while let Some(field) = map.next_key::<UiNodeDescriptionField>()? {
+            match field {
+                UiNodeDescriptionField::Name => node.name = Some(map.next_value()?),
+                UiNodeDescriptionField::Widget => {
+                    node.widget = map.next_value_seed(ReflectedSeed::new(self.registry))?
+                }
+                UiNodeDescriptionField::Style => {
+                    node.style = map.next_value_seed(ListSeed(ReflectedSeed::new(self.registry)))?
+                }
+                UiNodeDescriptionField::Children => {
+                    node.children = map.next_value_seed(ListSeed(self))?
+                }
+            }
+        }
+
+        Ok(node)
+    }
+}
+
+struct ReflectedSeed<'a, T> {
+    registry: &'a TypeRegistry,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> ReflectedSeed<'a, T> {
+    fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for ReflectedSeed<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ReflectedSeed<'_, T> {}
+
+impl<'de, T: FromReflect + TypePath> DeserializeSeed<'de> for ReflectedSeed<'_, T> {
+    type Value = T;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let Some(registration) = self.registry.get(TypeId::of::<T>()) else {
+            return Err(de::Error::custom(format_args!(
+                "{} is not registered",
+                T::type_path()
+            )));
+        };
+
+        let reflected =
+            TypedReflectDeserializer::new(registration, self.registry).deserialize(deserializer)?;
+
+        T::from_reflect(reflected.as_ref()).ok_or_else(|| {
+            de::Error::custom(format_args!(
+                "missing or invalid fields in {}",
+                T::short_type_path()
+            ))
+        })
+    }
+}
+
+struct ListSeed<S>(S);
+
+impl<'de, S: DeserializeSeed<'de> + Copy> DeserializeSeed<'de> for ListSeed<S> {
+    type Value = Vec<S::Value>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de> + Copy> Visitor<'de> for ListSeed<S> {
+    type Value = Vec<S::Value>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a list")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element_seed(self.0)? {
+            values.push(value);
+        }
+
+        Ok(values)
+    }
+}
+
+/// A widget and its config, mirroring the arguments of its `Ui*Ext` builder function
+#[derive(Clone, Debug, Default, Reflect)]
+pub enum UiWidgetDescription {
+    /// A plain node
+    #[default]
+    Container,
+    Row,
+    Column,
+    Label {
+        #[reflect(default)]
+        text: String,
+    },
+    Icon {
+        path: String,
+    },
+    Checkbox {
+        #[reflect(default)]
+        label: Option<String>,
+        #[reflect(default)]
+        checked: bool,
+    },
+    Slider {
+        #[reflect(default)]
+        label: Option<String>,
+        #[reflect(default)]
+        min: f32,
+        #[reflect(default = "one")]
+        max: f32,
+        #[reflect(default)]
+        value: f32,
+        #[reflect(default)]
+        show_current: bool,
+        #[reflect(default)]
+        axis: SliderAxis,
+    },
+    Dropdown {
+        options: Vec<String>,
+        #[reflect(default)]
+        value: Option<usize>,
+    },
+    RadioGroup {
+        options: Vec<String>,
+        #[reflect(default)]
+        value: Option<usize>,
+        #[reflect(default)]
+        unselectable: bool,
+    },
+    TextInput {
+        #[reflect(default)]
+        value: String,
+        #[reflect(default)]
+        placeholder: String,
+    },
+    ScrollView {
+        #[reflect(default)]
+        restrict_to: Option<ScrollAxis>,
+    },
+    Foldable {
+        label: String,
+        #[reflect(default)]
+        open: bool,
+    },
+    TabContainer,
+    /// A tab of the parent `TabContainer`, its children are spawned into the tab panel
+    Tab {
+        title: String,
+    },
+}
+
+fn one() -> f32 {
+    1.
+}
+
+/// Style values that can be set from a description. Each maps to the
+/// [`StaticStyleAttribute`] of the same name.
+#[derive(Clone, Debug, Reflect)]
+pub enum UiStyleOverride {
+    Display(Display),
+    PositionType(PositionType),
+    Overflow(Overflow),
+    Left(Val),
+    Right(Val),
+    Top(Val),
+    Bottom(Val),
+    Width(Val),
+    Height(Val),
+    MinWidth(Val),
+    MinHeight(Val),
+    MaxWidth(Val),
+    MaxHeight(Val),
+    AlignItems(AlignItems),
+    AlignSelf(AlignSelf),
+    AlignContent(AlignContent),
+    JustifyContent(JustifyContent),
+    Margin(UiRect),
+    Padding(UiRect),
+    Border(UiRect),
+    FlexDirection(FlexDirection),
+    FlexWrap(FlexWrap),
+    FlexGrow(f32),
+    FlexShrink(f32),
+    FlexBasis(Val),
+    RowGap(Val),
+    ColumnGap(Val),
+    BackgroundColor(Color),
+    BorderColor(Color),
+    Visibility(Visibility),
+    FontSize(f32),
+    FontColor(Color),
+}
+
+impl From<UiStyleOverride> for StaticStyleAttribute {
+    fn from(value: UiStyleOverride) -> Self {
+        match value {
+            UiStyleOverride::Display(value) => StaticStyleAttribute::Display(value),
+            UiStyleOverride::PositionType(value) => StaticStyleAttribute::PositionType(value),
+            UiStyleOverride::Overflow(value) => StaticStyleAttribute::Overflow(value),
+            UiStyleOverride::Left(value) => StaticStyleAttribute::Left(value),
+            UiStyleOverride::Right(value) => StaticStyleAttribute::Right(value),
+            UiStyleOverride::Top(value) => StaticStyleAttribute::Top(value),
+            UiStyleOverride::Bottom(value) => StaticStyleAttribute::Bottom(value),
+            UiStyleOverride::Width(value) => StaticStyleAttribute::Width(value),
+            UiStyleOverride::Height(value) => StaticStyleAttribute::Height(value),
+            UiStyleOverride::MinWidth(value) => StaticStyleAttribute::MinWidth(value),
+            UiStyleOverride::MinHeight(value) => StaticStyleAttribute::MinHeight(value),
+            UiStyleOverride::MaxWidth(value) => StaticStyleAttribute::MaxWidth(value),
+            UiStyleOverride::MaxHeight(value) => StaticStyleAttribute::MaxHeight(value),
+            UiStyleOverride::AlignItems(value) => StaticStyleAttribute::AlignItems(value),
+            UiStyleOverride::AlignSelf(value) => StaticStyleAttribute::AlignSelf(value),
+            UiStyleOverride::AlignContent(value) => StaticStyleAttribute::AlignContent(value),
+            UiStyleOverride::JustifyContent(value) => StaticStyleAttribute::JustifyContent(value),
+            UiStyleOverride::Margin(value) => StaticStyleAttribute::Margin(value),
+            UiStyleOverride::Padding(value) => StaticStyleAttribute::Padding(value),
+            UiStyleOverride::Border(value) => StaticStyleAttribute::Border(value),
+            UiStyleOverride::FlexDirection(value) => StaticStyleAttribute::FlexDirection(value),
+            UiStyleOverride::FlexWrap(value) => StaticStyleAttribute::FlexWrap(value),
+            UiStyleOverride::FlexGrow(value) => StaticStyleAttribute::FlexGrow(value),
+            UiStyleOverride::FlexShrink(value) => StaticStyleAttribute::FlexShrink(value),
+            UiStyleOverride::FlexBasis(value) => StaticStyleAttribute::FlexBasis(value),
+            UiStyleOverride::RowGap(value) => StaticStyleAttribute::RowGap(value),
+            UiStyleOverride::ColumnGap(value) => StaticStyleAttribute::ColumnGap(value),
+            UiStyleOverride::BackgroundColor(value) => StaticStyleAttribute::BackgroundColor(value),
+            UiStyleOverride::BorderColor(value) => StaticStyleAttribute::BorderColor(value),
+            UiStyleOverride::Visibility(value) => StaticStyleAttribute::Visibility(value),
+            UiStyleOverride::FontSize(value) => StaticStyleAttribute::FontSize(value),
+            UiStyleOverride::FontColor(value) => StaticStyleAttribute::FontColor(value),
+        }
+    }
+}
+
+pub trait UiDescriptionExt {
+    /// Spawns a node that holds the tree described by the asset. The tree is spawned once the
+    /// asset is loaded, and rebuilt when it is modified.
+    fn ui_description(&mut self, handle: Handle<UiDescription>) -> UiBuilder<'_, Entity>;
+}
+
+impl UiDescriptionExt for UiBuilder<'_, Entity> {
+    fn ui_description(&mut self, handle: Handle<UiDescription>) -> UiBuilder<'_, Entity> {
+        self.spawn(UiDescriptionInstance::frame(handle))
+    }
+}
+
+impl UiDescriptionExt for UiBuilder<'_, UiRoot> {
+    fn ui_description(&mut self, handle: Handle<UiDescription>) -> UiBuilder<'_, Entity> {
+        self.spawn(UiDescriptionInstance::frame(handle))
+    }
+}
+
+impl UiDescriptionInstance {
+    fn frame(handle: Handle<UiDescription>) -> impl Bundle {
+        (
+            Name::new("UI Description"),
+            NodeBundle::default(),
+            UiDescriptionInstance { handle },
+        )
+    }
+}
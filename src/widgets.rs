@@ -1,16 +1,24 @@
+pub mod binding;
 pub mod inputs;
 pub mod layout;
 pub mod menus;
+pub mod widget_builder;
+
+use std::marker::PhantomData;
 
 use bevy::prelude::*;
 
+use sickle_ui_scaffold::prelude::*;
+
 use self::{
     inputs::checkbox::CheckboxPlugin,
     inputs::dropdown::DropdownPlugin,
     inputs::radio_group::RadioGroupPlugin,
     inputs::slider::SliderPlugin,
+    inputs::text_input::TextInputPlugin,
     layout::docking_zone::DockingZonePlugin,
     layout::floating_panel::{FloatingPanelPlugin, FloatingPanelUpdate},
+    layout::floating_panel_manager::FloatingPanelManagerPlugin,
     layout::foldable::FoldablePlugin,
     layout::resize_handles::ResizeHandlePlugin,
     layout::scroll_view::ScrollViewPlugin,
@@ -28,15 +36,25 @@ use self::{
 
 pub mod prelude {
     pub use super::{
-        inputs::checkbox::{Checkbox, UiCheckboxExt},
-        inputs::dropdown::{Dropdown, UiDropdownExt},
-        inputs::radio_group::{RadioGroup, UiRadioGroupExt},
-        inputs::slider::{Slider, SliderConfig, UiSliderExt},
+        binding::{
+            BindableWidget, BindingSource, FieldAccessor, UiWidgetBindingExt, WidgetBinding,
+            WidgetBindingPlugin, WidgetBindingUpdate,
+        },
+        inputs::checkbox::{Checkbox, CheckboxChanged, UiCheckboxExt},
+        inputs::dropdown::{Dropdown, DropdownChanged, UiDropdownExt},
+        inputs::radio_group::{RadioGroup, RadioGroupChanged, UiRadioGroupExt},
+        inputs::slider::{Slider, SliderChanged, SliderConfig, UiSliderExt},
+        inputs::text_input::{TextInput, TextInputChanged, UiTextInputExt},
         layout::column::UiColumnExt,
         layout::container::UiContainerExt,
         layout::docking_zone::UiDockingZoneExt,
         layout::floating_panel::{
-            FloatingPanelConfig, FloatingPanelLayout, FloatingPanelUpdate, UiFloatingPanelExt,
+            FloatingPanelConfig, FloatingPanelLayout, FloatingPanelSnapping, FloatingPanelUpdate,
+            UiFloatingPanelExt,
+        },
+        layout::floating_panel_manager::{
+            FloatingPanelEntry, FloatingPanelManager, FloatingPanelVisibility,
+            UiFloatingPanelTrayExt,
         },
         layout::foldable::{Foldable, FoldableUpdate, UiFoldableExt},
         layout::icon::UiIconExt,
@@ -44,7 +62,10 @@ pub mod prelude {
         layout::panel::UiPanelExt,
         layout::resize_handles::{ResizeHandle, ResizeHandles, UiResizeHandlesExt},
         layout::row::UiRowExt,
-        layout::scroll_view::{ScrollViewLayoutUpdate, UiScrollViewExt},
+        layout::scroll_view::{
+            ScrollIntoViewExt, ScrollView, ScrollViewLayoutUpdate, ScrollViewOffsetChanged,
+            UiScrollViewExt,
+        },
         layout::sized_zone::{SizedZoneConfig, SizedZonePreUpdate, UiSizedZoneExt},
         layout::tab_container::{TabContainerUpdate, UiTabContainerExt, UiTabContainerSubExt},
         menus::context_menu::{
@@ -53,14 +74,18 @@ pub mod prelude {
         menus::extra_menu::{ExtraMenu, UiExtraMenuExt},
         menus::menu::{MenuConfig, MenuUpdate, UiMenuExt, UiMenuSubExt},
         menus::menu_bar::{UiMenuBarExt, UiMenuBarSubExt},
-        menus::menu_item::{MenuItem, MenuItemConfig, MenuItemUpdate, UiMenuItemExt},
+        menus::menu_item::{
+            MenuItem, MenuItemConfig, MenuItemPressed, MenuItemUpdate, UiMenuItemExt,
+        },
         menus::menu_separators::{UiMenuItemSeparatorExt, UiMenuSeparatorExt},
         menus::shortcut::{Shortcut, ShortcutPreUpdate},
         menus::submenu::{SubmenuConfig, SubmenuUpdate, UiSubmenuExt, UiSubmenuSubExt},
         menus::toggle_menu_item::{
-            ToggleMenuItemConfig, ToggleMenuItemUpdate, UiToggleMenuItemExt,
+            ToggleMenuItemChanged, ToggleMenuItemConfig, ToggleMenuItemUpdate, UiToggleMenuItemExt,
         },
-        WidgetLibraryUpdate,
+        widget_builder::{UiWidgetBuilderExt, WidgetBuilder, WidgetPlugin},
+        UiWidgetEventExt, ValueChangeSource, WidgetEvent, WidgetEventCallbackUpdate,
+        WidgetEventPlugin, WidgetLibraryUpdate,
     };
 
     // Used with scroll views, floating panels, etc. often
@@ -79,6 +104,7 @@ impl Plugin for WidgetsPlugin {
                 DockingZonePlugin,
                 DropdownPlugin,
                 FloatingPanelPlugin,
+                FloatingPanelManagerPlugin,
                 FoldablePlugin,
                 MenuPlugin,
             ))
@@ -93,6 +119,7 @@ impl Plugin for WidgetsPlugin {
                 ScrollViewPlugin,
                 SubmenuPlugin,
                 TabContainerPlugin,
+                TextInputPlugin,
                 ToggleMenuItemPlugin,
             ));
     }
@@ -100,3 +127,95 @@ impl Plugin for WidgetsPlugin {
 
 #[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
 pub struct WidgetLibraryUpdate;
+
+/// Whether a widget's value was changed through user interaction or by code
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum ValueChangeSource {
+    User,
+    #[default]
+    Code,
+}
+
+/// An event emitted by a widget entity. Callbacks for these can be registered with
+/// [`UiWidgetEventExt::on_event`].
+pub trait WidgetEvent: Event + Clone {
+    fn entity(&self) -> Entity;
+}
+
+/// Registers the widget event and runs the callbacks registered for it
+pub struct WidgetEventPlugin<E: WidgetEvent>(PhantomData<E>);
+
+impl<E: WidgetEvent> Default for WidgetEventPlugin<E> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<E: WidgetEvent> Plugin for WidgetEventPlugin<E> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<E>().add_systems(
+            PostUpdate,
+            run_widget_event_callbacks::<E>
+                .in_set(WidgetEventCallbackUpdate)
+                .before(ThemeUpdate),
+        );
+    }
+}
+
+/// Widget event callbacks run in `PostUpdate`, after all widget events of the frame are sent
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct WidgetEventCallbackUpdate;
+
+fn run_widget_event_callbacks<E: WidgetEvent>(
+    mut events: EventReader<E>,
+    q_callbacks: Query<&WidgetEventCallbacks<E>>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        let Ok(callbacks) = q_callbacks.get(event.entity()) else {
+            continue;
+        };
+
+        for callback in &callbacks.0 {
+            callback(event, &mut commands);
+        }
+    }
+}
+
+type WidgetEventCallback<E> = Box<dyn Fn(&E, &mut Commands) + Send + Sync>;
+
+#[derive(Component)]
+pub struct WidgetEventCallbacks<E: WidgetEvent>(Vec<WidgetEventCallback<E>>);
+
+pub trait UiWidgetEventExt {
+    /// Calls `callback` each time the widget emits the event `E`.
+    /// The event type is inferred from the callback, i.e.:
+    /// `.on_event(|changed: &SliderChanged, commands| { .. })`
+    fn on_event<E: WidgetEvent>(
+        &mut self,
+        callback: impl Fn(&E, &mut Commands) + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+
+impl UiWidgetEventExt for UiBuilder<'_, Entity> {
+    fn on_event<E: WidgetEvent>(
+        &mut self,
+        callback: impl Fn(&E, &mut Commands) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.entity_commands()
+            .add(move |entity: Entity, world: &mut World| {
+                let Some(mut entity) = world.get_entity_mut(entity) else {
+                    return;
+                };
+
+                let callback: WidgetEventCallback<E> = Box::new(callback);
+                if let Some(mut callbacks) = entity.get_mut::<WidgetEventCallbacks<E>>() {
+                    callbacks.0.push(callback);
+                } else {
+                    entity.insert(WidgetEventCallbacks(vec![callback]));
+                }
+            });
+
+        self
+    }
+}
@@ -0,0 +1,412 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use bevy::{ecs::event::ManualEventReader, prelude::*, utils::HashMap};
+
+use sickle_ui_scaffold::prelude::*;
+
+use super::WidgetEvent;
+
+/// Keeps a widget and its [`WidgetBinding`] source in sync
+pub struct WidgetBindingPlugin<W: BindableWidget>(PhantomData<W>);
+
+impl<W: BindableWidget> Default for WidgetBindingPlugin<W> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<W: BindableWidget> Plugin for WidgetBindingPlugin<W> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            update_widget_bindings::<W>.in_set(WidgetBindingUpdate),
+        );
+    }
+}
+
+/// Bound sources are read (and user edits written back) before `Update`, so widgets reflect
+/// changes made by game logic in the previous frame and game logic sees the latest edits.
+#[derive(SystemSet, Clone, Eq, Debug, Hash, PartialEq)]
+pub struct WidgetBindingUpdate;
+
+/// A widget that has a single value that can be bound to a component or resource field
+pub trait BindableWidget: Component {
+    type Value: Reflect + FromReflect + Clone + PartialEq;
+    /// The value-changed event of the widget. Only user edits are written back to the source.
+    type Changed: WidgetEvent;
+
+    fn bound_value(&self) -> Self::Value;
+    fn set_bound_value(&mut self, value: Self::Value);
+
+    /// The new value of the widget if the event was caused by user interaction
+    fn user_edit(event: &Self::Changed) -> Option<Self::Value>;
+}
+
+fn update_widget_bindings<W: BindableWidget>(
+    world: &mut World,
+    mut change_reader: Local<ManualEventReader<W::Changed>>,
+) {
+    let mut user_edits: HashMap<Entity, W::Value> = HashMap::new();
+    if let Some(events) = world.get_resource::<Events<W::Changed>>() {
+        for event in change_reader.read(events) {
+            if let Some(value) = W::user_edit(event) {
+                user_edits.insert(event.entity(), value);
+            }
+        }
+    }
+
+    let mut q_bindings = world.query_filtered::<Entity, (With<W>, With<WidgetBinding>)>();
+    let entities: Vec<Entity> = q_bindings.iter(world).collect();
+
+    for entity in entities {
+        // Safe unwrap: query filtered above
+        let binding = world.get::<WidgetBinding>(entity).unwrap();
+        let source = binding.source.clone();
+        let last_source = binding
+            .last_source
+            .as_ref()
+            .map(|value| value.clone_value());
+        let user_edit = user_edits.remove(&entity).filter(|value| {
+            binding.last_synced.as_ref().map_or(true, |last| {
+                !value.reflect_partial_eq(last.as_ref()).unwrap_or(false)
+            })
+        });
+
+        let Some(source_value) = source.read(world) else {
+            continue;
+        };
+
+        let source_changed = match &last_source {
+            Some(last) => !source_value
+                .reflect_partial_eq(last.as_ref())
+                .unwrap_or(false),
+            None => true,
+        };
+
+        let (last_source, last_synced): (Box<dyn Reflect>, Box<dyn Reflect>) = if source_changed {
+            let Some(value) = W::Value::from_reflect(source_value.as_ref()) else {
+                warn!(
+                    "Binding of {:?} cannot convert {} into the value of {}",
+                    entity,
+                    source_value.reflect_type_path(),
+                    std::any::type_name::<W>(),
+                );
+                continue;
+            };
+
+            // Safe unwrap: query filtered above
+            let mut widget = world.get_mut::<W>(entity).unwrap();
+            if widget.bound_value() != value {
+                widget.set_bound_value(value);
+            }
+
+            // The widget may adjust the value (i.e. clamp it to its range), the adjusted value
+            // is not written back unless the user edits the widget.
+            (source_value, Box::new(widget.bound_value()))
+        } else if let Some(value) = user_edit {
+            if !source.write(world, &value) {
+                warn!(
+                    "Binding of {:?} cannot write {} into its source",
+                    entity,
+                    std::any::type_name::<W::Value>(),
+                );
+            }
+
+            (Box::new(value.clone()), Box::new(value))
+        } else {
+            continue;
+        };
+
+        if let Some(mut binding) = world.get_mut::<WidgetBinding>(entity) {
+            let binding = binding.bypass_change_detection();
+            binding.last_source = Some(last_source);
+            binding.last_synced = Some(last_synced);
+        }
+    }
+}
+
+/// Reads and writes the value a widget is bound to
+pub trait BindingSource: Send + Sync + 'static {
+    fn read(&self, world: &mut World) -> Option<Box<dyn Reflect>>;
+
+    /// Returns `false` if the value could not be written
+    fn write(&self, world: &mut World, value: &dyn Reflect) -> bool;
+}
+
+/// Two-way binding between the widget on the entity and a field of a component or resource.
+///
+/// Changes of the source are applied to the widget, user edits of the widget are written back to
+/// the source. If both change in the same frame, the source wins.
+#[derive(Component)]
+pub struct WidgetBinding {
+    source: Arc<dyn BindingSource>,
+    last_source: Option<Box<dyn Reflect>>,
+    last_synced: Option<Box<dyn Reflect>>,
+}
+
+impl WidgetBinding {
+    pub fn new(source: impl BindingSource) -> Self {
+        Self {
+            source: Arc::new(source),
+            last_source: None,
+            last_synced: None,
+        }
+    }
+}
+
+/// A closure selecting the bound field of a component or resource, i.e. `|c| &mut c.speed`.
+/// Implemented for every matching closure, the field type is inferred from its return type.
+pub trait FieldAccessor<T>:
+    Fn(&mut T) -> &mut <Self as FieldAccessor<T>>::Value + Send + Sync + 'static
+{
+    type Value: Reflect + FromReflect + Clone + PartialEq;
+}
+
+impl<T, V, F> FieldAccessor<T> for F
+where
+    V: Reflect + FromReflect + Clone + PartialEq,
+    F: Fn(&mut T) -> &mut V + Send + Sync + 'static,
+{
+    type Value = V;
+}
+
+struct ComponentBinding<C, F> {
+    entity: Entity,
+    accessor: F,
+    _marker: PhantomData<fn() -> C>,
+}
+
+impl<C, F> BindingSource for ComponentBinding<C, F>
+where
+    C: Component,
+    F: FieldAccessor<C>,
+{
+    fn read(&self, world: &mut World) -> Option<Box<dyn Reflect>> {
+        let mut component = world.get_mut::<C>(self.entity)?;
+        let value = (self.accessor)(component.bypass_change_detection()).clone();
+
+        Some(Box::new(value))
+    }
+
+    fn write(&self, world: &mut World, value: &dyn Reflect) -> bool {
+        let Some(value) = F::Value::from_reflect(value) else {
+            return false;
+        };
+        let Some(mut component) = world.get_mut::<C>(self.entity) else {
+            return false;
+        };
+
+        let field = (self.accessor)(component.bypass_change_detection());
+        if *field != value {
+            *field = value;
+            component.set_changed();
+        }
+
+        true
+    }
+}
+
+struct ResourceBinding<R, F> {
+    accessor: F,
+    _marker: PhantomData<fn() -> R>,
+}
+
+impl<R, F> BindingSource for ResourceBinding<R, F>
+where
+    R: Resource,
+    F: FieldAccessor<R>,
+{
+    fn read(&self, world: &mut World) -> Option<Box<dyn Reflect>> {
+        let mut resource = world.get_resource_mut::<R>()?;
+        let value = (self.accessor)(resource.bypass_change_detection()).clone();
+
+        Some(Box::new(value))
+    }
+
+    fn write(&self, world: &mut World, value: &dyn Reflect) -> bool {
+        let Some(value) = F::Value::from_reflect(value) else {
+            return false;
+        };
+        let Some(mut resource) = world.get_resource_mut::<R>() else {
+            return false;
+        };
+
+        let field = (self.accessor)(resource.bypass_change_detection());
+        if *field != value {
+            *field = value;
+            resource.set_changed();
+        }
+
+        true
+    }
+}
+
+/// Binds to a reflected field by path, i.e. `"Transform.translation.x"`.
+/// The first segment is the (short or full) type path of the component or resource.
+struct ReflectPathBinding {
+    entity: Option<Entity>,
+    path: String,
+}
+
+impl ReflectPathBinding {
+    fn split_path(&self) -> Option<(&str, &str)> {
+        let (type_path, field_path) = self.path.split_once('.')?;
+        Some((type_path, field_path))
+    }
+
+    fn registration_data<T: Clone + bevy::reflect::TypeData>(&self, world: &World) -> Option<T> {
+        let (type_path, _) = self.split_path()?;
+        let registry = world.get_resource::<AppTypeRegistry>()?.read();
+        let registration = registry
+            .get_with_short_type_path(type_path)
+            .or_else(|| registry.get_with_type_path(type_path))?;
+
+        registration.data::<T>().cloned()
+    }
+
+    fn write_field(target: &mut dyn Reflect, field_path: &str, value: &dyn Reflect) -> bool {
+        let Ok(field) = target.reflect_path_mut(field_path) else {
+            return false;
+        };
+
+        if field.reflect_type_path() != value.reflect_type_path() {
+            return false;
+        }
+
+        if !field.reflect_partial_eq(value).unwrap_or(false) {
+            field.apply(value);
+        }
+
+        true
+    }
+}
+
+impl BindingSource for ReflectPathBinding {
+    fn read(&self, world: &mut World) -> Option<Box<dyn Reflect>> {
+        let (_, field_path) = self.split_path()?;
+
+        let target = match self.entity {
+            Some(entity) => {
+                let reflect_component = self.registration_data::<ReflectComponent>(world)?;
+                reflect_component.reflect(world.get_entity(entity)?)?
+            }
+            None => {
+                let reflect_resource = self.registration_data::<ReflectResource>(world)?;
+                reflect_resource.reflect(world)?
+            }
+        };
+
+        let field = target.reflect_path(field_path).ok()?;
+        Some(field.clone_value())
+    }
+
+    fn write(&self, world: &mut World, value: &dyn Reflect) -> bool {
+        let Some((_, field_path)) = self.split_path() else {
+            return false;
+        };
+
+        match self.entity {
+            Some(entity) => {
+                let Some(reflect_component) = self.registration_data::<ReflectComponent>(world)
+                else {
+                    return false;
+                };
+                let Some(mut entity) = world.get_entity_mut(entity) else {
+                    return false;
+                };
+                let Some(mut target) = reflect_component.reflect_mut(&mut entity) else {
+                    return false;
+                };
+
+                let written = ReflectPathBinding::write_field(
+                    target.bypass_change_detection(),
+                    field_path,
+                    value,
+                );
+                if written {
+                    target.set_changed();
+                }
+
+                written
+            }
+            None => {
+                let Some(reflect_resource) = self.registration_data::<ReflectResource>(world)
+                else {
+                    return false;
+                };
+                let Some(mut target) = reflect_resource.reflect_mut(world) else {
+                    return false;
+                };
+
+                let written = ReflectPathBinding::write_field(
+                    target.bypass_change_detection(),
+                    field_path,
+                    value,
+                );
+                if written {
+                    target.set_changed();
+                }
+
+                written
+            }
+        }
+    }
+}
+
+pub trait UiWidgetBindingExt {
+    /// Binds the widget's value to a field of a component on `entity`, i.e.
+    /// `.bind_to::<Movement>(player, |movement| &mut movement.speed)`.
+    /// The field type must match the widget's value type.
+    fn bind_to<C: Component>(
+        &mut self,
+        entity: Entity,
+        accessor: impl FieldAccessor<C>,
+    ) -> &mut Self;
+
+    /// Binds the widget's value to a field of a resource, i.e.
+    /// `.bind_to_resource::<GameSettings>(|settings| &mut settings.volume)`.
+    fn bind_to_resource<R: Resource>(&mut self, accessor: impl FieldAccessor<R>) -> &mut Self;
+
+    /// Binds the widget's value to a reflected component field of `entity`,
+    /// i.e. `"Transform.translation.x"`. The component must be registered and reflect `Component`.
+    fn bind_to_path(&mut self, entity: Entity, path: impl Into<String>) -> &mut Self;
+
+    /// Binds the widget's value to a reflected resource field, i.e. `"GameSettings.volume"`.
+    /// The resource must be registered and reflect `Resource`.
+    fn bind_to_resource_path(&mut self, path: impl Into<String>) -> &mut Self;
+}
+
+impl UiWidgetBindingExt for UiBuilder<'_, Entity> {
+    fn bind_to<C: Component>(
+        &mut self,
+        entity: Entity,
+        accessor: impl FieldAccessor<C>,
+    ) -> &mut Self {
+        self.insert(WidgetBinding::new(ComponentBinding {
+            entity,
+            accessor,
+            _marker: PhantomData,
+        }))
+    }
+
+    fn bind_to_resource<R: Resource>(&mut self, accessor: impl FieldAccessor<R>) -> &mut Self {
+        self.insert(WidgetBinding::new(ResourceBinding {
+            accessor,
+            _marker: PhantomData,
+        }))
+    }
+
+    fn bind_to_path(&mut self, entity: Entity, path: impl Into<String>) -> &mut Self {
+        self.insert(WidgetBinding::new(ReflectPathBinding {
+            entity: entity.into(),
+            path: path.into(),
+        }))
+    }
+
+    fn bind_to_resource_path(&mut self, path: impl Into<String>) -> &mut Self {
+        self.insert(WidgetBinding::new(ReflectPathBinding {
+            entity: None,
+            path: path.into(),
+        }))
+    }
+}
@@ -2,3 +2,4 @@ pub mod checkbox;
 pub mod dropdown;
 pub mod radio_group;
 pub mod slider;
+pub mod text_input;
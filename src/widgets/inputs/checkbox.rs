@@ -1,27 +1,80 @@
-use bevy::{prelude::*, ui::FocusPolicy};
+use bevy::{
+    a11y::accesskit::{Checked, NodeBuilder, Role},
+    prelude::*,
+    ui::FocusPolicy,
+};
 
-use sickle_ui_scaffold::prelude::*;
+use sickle_macros::UiContext;
+use sickle_ui_scaffold::{
+    prelude::*,
+    ui_commands::{AccessibilityNodeOutdated, SetAccessibilityNodeExt},
+};
 
-use crate::widgets::layout::{
-    container::UiContainerExt,
-    label::{LabelConfig, UiLabelExt},
+use crate::{
+    localization::LocalizedText,
+    widgets::{
+        binding::{BindableWidget, WidgetBindingPlugin},
+        layout::{
+            container::UiContainerExt,
+            label::{LabelConfig, UiLabelExt},
+        },
+        ValueChangeSource, WidgetEvent, WidgetEventPlugin,
+    },
 };
 
 pub struct CheckboxPlugin;
 
 impl Plugin for CheckboxPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(ComponentThemePlugin::<Checkbox>::default())
-            .add_systems(Update, (toggle_checkbox, update_checkbox).chain());
+        app.add_plugins((
+            ComponentThemePlugin::<Checkbox>::default(),
+            WidgetEventPlugin::<CheckboxChanged>::default(),
+            WidgetBindingPlugin::<Checkbox>::default(),
+        ))
+        .add_systems(
+            Update,
+            (
+                toggle_checkbox,
+                report_checkbox_change,
+                update_checkbox,
+                update_checkbox_accessibility,
+            )
+                .chain(),
+        );
     }
 }
 
 fn toggle_checkbox(
-    mut q_checkboxes: Query<(&mut Checkbox, &FluxInteraction), Changed<FluxInteraction>>,
+    mut q_checkboxes: Query<(Entity, &mut Checkbox, &FluxInteraction), Changed<FluxInteraction>>,
+    mut events: EventWriter<CheckboxChanged>,
 ) {
-    for (mut checkbox, interaction) in &mut q_checkboxes {
+    for (entity, mut checkbox, interaction) in &mut q_checkboxes {
         if *interaction == FluxInteraction::Released {
             checkbox.checked = !checkbox.checked;
+            checkbox.reported_checked = checkbox.checked;
+
+            events.send(CheckboxChanged {
+                entity,
+                value: checkbox.checked,
+                source: ValueChangeSource::User,
+            });
+        }
+    }
+}
+
+fn report_checkbox_change(
+    mut q_checkboxes: Query<(Entity, &mut Checkbox), Changed<Checkbox>>,
+    mut events: EventWriter<CheckboxChanged>,
+) {
+    for (entity, mut checkbox) in &mut q_checkboxes {
+        if checkbox.checked != checkbox.reported_checked {
+            checkbox.bypass_change_detection().reported_checked = checkbox.checked;
+
+            events.send(CheckboxChanged {
+                entity,
+                value: checkbox.checked,
+                source: ValueChangeSource::Code,
+            });
         }
     }
 }
@@ -49,12 +102,47 @@ fn update_checkbox(
     }
 }
 
-#[derive(Component, Clone, Debug, Reflect)]
+fn update_checkbox_accessibility(
+    q_checkboxes: Query<(Entity, &Checkbox), AccessibilityNodeOutdated<Checkbox>>,
+    mut commands: Commands,
+) {
+    for (entity, checkbox) in &q_checkboxes {
+        let mut node = NodeBuilder::new(Role::CheckBox);
+        node.set_checked(match checkbox.checked {
+            true => Checked::True,
+            false => Checked::False,
+        });
+
+        commands
+            .entity(entity)
+            .set_accessibility_node(node, checkbox.label);
+    }
+}
+
+/// Sent when a checkbox is checked or unchecked
+#[derive(Event, Clone, Copy, Debug)]
+pub struct CheckboxChanged {
+    pub entity: Entity,
+    pub value: bool,
+    pub source: ValueChangeSource,
+}
+
+impl WidgetEvent for CheckboxChanged {
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct Checkbox {
     pub checked: bool,
+    reported_checked: bool,
+    #[context("CheckmarkBackground")]
     checkmark_background: Entity,
+    #[context("Checkmark")]
     checkmark: Entity,
+    #[context("Label")]
     label: Entity,
 }
 
@@ -62,6 +150,7 @@ impl Default for Checkbox {
     fn default() -> Self {
         Self {
             checked: false,
+            reported_checked: false,
             checkmark_background: Entity::PLACEHOLDER,
             checkmark: Entity::PLACEHOLDER,
             label: Entity::PLACEHOLDER,
@@ -69,26 +158,20 @@ impl Default for Checkbox {
     }
 }
 
-impl UiContext for Checkbox {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            Checkbox::CHECKMARK_BACKGROUND => Ok(self.checkmark_background),
-            Checkbox::CHECKMARK => Ok(self.checkmark),
-            Checkbox::LABEL => Ok(self.label),
-            _ => Err(format!(
-                "{} doesn't exists for Checkbox. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
+impl BindableWidget for Checkbox {
+    type Value = bool;
+    type Changed = CheckboxChanged;
+
+    fn bound_value(&self) -> bool {
+        self.checked
+    }
+
+    fn set_bound_value(&mut self, value: bool) {
+        self.checked = value;
     }
 
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![
-            Checkbox::CHECKMARK_BACKGROUND,
-            Checkbox::CHECKMARK,
-            Checkbox::LABEL,
-        ]
+    fn user_edit(event: &CheckboxChanged) -> Option<bool> {
+        (event.source == ValueChangeSource::User).then_some(event.value)
     }
 }
 
@@ -99,10 +182,6 @@ impl DefaultTheme for Checkbox {
 }
 
 impl Checkbox {
-    pub const CHECKMARK_BACKGROUND: &'static str = "CheckmarkBackground";
-    pub const CHECKMARK: &'static str = "Checkmark";
-    pub const LABEL: &'static str = "Label";
-
     pub fn theme() -> Theme<Checkbox> {
         let base_theme = PseudoTheme::deferred(None, Checkbox::primary_style);
         let checked_theme =
@@ -252,6 +331,41 @@ impl Checkbox {
 
 pub trait UiCheckboxExt {
     fn checkbox(&mut self, label: impl Into<Option<String>>, checked: bool) -> UiBuilder<Entity>;
+
+    fn localized_checkbox(&mut self, label: LocalizedText, checked: bool) -> UiBuilder<'_, Entity>;
+}
+
+fn spawn_checkbox<'a>(
+    builder: &'a mut UiBuilder<'_, Entity>,
+    label: LabelConfig,
+    checked: bool,
+) -> UiBuilder<'a, Entity> {
+    let mut checkbox = Checkbox {
+        checked,
+        reported_checked: checked,
+        ..default()
+    };
+
+    let has_label = !label.label.is_empty() || label.localized.is_some();
+    let name = match &label.localized {
+        Some(localized) => format!("Checkbox [{}]", localized.id()),
+        None if has_label => format!("Checkbox [{}]", label.label),
+        None => "Checkbox".into(),
+    };
+
+    let mut input = builder.container(Checkbox::checkbox_container(name), |container| {
+        checkbox.checkmark_background = container
+            .container(Checkbox::checkmark_background(), |checkmark_bg| {
+                checkbox.checkmark = checkmark_bg.spawn(Checkbox::checkmark()).id();
+            })
+            .id();
+
+        checkbox.label = container.label(label).style().render(has_label).id();
+    });
+
+    input.insert(checkbox);
+
+    input
 }
 
 impl UiCheckboxExt for UiBuilder<'_, Entity> {
@@ -259,38 +373,26 @@ impl UiCheckboxExt for UiBuilder<'_, Entity> {
     ///
     /// ### PseudoState usage
     /// - `PseudoState::Checked`, when the checkbox is in a checked state
+    ///
+    /// ### Events
+    /// - `CheckboxChanged`, when the checkbox is checked or unchecked
     fn checkbox(&mut self, label: impl Into<Option<String>>, checked: bool) -> UiBuilder<Entity> {
-        let mut checkbox = Checkbox {
-            checked,
-            ..default()
-        };
-
         let label = match label.into() {
             Some(label) => label,
             None => "".into(),
         };
-        let has_label = label.len() > 0;
-        let name = match has_label {
-            true => format!("Checkbox [{}]", label.clone()),
-            false => "Checkbox".into(),
-        };
-
-        let mut input = self.container(Checkbox::checkbox_container(name), |container| {
-            checkbox.checkmark_background = container
-                .container(Checkbox::checkmark_background(), |checkmark_bg| {
-                    checkbox.checkmark = checkmark_bg.spawn(Checkbox::checkmark()).id();
-                })
-                .id();
-
-            checkbox.label = container
-                .label(LabelConfig { label, ..default() })
-                .style()
-                .render(has_label)
-                .id();
-        });
 
-        input.insert(checkbox);
+        spawn_checkbox(self, LabelConfig { label, ..default() }, checked)
+    }
 
-        input
+    /// A checkbox with a label that follows the current locale.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Checked`, when the checkbox is in a checked state
+    ///
+    /// ### Events
+    /// - `CheckboxChanged`, when the checkbox is checked or unchecked
+    fn localized_checkbox(&mut self, label: LocalizedText, checked: bool) -> UiBuilder<'_, Entity> {
+        spawn_checkbox(self, LabelConfig::localized(label), checked)
     }
 }
@@ -1,14 +1,29 @@
 use std::collections::VecDeque;
 
-use bevy::{prelude::*, ui::FocusPolicy};
-
-use sickle_ui_scaffold::prelude::*;
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode,
+    },
+    prelude::*,
+    ui::FocusPolicy,
+};
 
-use crate::widgets::layout::{
-    container::UiContainerExt,
-    label::{LabelConfig, UiLabelExt},
-    panel::UiPanelExt,
-    scroll_view::{ScrollView, ScrollViewLayoutUpdate, UiScrollViewExt},
+use sickle_macros::UiContext;
+use sickle_ui_scaffold::{prelude::*, ui_commands::SetAccessibilityNodeExt};
+
+use crate::{
+    localization::{Locale, LocalizedText},
+    widgets::{
+        binding::{BindableWidget, WidgetBindingPlugin},
+        layout::{
+            container::UiContainerExt,
+            label::{LabelConfig, UiLabelExt},
+            panel::UiPanelExt,
+            scroll_view::{ScrollIntoViewExt, ScrollView, ScrollViewLayoutUpdate, UiScrollViewExt},
+        },
+        ValueChangeSource, WidgetEvent, WidgetEventPlugin,
+    },
 };
 
 const DROPDOWN_PANEL_Z_INDEX: usize = 11000;
@@ -20,15 +35,19 @@ impl Plugin for DropdownPlugin {
         app.add_plugins((
             ComponentThemePlugin::<Dropdown>::default(),
             ComponentThemePlugin::<DropdownOption>::default(),
+            WidgetEventPlugin::<DropdownChanged>::default(),
+            WidgetBindingPlugin::<Dropdown>::default(),
         ))
         .add_systems(
             Update,
             (
                 handle_option_press,
                 update_dropdown_label,
+                report_dropdown_change,
                 handle_click_or_touch.after(FluxInteractionUpdate),
                 update_drowdown_pseudo_state,
                 update_dropdown_panel_visibility.before(ScrollViewLayoutUpdate),
+                update_dropdown_accessibility,
             )
                 .chain(),
         );
@@ -38,6 +57,7 @@ impl Plugin for DropdownPlugin {
 fn update_dropdown_label(
     mut q_dropdowns: Query<(&mut Dropdown, &DropdownOptions), Changed<Dropdown>>,
     mut q_text: Query<&mut Text>,
+    mut commands: Commands,
 ) {
     for (mut dropdown, options) in &mut q_dropdowns {
         let Ok(mut label) = q_text.get_mut(dropdown.label) else {
@@ -50,8 +70,18 @@ fn update_dropdown_label(
             }
         }
 
+        if let Some(localized) = dropdown
+            .value
+            .and_then(|value| options.0[value].localized.clone())
+        {
+            commands.entity(dropdown.label).insert(localized);
+            continue;
+        }
+
+        commands.entity(dropdown.label).remove::<LocalizedText>();
+
         let text = if let Some(value) = dropdown.value {
-            options.0[value].clone()
+            options.0[value].fallback.clone()
         } else {
             String::from("---")
         };
@@ -98,6 +128,7 @@ fn handle_click_or_touch(
 fn handle_option_press(
     q_options: Query<(&DropdownOption, &FluxInteraction), Changed<FluxInteraction>>,
     mut q_dropdown: Query<&mut Dropdown>,
+    mut events: EventWriter<DropdownChanged>,
 ) {
     for (option, interaction) in &q_options {
         if *interaction == FluxInteraction::Released {
@@ -106,6 +137,31 @@ fn handle_option_press(
             };
 
             dropdown.value = option.option.into();
+            if dropdown.value != dropdown.reported_value {
+                dropdown.reported_value = dropdown.value;
+                events.send(DropdownChanged {
+                    entity: option.dropdown,
+                    value: dropdown.value,
+                    source: ValueChangeSource::User,
+                });
+            }
+        }
+    }
+}
+
+fn report_dropdown_change(
+    mut q_dropdowns: Query<(Entity, &mut Dropdown), Changed<Dropdown>>,
+    mut events: EventWriter<DropdownChanged>,
+) {
+    for (entity, mut dropdown) in &mut q_dropdowns {
+        if dropdown.value != dropdown.reported_value {
+            dropdown.bypass_change_detection().reported_value = dropdown.value;
+
+            events.send(DropdownChanged {
+                entity,
+                value: dropdown.value,
+                source: ValueChangeSource::Code,
+            });
         }
     }
 }
@@ -129,6 +185,7 @@ fn update_drowdown_pseudo_state(
 
 fn update_dropdown_panel_visibility(
     q_dropdowns: Query<&Dropdown, Changed<Dropdown>>,
+    q_children: Query<&Children>,
     mut q_scroll_view: Query<&mut ScrollView>,
     mut commands: Commands,
 ) {
@@ -140,6 +197,14 @@ fn update_dropdown_panel_visibility(
                 .visibility(Visibility::Inherited)
                 .height(Val::Px(0.));
 
+            if let (Some(value), Ok(options)) =
+                (dropdown.value, q_children.get(dropdown.scroll_view_content))
+            {
+                if let Some(option) = options.get(value) {
+                    commands.entity(*option).scroll_into_view();
+                }
+            }
+
             let Ok(mut scroll_view) = q_scroll_view.get_mut(dropdown.scroll_view) else {
                 continue;
             };
@@ -154,6 +219,36 @@ fn update_dropdown_panel_visibility(
     }
 }
 
+fn update_dropdown_accessibility(
+    r_locale: Res<Locale>,
+    q_dropdowns: Query<(
+        Entity,
+        Ref<Dropdown>,
+        &DropdownOptions,
+        Ref<AccessibilityNode>,
+    )>,
+    mut commands: Commands,
+) {
+    for (entity, dropdown, options, node) in &q_dropdowns {
+        // The selected option is reported in the current locale
+        if !dropdown.is_changed() && !node.is_added() && !r_locale.is_changed() {
+            continue;
+        }
+
+        let mut node = NodeBuilder::new(Role::ComboBox);
+        node.set_expanded(dropdown.is_open);
+        if let Some(option) = dropdown.value.and_then(|value| options.0.get(value)) {
+            let value = match &option.localized {
+                Some(localized) => r_locale.format(localized),
+                None => option.fallback.clone(),
+            };
+            node.set_value(value);
+        }
+
+        commands.entity(entity).set_accessibility_node(node, None);
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum DropdownPanelAnchor {
     TopLeft,
@@ -176,12 +271,38 @@ pub struct DropdownPanelPlacement {
 
 #[derive(Component, Clone, Debug, Default, Reflect)]
 #[reflect(Component)]
-pub struct DropdownOptions(Vec<String>);
+pub struct DropdownOptions(Vec<DropdownOptionText>);
+
+/// The text of a dropdown option, translated by the current locale if it is localized
+#[derive(Clone, Debug, Default, Reflect)]
+pub struct DropdownOptionText {
+    pub fallback: String,
+    pub localized: Option<LocalizedText>,
+}
+
+impl From<String> for DropdownOptionText {
+    fn from(value: String) -> Self {
+        Self {
+            fallback: value,
+            localized: None,
+        }
+    }
+}
+
+impl From<LocalizedText> for DropdownOptionText {
+    fn from(value: LocalizedText) -> Self {
+        Self {
+            fallback: value.fallback(),
+            localized: Some(value),
+        }
+    }
+}
 
-#[derive(Component, Clone, Debug, Reflect)]
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct DropdownOption {
     dropdown: Entity,
+    #[context("Label")]
     label: Entity,
     option: usize,
 }
@@ -196,23 +317,6 @@ impl Default for DropdownOption {
     }
 }
 
-impl UiContext for DropdownOption {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            DropdownOption::LABEL => Ok(self.label),
-            _ => Err(format!(
-                "{} doesn't exists for DropdownOption. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
-    }
-
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![DropdownOption::LABEL]
-    }
-}
-
 impl DefaultTheme for DropdownOption {
     fn default_theme() -> Option<Theme<DropdownOption>> {
         DropdownOption::theme().into()
@@ -220,8 +324,6 @@ impl DefaultTheme for DropdownOption {
 }
 
 impl DropdownOption {
-    pub const LABEL: &'static str = "Label";
-
     pub fn theme() -> Theme<DropdownOption> {
         let base_theme = PseudoTheme::deferred(None, DropdownOption::primary_style);
 
@@ -272,14 +374,34 @@ impl Default for DropdownPanel {
     }
 }
 
-#[derive(Component, Clone, Debug, Reflect)]
+/// Sent when the selected option of a dropdown changes
+#[derive(Event, Clone, Copy, Debug)]
+pub struct DropdownChanged {
+    pub entity: Entity,
+    pub value: Option<usize>,
+    pub source: ValueChangeSource,
+}
+
+impl WidgetEvent for DropdownChanged {
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct Dropdown {
     value: Option<usize>,
+    reported_value: Option<usize>,
+    #[context("Label")]
     label: Entity,
+    #[context("Icon")]
     icon: Entity,
+    #[context("Panel")]
     panel: Entity,
+    #[context("ScrollView")]
     scroll_view: Entity,
+    #[context("ScrollViewContent")]
     scroll_view_content: Entity,
     is_open: bool,
 }
@@ -288,6 +410,7 @@ impl Default for Dropdown {
     fn default() -> Self {
         Self {
             value: Default::default(),
+            reported_value: Default::default(),
             label: Entity::PLACEHOLDER,
             icon: Entity::PLACEHOLDER,
             panel: Entity::PLACEHOLDER,
@@ -298,30 +421,20 @@ impl Default for Dropdown {
     }
 }
 
-impl UiContext for Dropdown {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            Dropdown::LABEL => Ok(self.label),
-            Dropdown::ICON => Ok(self.icon),
-            Dropdown::PANEL => Ok(self.panel),
-            Dropdown::SCROLL_VIEW => Ok(self.scroll_view),
-            Dropdown::SCROLL_VIEW_CONTENT => Ok(self.scroll_view_content),
-            _ => Err(format!(
-                "{} doesn't exists for Dropdown. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
+impl BindableWidget for Dropdown {
+    type Value = Option<usize>;
+    type Changed = DropdownChanged;
+
+    fn bound_value(&self) -> Option<usize> {
+        self.value
     }
 
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![
-            Dropdown::LABEL,
-            Dropdown::ICON,
-            Dropdown::PANEL,
-            Dropdown::SCROLL_VIEW,
-            Dropdown::SCROLL_VIEW_CONTENT,
-        ]
+    fn set_bound_value(&mut self, value: Option<usize>) {
+        self.set_value(value);
+    }
+
+    fn user_edit(event: &DropdownChanged) -> Option<Option<usize>> {
+        (event.source == ValueChangeSource::User).then_some(event.value)
     }
 }
 
@@ -332,12 +445,6 @@ impl DefaultTheme for Dropdown {
 }
 
 impl Dropdown {
-    pub const LABEL: &'static str = "Label";
-    pub const ICON: &'static str = "Icon";
-    pub const PANEL: &'static str = "Panel";
-    pub const SCROLL_VIEW: &'static str = "ScrollView";
-    pub const SCROLL_VIEW_CONTENT: &'static str = "ScrollViewContent";
-
     pub fn value(&self) -> Option<usize> {
         self.value
     }
@@ -492,7 +599,12 @@ impl Dropdown {
         let (container_size, dropdown_position) = UiUtils::container_size_and_offset(entity, world);
         let tl_corner = dropdown_position - dropdown_size / 2.;
         let halfway_point = container_size / 2.;
-        let anchor = if tl_corner.x > halfway_point.x {
+        // Panels align to the start edge of the dropdown, unless it is past the middle of the container
+        let align_right = match LayoutDirection::of(entity, world).is_right_to_left() {
+            true => tl_corner.x + dropdown_size.x >= halfway_point.x,
+            false => tl_corner.x > halfway_point.x,
+        };
+        let anchor = if align_right {
             if tl_corner.y > halfway_point.y {
                 DropdownPanelAnchor::TopRight
             } else {
@@ -590,7 +702,7 @@ impl Dropdown {
         })
     }
 
-    fn button(options: Vec<String>) -> impl Bundle {
+    fn button(options: Vec<DropdownOptionText>) -> impl Bundle {
         (
             Name::new("Dropdown"),
             ButtonBundle {
@@ -640,91 +752,128 @@ pub trait UiDropdownExt {
         &mut self,
         options: Vec<impl Into<String>>,
         value: impl Into<Option<usize>>,
-    ) -> UiBuilder<Entity>;
+    ) -> UiBuilder<'_, Entity>;
+
+    fn localized_dropdown(
+        &mut self,
+        options: Vec<LocalizedText>,
+        value: impl Into<Option<usize>>,
+    ) -> UiBuilder<'_, Entity>;
+}
+
+fn spawn_dropdown<'a>(
+    builder: &'a mut UiBuilder<'_, Entity>,
+    options: Vec<DropdownOptionText>,
+    value: Option<usize>,
+) -> UiBuilder<'a, Entity> {
+    let mut label_id = Entity::PLACEHOLDER;
+    let mut icon_id = Entity::PLACEHOLDER;
+    let mut panel_id = Entity::PLACEHOLDER;
+    let mut scroll_view_id = Entity::PLACEHOLDER;
+    let mut scroll_view_content_id = Entity::PLACEHOLDER;
+
+    let button = Dropdown::button(options.clone());
+    let mut dropdown = builder.container(button, |builder| {
+        let dropdown_id = builder.id();
+        label_id = builder.label(LabelConfig::default()).id();
+        icon_id = builder.spawn(Dropdown::button_icon()).id();
+        panel_id = builder
+            .panel("Dropdown Options".into(), |container| {
+                scroll_view_id = container
+                    .scroll_view(None, |scroll_view| {
+                        scroll_view_content_id = scroll_view.id();
+
+                        for (index, option_text) in options.into_iter().enumerate() {
+                            let mut label_id = Entity::PLACEHOLDER;
+                            scroll_view.container(Dropdown::option_bundle(index), |option| {
+                                label_id = option
+                                    .label(LabelConfig {
+                                        label: option_text.fallback,
+                                        localized: option_text.localized,
+                                        ..default()
+                                    })
+                                    .id();
+
+                                option.insert(DropdownOption {
+                                    dropdown: dropdown_id,
+                                    option: index,
+                                    label: label_id,
+                                });
+                            });
+                        }
+                    })
+                    .insert(TrackedStyleState::default())
+                    .id();
+            })
+            .insert((
+                DropdownPanel {
+                    dropdown: dropdown_id,
+                },
+                LockedStyleAttributes::from_vec(vec![
+                    LockableStyleAttribute::Visibility,
+                    LockableStyleAttribute::Display,
+                    LockableStyleAttribute::FocusPolicy,
+                ]),
+                PseudoStates::default(),
+                VisibilityToPseudoState,
+            ))
+            .style_unchecked()
+            .focus_policy(bevy::ui::FocusPolicy::Block)
+            .id();
+    });
+
+    dropdown.insert(Dropdown {
+        value,
+        reported_value: value,
+        label: label_id,
+        icon: icon_id,
+        panel: panel_id,
+        scroll_view: scroll_view_id,
+        scroll_view_content: scroll_view_content_id,
+        ..default()
+    });
+
+    dropdown
 }
 
 impl UiDropdownExt for UiBuilder<'_, Entity> {
     /// A simple dropdown with options.
-    /// 
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Open`, when the options panel should be visible
+    ///
+    /// ### Events
+    /// - `DropdownChanged`, when the selected option changes
     fn dropdown(
         &mut self,
         options: Vec<impl Into<String>>,
         value: impl Into<Option<usize>>,
-    ) -> UiBuilder<Entity> {
-        let mut label_id = Entity::PLACEHOLDER;
-        let mut icon_id = Entity::PLACEHOLDER;
-        let mut panel_id = Entity::PLACEHOLDER;
-        let mut scroll_view_id = Entity::PLACEHOLDER;
-        let mut scroll_view_content_id = Entity::PLACEHOLDER;
-
+    ) -> UiBuilder<'_, Entity> {
         let option_count = options.len();
-        let mut string_options: Vec<String> = Vec::with_capacity(option_count);
+        let mut option_texts: Vec<DropdownOptionText> = Vec::with_capacity(option_count);
         let mut queue = VecDeque::from(options);
         for _ in 0..option_count {
             let label: String = queue.pop_front().unwrap().into();
-            string_options.push(label);
+            option_texts.push(label.into());
         }
 
-        let mut dropdown = self.container(Dropdown::button(string_options.clone()), |builder| {
-            let dropdown_id = builder.id();
-            label_id = builder.label(LabelConfig::default()).id();
-            icon_id = builder.spawn(Dropdown::button_icon()).id();
-            panel_id = builder
-                .panel("Dropdown Options".into(), |container| {
-                    scroll_view_id = container
-                        .scroll_view(None, |scroll_view| {
-                            scroll_view_content_id = scroll_view.id();
-
-                            for (index, label) in string_options.iter().enumerate() {
-                                let mut label_id = Entity::PLACEHOLDER;
-                                scroll_view.container(Dropdown::option_bundle(index), |option| {
-                                    label_id = option
-                                        .label(LabelConfig {
-                                            label: label.clone(),
-                                            ..default()
-                                        })
-                                        .id();
-
-                                    option.insert(DropdownOption {
-                                        dropdown: dropdown_id,
-                                        option: index,
-                                        label: label_id,
-                                    });
-                                });
-                            }
-                        })
-                        .insert(TrackedStyleState::default())
-                        .id();
-                })
-                .insert((
-                    DropdownPanel {
-                        dropdown: dropdown_id,
-                    },
-                    LockedStyleAttributes::from_vec(vec![
-                        LockableStyleAttribute::Visibility,
-                        LockableStyleAttribute::Display,
-                        LockableStyleAttribute::FocusPolicy,
-                    ]),
-                    PseudoStates::default(),
-                    VisibilityToPseudoState,
-                ))
-                .style_unchecked()
-                .focus_policy(bevy::ui::FocusPolicy::Block)
-                .id();
-        });
-
-        dropdown.insert(Dropdown {
-            value: value.into(),
-            label: label_id,
-            icon: icon_id,
-            panel: panel_id,
-            scroll_view: scroll_view_id,
-            scroll_view_content: scroll_view_content_id,
-            ..default()
-        });
-
-        dropdown
+        spawn_dropdown(self, option_texts, value.into())
+    }
+
+    /// A dropdown with options that follow the current locale.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Open`, when the options panel should be visible
+    ///
+    /// ### Events
+    /// - `DropdownChanged`, when the selected option changes
+    fn localized_dropdown(
+        &mut self,
+        options: Vec<LocalizedText>,
+        value: impl Into<Option<usize>>,
+    ) -> UiBuilder<'_, Entity> {
+        let option_texts = options.into_iter().map(DropdownOptionText::from).collect();
+
+        spawn_dropdown(self, option_texts, value.into())
     }
 }
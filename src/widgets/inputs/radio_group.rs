@@ -1,11 +1,25 @@
-use bevy::{prelude::*, ui::FocusPolicy};
+use bevy::{
+    a11y::accesskit::{Checked, NodeBuilder, Role},
+    prelude::*,
+    ui::FocusPolicy,
+};
 
 use sickle_macros::UiContext;
-use sickle_ui_scaffold::prelude::*;
+use sickle_ui_scaffold::{
+    prelude::*,
+    ui_commands::{AccessibilityNodeOutdated, SetAccessibilityNodeExt},
+};
 
-use crate::widgets::layout::{
-    container::UiContainerExt,
-    label::{LabelConfig, UiLabelExt},
+use crate::{
+    localization::LocalizedText,
+    widgets::{
+        binding::{BindableWidget, WidgetBindingPlugin},
+        layout::{
+            container::UiContainerExt,
+            label::{LabelConfig, UiLabelExt},
+        },
+        ValueChangeSource, WidgetEvent, WidgetEventPlugin,
+    },
 };
 
 pub struct RadioGroupPlugin;
@@ -15,13 +29,18 @@ impl Plugin for RadioGroupPlugin {
         app.add_plugins((
             ComponentThemePlugin::<RadioGroup>::default(),
             ComponentThemePlugin::<RadioButton>::default(),
+            WidgetEventPlugin::<RadioGroupChanged>::default(),
+            WidgetBindingPlugin::<RadioGroup>::default(),
         ))
         .add_systems(
             Update,
             (
                 toggle_radio_button,
+                report_radio_group_change.after(toggle_radio_button),
                 update_radio_group_buttons,
                 update_radio_button,
+                update_radio_group_accessibility,
+                update_radio_button_accessibility.after(update_radio_button),
             ),
         );
     }
@@ -31,6 +50,7 @@ fn toggle_radio_button(
     mut q_radio_buttons: Query<(&mut RadioButton, &FluxInteraction), Changed<FluxInteraction>>,
     keys: Res<ButtonInput<KeyCode>>,
     mut q_group: Query<&mut RadioGroup>,
+    mut events: EventWriter<RadioGroupChanged>,
 ) {
     for (mut radio_button, interaction) in &mut q_radio_buttons {
         if *interaction == FluxInteraction::Pressed {
@@ -60,6 +80,32 @@ fn toggle_radio_button(
             } else {
                 None
             };
+
+            if radio_group.selected != radio_group.reported_selected {
+                radio_group.reported_selected = radio_group.selected;
+                events.send(RadioGroupChanged {
+                    entity: radio_button.group,
+                    value: radio_group.selected,
+                    source: ValueChangeSource::User,
+                });
+            }
+        }
+    }
+}
+
+fn report_radio_group_change(
+    mut q_groups: Query<(Entity, &mut RadioGroup), Changed<RadioGroup>>,
+    mut events: EventWriter<RadioGroupChanged>,
+) {
+    for (entity, mut radio_group) in &mut q_groups {
+        if radio_group.selected != radio_group.reported_selected {
+            radio_group.bypass_change_detection().reported_selected = radio_group.selected;
+
+            events.send(RadioGroupChanged {
+                entity,
+                value: radio_group.selected,
+                source: ValueChangeSource::Code,
+            });
         }
     }
 }
@@ -105,15 +151,78 @@ fn update_radio_button(
     }
 }
 
+fn update_radio_group_accessibility(
+    q_radio_groups: Query<Entity, Added<RadioGroup>>,
+    mut commands: Commands,
+) {
+    for entity in &q_radio_groups {
+        commands
+            .entity(entity)
+            .set_accessibility_node(NodeBuilder::new(Role::RadioGroup), None);
+    }
+}
+
+fn update_radio_button_accessibility(
+    q_radio_buttons: Query<(Entity, &RadioButton), AccessibilityNodeOutdated<RadioButton>>,
+    mut commands: Commands,
+) {
+    for (entity, radio_button) in &q_radio_buttons {
+        let mut node = NodeBuilder::new(Role::RadioButton);
+        node.set_checked(match radio_button.checked {
+            true => Checked::True,
+            false => Checked::False,
+        });
+
+        commands
+            .entity(entity)
+            .set_accessibility_node(node, radio_button.label);
+    }
+}
+
+/// Sent when the selected option of a radio group changes
+#[derive(Event, Clone, Copy, Debug)]
+pub struct RadioGroupChanged {
+    pub entity: Entity,
+    pub value: Option<usize>,
+    pub source: ValueChangeSource,
+}
+
+impl WidgetEvent for RadioGroupChanged {
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
 #[derive(Component, Clone, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct RadioGroup {
     pub selected: Option<usize>,
+    reported_selected: Option<usize>,
 }
 
 impl Default for RadioGroup {
     fn default() -> Self {
-        Self { selected: None }
+        Self {
+            selected: None,
+            reported_selected: None,
+        }
+    }
+}
+
+impl BindableWidget for RadioGroup {
+    type Value = Option<usize>;
+    type Changed = RadioGroupChanged;
+
+    fn bound_value(&self) -> Option<usize> {
+        self.selected
+    }
+
+    fn set_bound_value(&mut self, value: Option<usize>) {
+        self.select(value);
+    }
+
+    fn user_edit(event: &RadioGroupChanged) -> Option<Option<usize>> {
+        (event.source == ValueChangeSource::User).then_some(event.value)
     }
 }
 
@@ -149,15 +258,18 @@ impl RadioGroup {
     }
 }
 
-#[derive(Component, Clone, Debug, Reflect)]
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct RadioButton {
     pub index: usize,
     pub checked: bool,
     unselectable: bool,
     group: Entity,
+    #[context("RadiomarkBackground")]
     radiomark_background: Entity,
+    #[context("Radiomark")]
     radiomark: Entity,
+    #[context("Label")]
     label: Entity,
 }
 
@@ -175,29 +287,6 @@ impl Default for RadioButton {
     }
 }
 
-impl UiContext for RadioButton {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            RadioButton::RADIOMARK_BACKGROUND => Ok(self.radiomark_background),
-            RadioButton::RADIOMARK => Ok(self.radiomark),
-            RadioButton::LABEL => Ok(self.label),
-            _ => Err(format!(
-                "{} doesn't exists for RadioButton. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
-    }
-
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![
-            RadioButton::RADIOMARK_BACKGROUND,
-            RadioButton::RADIOMARK,
-            RadioButton::LABEL,
-        ]
-    }
-}
-
 impl DefaultTheme for RadioButton {
     fn default_theme() -> Option<Theme<RadioButton>> {
         RadioButton::theme().into()
@@ -205,10 +294,6 @@ impl DefaultTheme for RadioButton {
 }
 
 impl RadioButton {
-    pub const RADIOMARK_BACKGROUND: &'static str = "RadiomarkBackground";
-    pub const RADIOMARK: &'static str = "Radiomark";
-    pub const LABEL: &'static str = "Label";
-
     pub fn theme() -> Theme<RadioButton> {
         let base_theme = PseudoTheme::deferred(None, RadioButton::primary_style);
         let checked_theme =
@@ -352,54 +437,96 @@ pub trait UiRadioGroupExt {
         selected: impl Into<Option<usize>>,
         unselectable: bool,
     ) -> UiBuilder<Entity>;
+
+    fn localized_radio_group(
+        &mut self,
+        options: Vec<LocalizedText>,
+        selected: impl Into<Option<usize>>,
+        unselectable: bool,
+    ) -> UiBuilder<'_, Entity>;
+}
+
+fn spawn_radio_group<'a>(
+    builder: &'a mut UiBuilder<'_, Entity>,
+    options: Vec<LabelConfig>,
+    selected: Option<usize>,
+    unselectable: bool,
+) -> UiBuilder<'a, Entity> {
+    let mut radio_group = builder.spawn((
+        RadioGroup::container(),
+        RadioGroup {
+            selected,
+            reported_selected: selected,
+        },
+    ));
+
+    let group = radio_group.id();
+    for (index, label) in options.into_iter().enumerate() {
+        let name = format!("Radio Button [{}]", label.label);
+        let mut radio_button = RadioButton {
+            checked: false,
+            unselectable,
+            index,
+            group,
+            ..default()
+        };
+
+        radio_group
+            .container(RadioButton::button(name), |button| {
+                radio_button.radiomark_background = button
+                    .container(RadioButton::radio_mark_background(), |radio_mark_bg| {
+                        radio_button.radiomark =
+                            radio_mark_bg.spawn(RadioButton::radio_mark()).id();
+                    })
+                    .id();
+                radio_button.label = button.label(label).id();
+            })
+            .insert(radio_button);
+    }
+
+    radio_group
 }
 
 impl UiRadioGroupExt for UiBuilder<'_, Entity> {
     /// A simple radio group with options. Optionally, the radio group can be "unselected"
-    /// 
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Checked` is added to the currently selected `RadioButton` entity
+    ///
+    /// ### Events
+    /// - `RadioGroupChanged`, when the selected option changes
     fn radio_group(
         &mut self,
         options: Vec<impl Into<String>>,
         selected: impl Into<Option<usize>>,
         unselectable: bool,
     ) -> UiBuilder<Entity> {
-        let mut radio_group = self.spawn((
-            RadioGroup::container(),
-            RadioGroup {
-                selected: selected.into(),
-            },
-        ));
-
-        let mut index = 0;
-        let group = radio_group.id();
-        for option in options {
-            let label = option.into();
-            let name = format!("Radio Button [{}]", label);
-            let mut radio_button = RadioButton {
-                checked: false,
-                unselectable,
-                index,
-                group,
+        let options = options
+            .into_iter()
+            .map(|option| LabelConfig {
+                label: option.into(),
                 ..default()
-            };
+            })
+            .collect();
 
-            radio_group
-                .container(RadioButton::button(name), |button| {
-                    radio_button.radiomark_background = button
-                        .container(RadioButton::radio_mark_background(), |radio_mark_bg| {
-                            radio_button.radiomark =
-                                radio_mark_bg.spawn(RadioButton::radio_mark()).id();
-                        })
-                        .id();
-                    radio_button.label = button.label(LabelConfig { label, ..default() }).id();
-                })
-                .insert(radio_button);
-
-            index += 1;
-        }
+        spawn_radio_group(self, options, selected.into(), unselectable)
+    }
 
-        radio_group
+    /// A radio group with options that follow the current locale.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Checked` is added to the currently selected `RadioButton` entity
+    ///
+    /// ### Events
+    /// - `RadioGroupChanged`, when the selected option changes
+    fn localized_radio_group(
+        &mut self,
+        options: Vec<LocalizedText>,
+        selected: impl Into<Option<usize>>,
+        unselectable: bool,
+    ) -> UiBuilder<'_, Entity> {
+        let options = options.into_iter().map(LabelConfig::localized).collect();
+
+        spawn_radio_group(self, options, selected.into(), unselectable)
     }
 }
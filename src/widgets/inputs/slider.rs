@@ -1,38 +1,64 @@
-use bevy::{input::mouse::MouseScrollUnit, prelude::*, ui::RelativeCursorPosition};
+use bevy::{
+    a11y::accesskit::{NodeBuilder, Role},
+    input::mouse::MouseScrollUnit,
+    prelude::*,
+    ui::RelativeCursorPosition,
+};
 
-use sickle_ui_scaffold::{prelude::*, ui_commands::UpdateTextExt};
+use sickle_macros::UiContext;
+use sickle_ui_scaffold::{
+    prelude::*,
+    ui_commands::{AccessibilityNodeOutdated, SetAccessibilityNodeExt, UpdateTextExt},
+};
 
-use crate::widgets::layout::{
-    container::UiContainerExt,
-    label::{LabelConfig, UiLabelExt},
+use crate::{
+    localization::LocalizedText,
+    widgets::{
+        binding::{BindableWidget, WidgetBindingPlugin},
+        layout::{
+            container::UiContainerExt,
+            label::{LabelConfig, UiLabelExt},
+        },
+        ValueChangeSource, WidgetEvent, WidgetEventPlugin,
+    },
 };
 
 pub struct SliderPlugin;
 
 impl Plugin for SliderPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(ComponentThemePlugin::<Slider>::default())
-            .add_systems(
-                Update,
-                (
-                    update_slider_on_scroll.after(ScrollableUpdate),
-                    update_slider_on_drag.after(DraggableUpdate),
-                    update_slider_handle,
-                    update_slider_readout,
-                )
-                    .chain(),
-            );
+        app.add_plugins((
+            ComponentThemePlugin::<Slider>::default(),
+            WidgetEventPlugin::<SliderChanged>::default(),
+            WidgetBindingPlugin::<Slider>::default(),
+        ))
+        .add_systems(
+            Update,
+            (
+                update_slider_on_scroll.after(ScrollableUpdate),
+                update_slider_on_drag.after(DraggableUpdate),
+                report_slider_change,
+                update_slider_handle,
+                update_slider_readout,
+                update_slider_accessibility,
+            )
+                .chain(),
+        );
     }
 }
 
 // TODO: Add input for value (w/ read/write flags)
 // TODO: Support click-on-bar value setting
+/// The bar or the handle of a slider, with its scroll state
+type SliderScrollable = (
+    AnyOf<(&'static SliderBar, &'static SliderDragHandle)>,
+    &'static Scrollable,
+);
+
 fn update_slider_on_scroll(
-    q_scrollables: Query<
-        (AnyOf<(&SliderBar, &SliderDragHandle)>, &Scrollable),
-        Changed<Scrollable>,
-    >,
+    q_scrollables: Query<SliderScrollable, Changed<Scrollable>>,
     mut q_slider: Query<&mut Slider>,
+    mut events: EventWriter<SliderChanged>,
 ) {
     for ((slider_bar, handle), scrollable) in &q_scrollables {
         let Some((axis, diff, unit)) = scrollable.last_change() else {
@@ -61,16 +87,18 @@ fn update_slider_on_scroll(
 
         let fraction = offset / 100.;
         slider.ratio = (slider.ratio + fraction).clamp(0., 1.);
+        slider.report_user_change(slider_id, &mut events);
     }
 }
 
 fn update_slider_on_drag(
     q_draggable: Query<(&Draggable, &SliderDragHandle, &Node), Changed<Draggable>>,
     q_node: Query<&Node>,
-    mut q_slider: Query<&mut Slider>,
+    mut q_slider: Query<(&mut Slider, Option<&PseudoStates>)>,
+    mut events: EventWriter<SliderChanged>,
 ) {
     for (draggable, handle, node) in &q_draggable {
-        let Ok(mut slider) = q_slider.get_mut(handle.slider) else {
+        let Ok((mut slider, pseudo_states)) = q_slider.get_mut(handle.slider) else {
             continue;
         };
 
@@ -81,6 +109,7 @@ fn update_slider_on_drag(
         if draggable.state == DragState::DragCanceled {
             if let Some(base_ratio) = slider.base_ratio {
                 slider.ratio = base_ratio;
+                slider.report_user_change(handle.slider, &mut events);
                 continue;
             }
         }
@@ -103,7 +132,11 @@ fn update_slider_on_drag(
                 if diff.x == 0. || width == 0. {
                     continue;
                 }
-                diff.x / width
+
+                match Slider::is_right_to_left(pseudo_states) {
+                    true => -diff.x / width,
+                    false => diff.x / width,
+                }
             }
             SliderAxis::Vertical => {
                 let height = slider_bar.size().y - node.size().y;
@@ -115,15 +148,35 @@ fn update_slider_on_drag(
         };
 
         slider.ratio = (slider.ratio + fraction).clamp(0., 1.);
+        slider.report_user_change(handle.slider, &mut events);
     }
 }
 
+fn report_slider_change(
+    mut q_slider: Query<(Entity, &mut Slider), Changed<Slider>>,
+    mut events: EventWriter<SliderChanged>,
+) {
+    for (entity, mut slider) in &mut q_slider {
+        if slider.ratio != slider.reported_ratio {
+            slider.bypass_change_detection().reported_ratio = slider.ratio;
+
+            events.send(SliderChanged {
+                entity,
+                value: slider.value(),
+                source: ValueChangeSource::Code,
+            });
+        }
+    }
+}
+
+type SliderLayoutChanged = Or<(Changed<Slider>, Changed<Node>, Changed<PseudoStates>)>;
+
 fn update_slider_handle(
-    q_slider: Query<&Slider, Or<(Changed<Slider>, Changed<Node>)>>,
+    q_slider: Query<(&Slider, Option<&PseudoStates>), SliderLayoutChanged>,
     q_node: Query<&Node>,
     mut q_hadle_style: Query<(&Node, &mut Style), With<SliderDragHandle>>,
 ) {
-    for slider in &q_slider {
+    for (slider, pseudo_states) in &q_slider {
         let Ok(slider_bar) = q_node.get(slider.bar_container) else {
             continue;
         };
@@ -135,7 +188,10 @@ fn update_slider_handle(
         match axis {
             SliderAxis::Horizontal => {
                 let width = slider_bar.size().x - node.size().x;
-                let handle_position = width * slider.ratio;
+                let handle_position = match Slider::is_right_to_left(pseudo_states) {
+                    true => width * (1. - slider.ratio),
+                    false => width * slider.ratio,
+                };
                 if style.left != Val::Px(handle_position) {
                     style.left = Val::Px(handle_position);
                 }
@@ -198,9 +254,40 @@ impl Default for SliderBar {
     }
 }
 
+fn update_slider_accessibility(
+    q_sliders: Query<(Entity, &Slider), AccessibilityNodeOutdated<Slider>>,
+    mut commands: Commands,
+) {
+    for (entity, slider) in &q_sliders {
+        let mut node = NodeBuilder::new(Role::Slider);
+        node.set_numeric_value(slider.value() as f64);
+        node.set_min_numeric_value(slider.config.min as f64);
+        node.set_max_numeric_value(slider.config.max as f64);
+
+        commands
+            .entity(entity)
+            .set_accessibility_node(node, slider.label);
+    }
+}
+
+/// Sent when the value of a slider changes
+#[derive(Event, Clone, Copy, Debug)]
+pub struct SliderChanged {
+    pub entity: Entity,
+    pub value: f32,
+    pub source: ValueChangeSource,
+}
+
+impl WidgetEvent for SliderChanged {
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
 #[derive(Component, Clone, Debug, Reflect)]
 pub struct SliderConfig {
     label: Option<String>,
+    localized_label: Option<LocalizedText>,
     min: f32,
     max: f32,
     initial_value: f32,
@@ -226,6 +313,7 @@ impl SliderConfig {
 
         SliderConfig {
             label: label.into(),
+            localized_label: None,
             min,
             max,
             initial_value,
@@ -278,12 +366,25 @@ impl SliderConfig {
 
         panic!("Value must be between min and max!");
     }
+
+    /// Displays the translation of the message as the label, instead of `label`
+    pub fn with_localized_label(self, label: LocalizedText) -> Self {
+        Self {
+            localized_label: Some(label),
+            ..self
+        }
+    }
+
+    pub fn has_label(&self) -> bool {
+        self.label.is_some() || self.localized_label.is_some()
+    }
 }
 
 impl Default for SliderConfig {
     fn default() -> Self {
         Self {
             label: None,
+            localized_label: None,
             min: 0.,
             max: 1.,
             initial_value: 0.5,
@@ -293,18 +394,25 @@ impl Default for SliderConfig {
     }
 }
 
-#[derive(Component, Clone, Debug, Reflect)]
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct Slider {
     ratio: f32,
     config: SliderConfig,
+    #[context("Label")]
     label: Entity,
+    #[context("BarContainer")]
     bar_container: Entity,
+    #[context("Bar")]
     bar: Entity,
+    #[context("Handle")]
     handle: Entity,
+    #[context("ReadoutContainer")]
     readout_container: Entity,
+    #[context("Readout")]
     readout: Entity,
     base_ratio: Option<f32>,
+    reported_ratio: f32,
 }
 
 impl Default for Slider {
@@ -313,6 +421,7 @@ impl Default for Slider {
             ratio: Default::default(),
             config: Default::default(),
             base_ratio: None,
+            reported_ratio: Default::default(),
             label: Entity::PLACEHOLDER,
             bar_container: Entity::PLACEHOLDER,
             bar: Entity::PLACEHOLDER,
@@ -323,32 +432,20 @@ impl Default for Slider {
     }
 }
 
-impl UiContext for Slider {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            Slider::LABEL => Ok(self.label),
-            Slider::BAR_CONTAINER => Ok(self.bar_container),
-            Slider::BAR => Ok(self.bar),
-            Slider::HANDLE => Ok(self.handle),
-            Slider::READOUT_CONTAINER => Ok(self.readout_container),
-            Slider::READOUT => Ok(self.readout),
-            _ => Err(format!(
-                "{} doesn't exists for Slider. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
+impl BindableWidget for Slider {
+    type Value = f32;
+    type Changed = SliderChanged;
+
+    fn bound_value(&self) -> f32 {
+        self.value()
+    }
+
+    fn set_bound_value(&mut self, value: f32) {
+        self.set_value(value.clamp(self.config.min, self.config.max));
     }
 
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![
-            Slider::LABEL,
-            Slider::BAR_CONTAINER,
-            Slider::BAR,
-            Slider::HANDLE,
-            Slider::READOUT_CONTAINER,
-            Slider::READOUT,
-        ]
+    fn user_edit(event: &SliderChanged) -> Option<f32> {
+        (event.source == ValueChangeSource::User).then_some(event.value)
     }
 }
 
@@ -359,13 +456,6 @@ impl DefaultTheme for Slider {
 }
 
 impl Slider {
-    pub const LABEL: &'static str = "Label";
-    pub const BAR_CONTAINER: &'static str = "BarContainer";
-    pub const BAR: &'static str = "Bar";
-    pub const HANDLE: &'static str = "Handle";
-    pub const READOUT_CONTAINER: &'static str = "ReadoutContainer";
-    pub const READOUT: &'static str = "Readout";
-
     pub fn value(&self) -> f32 {
         self.config.min.lerp(self.config.max, self.ratio)
     }
@@ -379,9 +469,29 @@ impl Slider {
         self.ratio = (value - self.config.min) / (self.config.max + (0. - self.config.min))
     }
 
+    fn report_user_change(&mut self, entity: Entity, events: &mut EventWriter<SliderChanged>) {
+        if self.ratio == self.reported_ratio {
+            return;
+        }
+
+        self.reported_ratio = self.ratio;
+        events.send(SliderChanged {
+            entity,
+            value: self.value(),
+            source: ValueChangeSource::User,
+        });
+    }
+
     pub fn theme() -> Theme<Slider> {
         let base_theme = PseudoTheme::deferred_context(None, Slider::primary_style);
-        Theme::new(vec![base_theme])
+        let rtl_theme =
+            PseudoTheme::deferred_context(vec![PseudoState::RightToLeft], Slider::rtl_style);
+
+        Theme::new(vec![base_theme, rtl_theme])
+    }
+
+    fn is_right_to_left(pseudo_states: Option<&PseudoStates>) -> bool {
+        pseudo_states.is_some_and(|states| states.has(&PseudoState::RightToLeft))
     }
 
     // TODO: bevy 0.14: Redesign handle style with rounded borders
@@ -395,6 +505,7 @@ impl Slider {
         match slider.config.axis {
             SliderAxis::Horizontal => {
                 style_builder
+                    .flex_direction(FlexDirection::Row)
                     .justify_content(JustifyContent::SpaceBetween)
                     .align_items(AlignItems::Center)
                     .width(Val::Percent(100.))
@@ -481,7 +592,7 @@ impl Slider {
             .sized_font(font.clone())
             .font_color(colors.on(On::Surface));
 
-        if slider.config.label.is_none() {
+        if !slider.config.has_label() {
             style_builder
                 .switch_target(Slider::LABEL)
                 .display(Display::None)
@@ -535,6 +646,25 @@ impl Slider {
             .copy_from(theme_data.interaction_animation);
     }
 
+    /// Horizontal sliders fill from the right, with the label on the right and the readout on the left
+    fn rtl_style(style_builder: &mut StyleBuilder, slider: &Slider, theme_data: &ThemeData) {
+        if slider.config.axis != SliderAxis::Horizontal {
+            return;
+        }
+
+        let theme_spacing = theme_data.spacing;
+
+        style_builder.flex_direction(FlexDirection::RowReverse);
+
+        style_builder
+            .switch_target(Slider::LABEL)
+            .margin(UiRect::left(Val::Px(theme_spacing.gaps.medium)));
+
+        style_builder
+            .switch_target(Slider::READOUT)
+            .margin(UiRect::right(Val::Px(theme_spacing.gaps.medium)));
+    }
+
     fn container(name: String) -> impl Bundle {
         (Name::new(name), NodeBundle::default())
     }
@@ -570,31 +700,39 @@ impl Slider {
 }
 
 pub trait UiSliderExt {
+    /// A horizontal or vertical slider with an optional label and readout
+    ///
+    /// ### Events
+    /// - `SliderChanged`, when the value of the slider changes
     fn slider(&mut self, config: SliderConfig) -> UiBuilder<Entity>;
 }
 
 impl UiSliderExt for UiBuilder<'_, Entity> {
     fn slider(&mut self, config: SliderConfig) -> UiBuilder<Entity> {
+        let ratio = (config.initial_value - config.min) / (config.max + (0. - config.min));
         let mut slider = Slider {
-            ratio: (config.initial_value - config.min) / (config.max + (0. - config.min)),
+            ratio,
+            reported_ratio: ratio,
             config: config.clone(),
             ..default()
         };
 
-        let label = match config.label {
-            Some(label) => label,
-            None => "".into(),
+        let label = match (config.localized_label, config.label) {
+            (Some(localized), _) => LabelConfig::localized(localized),
+            (None, Some(label)) => LabelConfig { label, ..default() },
+            (None, None) => LabelConfig::default(),
         };
-        let has_label = label.len() > 0;
-        let name = match has_label {
-            true => format!("Slider [{}]", label.clone()),
-            false => "Slider".into(),
+        let has_label = !label.label.is_empty() || label.localized.is_some();
+        let name = match &label.localized {
+            Some(localized) => format!("Slider [{}]", localized.id()),
+            None if has_label => format!("Slider [{}]", label.label),
+            None => "Slider".into(),
         };
 
         let mut input = self.container(Slider::container(name), |container| {
             let input_id = container.id();
 
-            slider.label = container.label(LabelConfig { label, ..default() }).id();
+            slider.label = container.label(label).id();
             slider.bar_container = container
                 .container(
                     (Slider::bar_container(), SliderBar { slider: input_id }),
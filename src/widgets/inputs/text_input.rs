@@ -0,0 +1,337 @@
+use bevy::{
+    a11y::accesskit::{NodeBuilder, Role},
+    prelude::*,
+    window::ReceivedCharacter,
+};
+
+use sickle_macros::UiContext;
+use sickle_ui_scaffold::{
+    prelude::*,
+    ui_commands::{AccessibilityNodeOutdated, SetAccessibilityNodeExt, UpdateTextExt},
+};
+
+use crate::widgets::{
+    binding::{BindableWidget, WidgetBindingPlugin},
+    layout::{
+        container::UiContainerExt,
+        label::{LabelConfig, UiLabelExt},
+    },
+    ValueChangeSource, WidgetEvent, WidgetEventPlugin,
+};
+
+const CARET: &str = "|";
+
+pub struct TextInputPlugin;
+
+impl Plugin for TextInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            ComponentThemePlugin::<TextInput>::default(),
+            WidgetEventPlugin::<TextInputChanged>::default(),
+            WidgetBindingPlugin::<TextInput>::default(),
+        ))
+        .add_systems(
+            Update,
+            (
+                update_text_input_focus,
+                update_text_input_value,
+                report_text_input_change,
+                update_text_input,
+                update_text_input_accessibility,
+            )
+                .chain(),
+        );
+    }
+}
+
+fn update_text_input_focus(
+    mut q_text_inputs: Query<(&mut TextInput, &Interaction)>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    let clicked = mouse_input.any_just_pressed([MouseButton::Left, MouseButton::Right]);
+    let dismissed = keyboard_input.any_just_pressed([KeyCode::Escape, KeyCode::Enter]);
+
+    for (mut text_input, interaction) in &mut q_text_inputs {
+        let pressed = *interaction == Interaction::Pressed;
+        if clicked && text_input.focused != pressed {
+            text_input.focused = pressed;
+        } else if dismissed && text_input.focused {
+            text_input.focused = false;
+        }
+    }
+}
+
+fn update_text_input_value(
+    mut q_text_inputs: Query<(Entity, &mut TextInput)>,
+    mut characters: EventReader<ReceivedCharacter>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut events: EventWriter<TextInputChanged>,
+) {
+    // NOTE: Backspace is read from the key code, platforms send different control characters
+    let backspace = keyboard_input.just_pressed(KeyCode::Backspace);
+    if characters.is_empty() && !backspace {
+        return;
+    }
+
+    let characters: Vec<char> = characters
+        .read()
+        .flat_map(|event| event.char.chars())
+        .filter(|character| !character.is_control())
+        .collect();
+
+    for (entity, mut text_input) in &mut q_text_inputs {
+        if !text_input.focused {
+            continue;
+        }
+
+        let mut value = text_input.value.clone();
+        if backspace {
+            value.pop();
+        }
+        value.extend(characters.iter());
+
+        if value != text_input.value {
+            text_input.value = value.clone();
+            text_input.reported_value = value.clone();
+
+            events.send(TextInputChanged {
+                entity,
+                value,
+                source: ValueChangeSource::User,
+            });
+        }
+    }
+}
+
+fn report_text_input_change(
+    mut q_text_inputs: Query<(Entity, &mut TextInput), Changed<TextInput>>,
+    mut events: EventWriter<TextInputChanged>,
+) {
+    for (entity, mut text_input) in &mut q_text_inputs {
+        if text_input.value != text_input.reported_value {
+            let value = text_input.value.clone();
+            text_input.bypass_change_detection().reported_value = value.clone();
+
+            events.send(TextInputChanged {
+                entity,
+                value,
+                source: ValueChangeSource::Code,
+            });
+        }
+    }
+}
+
+fn update_text_input(
+    q_text_inputs: Query<(Entity, &TextInput), Changed<TextInput>>,
+    mut commands: Commands,
+) {
+    for (entity, text_input) in &q_text_inputs {
+        let text = match (text_input.value.is_empty(), text_input.focused) {
+            (true, true) => CARET.into(),
+            (true, false) => text_input.placeholder.clone(),
+            (false, true) => format!("{}{}", text_input.value, CARET),
+            (false, false) => text_input.value.clone(),
+        };
+        commands.entity(text_input.text).update_text(text);
+
+        match text_input.focused {
+            true => commands
+                .entity(entity)
+                .add_pseudo_state(PseudoState::Selected),
+            false => commands
+                .entity(entity)
+                .remove_pseudo_state(PseudoState::Selected),
+        };
+
+        match text_input.value.is_empty() {
+            true => commands.entity(entity).add_pseudo_state(PseudoState::Empty),
+            false => commands
+                .entity(entity)
+                .remove_pseudo_state(PseudoState::Empty),
+        };
+    }
+}
+
+fn update_text_input_accessibility(
+    q_text_inputs: Query<(Entity, &TextInput), AccessibilityNodeOutdated<TextInput>>,
+    mut commands: Commands,
+) {
+    for (entity, text_input) in &q_text_inputs {
+        let mut node = NodeBuilder::new(Role::TextInput);
+        node.set_value(text_input.value.as_str());
+        node.set_name(text_input.placeholder.as_str());
+
+        commands.entity(entity).set_accessibility_node(node, None);
+    }
+}
+
+/// Sent when the text of a text input changes
+#[derive(Event, Clone, Debug)]
+pub struct TextInputChanged {
+    pub entity: Entity,
+    pub value: String,
+    pub source: ValueChangeSource,
+}
+
+impl WidgetEvent for TextInputChanged {
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
+#[reflect(Component)]
+pub struct TextInput {
+    pub value: String,
+    pub placeholder: String,
+    reported_value: String,
+    focused: bool,
+    #[context("Text")]
+    text: Entity,
+}
+
+impl Default for TextInput {
+    fn default() -> Self {
+        Self {
+            value: "".into(),
+            placeholder: "".into(),
+            reported_value: "".into(),
+            focused: false,
+            text: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl BindableWidget for TextInput {
+    type Value = String;
+    type Changed = TextInputChanged;
+
+    fn bound_value(&self) -> String {
+        self.value.clone()
+    }
+
+    fn set_bound_value(&mut self, value: String) {
+        self.value = value;
+    }
+
+    fn user_edit(event: &TextInputChanged) -> Option<String> {
+        (event.source == ValueChangeSource::User).then(|| event.value.clone())
+    }
+}
+
+impl DefaultTheme for TextInput {
+    fn default_theme() -> Option<Theme<TextInput>> {
+        TextInput::theme().into()
+    }
+}
+
+impl TextInput {
+    pub fn focused(&self) -> bool {
+        self.focused
+    }
+
+    pub fn theme() -> Theme<TextInput> {
+        let base_theme = PseudoTheme::deferred(None, TextInput::primary_style);
+        let empty_theme = PseudoTheme::deferred(vec![PseudoState::Empty], TextInput::empty_style);
+        let focused_theme =
+            PseudoTheme::deferred(vec![PseudoState::Selected], TextInput::focused_style);
+        Theme::new(vec![base_theme, empty_theme, focused_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+        let font = theme_data
+            .text
+            .get(FontStyle::Body, FontScale::Medium, FontType::Regular);
+
+        style_builder
+            .align_items(AlignItems::Center)
+            .min_height(Val::Px(theme_spacing.areas.small))
+            .padding(UiRect::horizontal(Val::Px(theme_spacing.gaps.small)))
+            .margin(UiRect::all(Val::Px(theme_spacing.gaps.tiny)))
+            .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
+            .background_color(colors.container(Container::SurfaceMid))
+            .animated()
+            .border_color(AnimatedVals {
+                idle: colors.accent(Accent::OutlineVariant),
+                hover: colors.accent(Accent::Outline).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(TextInput::TEXT)
+            .sized_font(font)
+            .font_color(colors.on(On::Surface));
+    }
+
+    fn empty_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder
+            .switch_target(TextInput::TEXT)
+            .font_color(colors.on(On::SurfaceVariant));
+    }
+
+    fn focused_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let colors = theme_data.colors();
+
+        style_builder.border_color(colors.accent(Accent::Primary));
+    }
+
+    fn frame(name: String) -> impl Bundle {
+        (
+            Name::new(name),
+            ButtonBundle::default(),
+            TrackedInteraction::default(),
+        )
+    }
+}
+
+pub trait UiTextInputExt {
+    fn text_input(
+        &mut self,
+        value: impl Into<String>,
+        placeholder: impl Into<String>,
+    ) -> UiBuilder<'_, Entity>;
+}
+
+impl UiTextInputExt for UiBuilder<'_, Entity> {
+    /// A single line text input. It is focused by clicking it, and loses focus on
+    /// `Enter`, `Escape` or when clicking elsewhere.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Selected`, while the input has focus
+    /// - `PseudoState::Empty`, while the placeholder is shown
+    ///
+    /// ### Events
+    /// - `TextInputChanged`, when the text changes
+    fn text_input(
+        &mut self,
+        value: impl Into<String>,
+        placeholder: impl Into<String>,
+    ) -> UiBuilder<'_, Entity> {
+        let value: String = value.into();
+        let mut text_input = TextInput {
+            reported_value: value.clone(),
+            value,
+            placeholder: placeholder.into(),
+            ..default()
+        };
+
+        let name = match text_input.placeholder.is_empty() {
+            true => "Text Input".into(),
+            false => format!("Text Input [{}]", text_input.placeholder),
+        };
+
+        let mut input = self.container(TextInput::frame(name), |container| {
+            text_input.text = container.label(LabelConfig::default()).id();
+        });
+
+        input.insert(text_input);
+
+        input
+    }
+}
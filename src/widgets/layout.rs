@@ -2,6 +2,7 @@ pub mod column;
 pub mod container;
 pub mod docking_zone;
 pub mod floating_panel;
+pub mod floating_panel_manager;
 pub mod foldable;
 pub mod icon;
 pub mod label;
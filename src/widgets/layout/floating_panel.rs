@@ -4,20 +4,24 @@ use bevy::{
     window::{PrimaryWindow, WindowResized},
 };
 
+use sickle_macros::UiContext;
 use sickle_ui_scaffold::{prelude::*, ui_commands::RefreshThemeExt};
 
-use crate::widgets::layout::{
-    container::UiContainerExt,
-    label::{LabelConfig, SetLabelTextExt, UiLabelExt},
-    panel::UiPanelExt,
-    resize_handles::{ResizeDirection, ResizeHandle, UiResizeHandlesExt},
-    scroll_view::UiScrollViewExt,
+use crate::{
+    localization::LocalizedText,
+    widgets::layout::{
+        container::UiContainerExt,
+        label::{LabelConfig, SetLabelTextExt, UiLabelExt},
+        panel::UiPanelExt,
+        resize_handles::{ResizeDirection, ResizeHandle, UiResizeHandlesExt},
+        scroll_view::UiScrollViewExt,
+    },
 };
 
 use super::column::UiColumnExt;
 
 const MIN_PANEL_SIZE: Vec2 = Vec2 { x: 150., y: 100. };
-const MIN_FLOATING_PANEL_Z_INDEX: usize = 1000;
+pub(crate) const MIN_FLOATING_PANEL_Z_INDEX: usize = 1000;
 const PRIORITY_FLOATING_PANEL_Z_INDEX: usize = 10000;
 const WINDOW_RESIZE_PADDING: f32 = 20.;
 
@@ -26,7 +30,11 @@ pub struct FloatingPanelPlugin;
 impl Plugin for FloatingPanelPlugin {
     fn build(&self, app: &mut App) {
         app.configure_sets(Update, FloatingPanelUpdate.after(DroppableUpdate))
-            .add_plugins(ComponentThemePlugin::<FloatingPanel>::default())
+            .init_resource::<FloatingPanelSnapping>()
+            .add_plugins((
+                ComponentThemePlugin::<FloatingPanel>::default(),
+                ComponentThemePlugin::<FloatingPanelSnapGuide>::default(),
+            ))
             .add_systems(PreUpdate, update_floating_panel_panel_id)
             .add_systems(
                 Update,
@@ -34,10 +42,12 @@ impl Plugin for FloatingPanelPlugin {
                     index_floating_panels.run_if(panel_added),
                     process_panel_close_pressed,
                     process_panel_fold_pressed,
+                    process_panel_minimize_pressed,
                     update_panel_size_on_resize,
                     update_panel_on_title_drag,
                     handle_window_resize.run_if(window_resized),
                     update_panel_layout,
+                    update_panel_snap_guides,
                 )
                     .chain()
                     .in_set(FloatingPanelUpdate),
@@ -133,16 +143,46 @@ fn process_panel_fold_pressed(
     }
 }
 
+fn process_panel_minimize_pressed(
+    q_buttons: Query<
+        (Entity, &FloatingPanelMinimizeButton, &FluxInteraction),
+        Changed<FluxInteraction>,
+    >,
+    mut q_panel_configs: Query<&mut FloatingPanelConfig>,
+) {
+    for (entity, button, interaction) in &q_buttons {
+        if *interaction == FluxInteraction::Released {
+            let Ok(mut config) = q_panel_configs.get_mut(button.panel) else {
+                warn!(
+                    "Missing floating panel config for minimize button {:?}",
+                    entity
+                );
+                continue;
+            };
+
+            config.minimized = true;
+        }
+    }
+}
+
 fn update_panel_size_on_resize(
     q_draggable: Query<(&Draggable, &ResizeHandle, &FloatingPanelResizeHandle), Changed<Draggable>>,
-    mut q_panels: Query<&mut FloatingPanel>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    r_snapping: Res<FloatingPanelSnapping>,
+    mut q_panels: Query<(Entity, &mut FloatingPanel, &Node, Option<&Parent>)>,
 ) {
-    if let Some(_) = q_panels.iter().find(|p| p.priority) {
+    if q_panels.iter().any(|(_, p, _, _)| p.priority) {
         return;
     }
 
+    let window_size = q_window
+        .get_single()
+        .ok()
+        .map(|window| Vec2::new(window.width(), window.height()));
+    let panel_rects = snap_panel_rects(q_panels.iter());
+
     for (draggable, handle, handle_ref) in &q_draggable {
-        let Ok(mut panel) = q_panels.get_mut(handle_ref.panel) else {
+        let Ok((panel_id, mut panel, _, _)) = q_panels.get_mut(handle_ref.panel) else {
             continue;
         };
 
@@ -151,6 +191,9 @@ fn update_panel_size_on_resize(
             || draggable.state == DragState::DragCanceled
         {
             panel.resizing = false;
+            panel.unsnapped_position = None;
+            panel.unsnapped_size = None;
+            panel.snap_lines = Default::default();
             continue;
         }
 
@@ -160,45 +203,104 @@ fn update_panel_size_on_resize(
 
         let size_diff = handle.direction().to_size_diff(diff);
 
-        let old_size = panel.size;
+        // Resizing is tracked without snapping applied, so that the panel can leave a snap line
+        let mut position = panel.unsnapped_position.unwrap_or(panel.position);
+        let mut size = panel.unsnapped_size.unwrap_or(panel.size);
+        let old_size = size;
         panel.resizing = true;
-        panel.size += size_diff;
+        size += size_diff;
         if draggable.state == DragState::DragEnd {
-            if panel.size.x < MIN_PANEL_SIZE.x {
-                panel.size.x = MIN_PANEL_SIZE.x;
+            if size.x < MIN_PANEL_SIZE.x {
+                size.x = MIN_PANEL_SIZE.x;
             }
-            if panel.size.y < MIN_PANEL_SIZE.y {
-                panel.size.y = MIN_PANEL_SIZE.y;
+            if size.y < MIN_PANEL_SIZE.y {
+                size.y = MIN_PANEL_SIZE.y;
             }
         }
 
         let pos_diff = match handle.direction() {
             ResizeDirection::North => Vec2 {
                 x: 0.,
-                y: clip_position_change(diff.y, MIN_PANEL_SIZE.y, old_size.y, panel.size.y),
+                y: clip_position_change(diff.y, MIN_PANEL_SIZE.y, old_size.y, size.y),
             },
             ResizeDirection::NorthEast => Vec2 {
                 x: 0.,
-                y: clip_position_change(diff.y, MIN_PANEL_SIZE.y, old_size.y, panel.size.y),
+                y: clip_position_change(diff.y, MIN_PANEL_SIZE.y, old_size.y, size.y),
             },
             ResizeDirection::East => Vec2::ZERO,
             ResizeDirection::SouthEast => Vec2::ZERO,
             ResizeDirection::South => Vec2::ZERO,
             ResizeDirection::SouthWest => Vec2 {
-                x: clip_position_change(diff.x, MIN_PANEL_SIZE.x, old_size.x, panel.size.x),
+                x: clip_position_change(diff.x, MIN_PANEL_SIZE.x, old_size.x, size.x),
                 y: 0.,
             },
             ResizeDirection::West => Vec2 {
-                x: clip_position_change(diff.x, MIN_PANEL_SIZE.x, old_size.x, panel.size.x),
+                x: clip_position_change(diff.x, MIN_PANEL_SIZE.x, old_size.x, size.x),
                 y: 0.,
             },
             ResizeDirection::NorthWest => Vec2 {
-                x: clip_position_change(diff.x, MIN_PANEL_SIZE.x, old_size.x, panel.size.x),
-                y: clip_position_change(diff.y, MIN_PANEL_SIZE.y, old_size.y, panel.size.y),
+                x: clip_position_change(diff.x, MIN_PANEL_SIZE.x, old_size.x, size.x),
+                y: clip_position_change(diff.y, MIN_PANEL_SIZE.y, old_size.y, size.y),
             },
         };
 
-        panel.position += pos_diff;
+        position += pos_diff;
+        panel.unsnapped_position = position.into();
+        panel.unsnapped_size = size.into();
+        panel.snap_lines = Default::default();
+
+        if r_snapping.enabled {
+            let rect = Rect::from_corners(position, position + size.max(MIN_PANEL_SIZE));
+            let (x_targets, y_targets) =
+                snap_targets(panel_id, rect, &panel_rects, window_size, &r_snapping);
+            let (snap_x, snap_y) = resize_snap_edges(handle.direction());
+
+            if let Some(grow_x) = snap_x {
+                let edge = if grow_x { rect.max.x } else { rect.min.x };
+                if let Some((diff, line)) = find_snap(&[edge], &x_targets, r_snapping.distance) {
+                    let new_width = if grow_x { size.x + diff } else { size.x - diff };
+                    if new_width >= MIN_PANEL_SIZE.x {
+                        if !grow_x {
+                            position.x += diff;
+                        }
+                        size.x = new_width;
+                        panel.snap_lines.x = line.into();
+                    }
+                }
+            }
+
+            if let Some(grow_y) = snap_y {
+                let edge = if grow_y { rect.max.y } else { rect.min.y };
+                if let Some((diff, line)) = find_snap(&[edge], &y_targets, r_snapping.distance) {
+                    let new_height = if grow_y { size.y + diff } else { size.y - diff };
+                    if new_height >= MIN_PANEL_SIZE.y {
+                        if !grow_y {
+                            position.y += diff;
+                        }
+                        size.y = new_height;
+                        panel.snap_lines.y = line.into();
+                    }
+                }
+            }
+        }
+
+        panel.position = position;
+        panel.size = size;
+    }
+}
+
+/// Returns which edges move for a resize direction, per axis.
+/// `Some(true)` means the far (right / bottom) edge moves, `Some(false)` the near one.
+fn resize_snap_edges(direction: ResizeDirection) -> (Option<bool>, Option<bool>) {
+    match direction {
+        ResizeDirection::North => (None, Some(false)),
+        ResizeDirection::NorthEast => (Some(true), Some(false)),
+        ResizeDirection::East => (Some(true), None),
+        ResizeDirection::SouthEast => (Some(true), Some(true)),
+        ResizeDirection::South => (None, Some(true)),
+        ResizeDirection::SouthWest => (Some(false), Some(true)),
+        ResizeDirection::West => (Some(false), None),
+        ResizeDirection::NorthWest => (Some(false), Some(false)),
     }
 }
 
@@ -215,27 +317,37 @@ fn clip_position_change(diff: f32, min: f32, old_size: f32, new_size: f32) -> f3
     new_diff
 }
 
+/// The title or a drag handle of a floating panel, with its drag state
+type FloatingPanelDraggable = (
+    &'static Draggable,
+    AnyOf<(
+        &'static FloatingPanelTitle,
+        &'static FloatingPanelDragHandle,
+    )>,
+);
+
 fn update_panel_on_title_drag(
-    q_draggable: Query<
-        (
-            &Draggable,
-            AnyOf<(&FloatingPanelTitle, &FloatingPanelDragHandle)>,
-        ),
-        Changed<Draggable>,
-    >,
-    mut q_panels: Query<(Entity, &mut FloatingPanel)>,
+    q_draggable: Query<FloatingPanelDraggable, Changed<Draggable>>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    r_snapping: Res<FloatingPanelSnapping>,
+    mut q_panels: Query<(Entity, &mut FloatingPanel, &Node, Option<&Parent>)>,
 ) {
-    if let Some(_) = q_panels.iter().find(|(_, p)| p.priority) {
+    if q_panels.iter().any(|(_, p, _, _)| p.priority) {
         return;
     }
 
-    let max_index = if let Some(Some(m)) = q_panels.iter().map(|(_, p)| p.z_index).max() {
+    let max_index = if let Some(Some(m)) = q_panels.iter().map(|(_, p, _, _)| p.z_index).max() {
         m
     } else {
         0
     };
     let mut offset = 1;
 
+    let window_size = q_window
+        .get_single()
+        .ok()
+        .map(|window| Vec2::new(window.width(), window.height()));
+    let panel_rects = snap_panel_rects(q_panels.iter());
     let mut panel_updated = false;
 
     for (draggable, (panel_title, drag_handle)) in &q_draggable {
@@ -247,7 +359,7 @@ fn update_panel_on_title_drag(
             continue;
         };
 
-        let Ok((_, mut panel)) = q_panels.get_mut(panel_id) else {
+        let Ok((_, mut panel, node, _)) = q_panels.get_mut(panel_id) else {
             continue;
         };
 
@@ -260,6 +372,8 @@ fn update_panel_on_title_drag(
             || draggable.state == DragState::DragCanceled
         {
             panel.moving = false;
+            panel.unsnapped_position = None;
+            panel.snap_lines = Default::default();
             continue;
         }
 
@@ -268,8 +382,33 @@ fn update_panel_on_title_drag(
             continue;
         };
 
+        // Movement is tracked without snapping applied, so that the panel can be pulled off a snap line
+        let mut position = panel.unsnapped_position.unwrap_or(panel.position) + diff;
+        panel.unsnapped_position = position.into();
+        panel.snap_lines = Default::default();
+
+        if r_snapping.enabled {
+            let rect = Rect::from_corners(position, position + node.size());
+            let (x_targets, y_targets) =
+                snap_targets(panel_id, rect, &panel_rects, window_size, &r_snapping);
+
+            if let Some((diff, line)) =
+                find_snap(&[rect.min.x, rect.max.x], &x_targets, r_snapping.distance)
+            {
+                position.x += diff;
+                panel.snap_lines.x = line.into();
+            }
+
+            if let Some((diff, line)) =
+                find_snap(&[rect.min.y, rect.max.y], &y_targets, r_snapping.distance)
+            {
+                position.y += diff;
+                panel.snap_lines.y = line.into();
+            }
+        }
+
         panel.z_index = Some(max_index + offset);
-        panel.position += diff;
+        panel.position = position;
         offset += 1;
         panel_updated = true;
     }
@@ -280,17 +419,105 @@ fn update_panel_on_title_drag(
 
     let mut panel_indices: Vec<(Entity, Option<usize>)> = q_panels
         .iter()
-        .map(|(entity, panel)| (entity, panel.z_index))
+        .map(|(entity, panel, _, _)| (entity, panel.z_index))
         .collect();
     panel_indices.sort_by(|(_, a), (_, b)| a.cmp(b));
 
     for (i, (entity, _)) in panel_indices.iter().enumerate() {
-        if let Some((_, mut panel)) = q_panels.iter_mut().find(|(e, _)| e == entity) {
+        if let Some((_, mut panel, _, _)) = q_panels.iter_mut().find(|(e, _, _, _)| e == entity) {
             panel.z_index = (MIN_FLOATING_PANEL_Z_INDEX + i + 1).into();
         };
     }
 }
 
+struct SnapPanelRect {
+    panel: Entity,
+    parent: Option<Entity>,
+    rect: Rect,
+}
+
+fn snap_panel_rects<'a>(
+    panels: impl Iterator<Item = (Entity, &'a FloatingPanel, &'a Node, Option<&'a Parent>)>,
+) -> Vec<SnapPanelRect> {
+    panels
+        .map(|(entity, panel, node, parent)| SnapPanelRect {
+            panel: entity,
+            parent: parent.map(|p| p.get()),
+            rect: Rect::from_corners(panel.position, panel.position + node.size()),
+        })
+        .collect()
+}
+
+/// Collects the edges a panel can snap to on each axis.
+///
+/// Panel positions are in window space (see `SetAbsolutePosition`), so are the targets.
+///
+/// Edges of other panels are only considered if they share the same parent and
+/// are close enough on the other axis to be visually adjacent.
+fn snap_targets(
+    panel: Entity,
+    rect: Rect,
+    panel_rects: &[SnapPanelRect],
+    window_size: Option<Vec2>,
+    snapping: &FloatingPanelSnapping,
+) -> (Vec<f32>, Vec<f32>) {
+    let mut x_targets: Vec<f32> = Vec::new();
+    let mut y_targets: Vec<f32> = Vec::new();
+
+    if snapping.to_window {
+        if let Some(window_size) = window_size {
+            x_targets.extend([0., window_size.x]);
+            y_targets.extend([0., window_size.y]);
+        }
+    }
+
+    if snapping.to_panels {
+        let parent = panel_rects
+            .iter()
+            .find(|other| other.panel == panel)
+            .and_then(|own| own.parent);
+
+        for other in panel_rects
+            .iter()
+            .filter(|other| other.panel != panel && other.parent == parent)
+        {
+            let near = other.rect.inset(snapping.distance);
+            if near.min.y <= rect.max.y && near.max.y >= rect.min.y {
+                x_targets.extend([other.rect.min.x, other.rect.max.x]);
+            }
+            if near.min.x <= rect.max.x && near.max.x >= rect.min.x {
+                y_targets.extend([other.rect.min.y, other.rect.max.y]);
+            }
+        }
+    }
+
+    (x_targets, y_targets)
+}
+
+/// Finds the closest target to any of the edges within `distance`.
+/// Returns the offset to apply to the edges and the target snapped to.
+fn find_snap(edges: &[f32], targets: &[f32], distance: f32) -> Option<(f32, f32)> {
+    let mut closest: Option<(f32, f32)> = None;
+    for edge in edges {
+        for target in targets {
+            let diff = target - edge;
+            if diff.abs() > distance {
+                continue;
+            }
+
+            let is_closer = match closest {
+                Some((prev, _)) => diff.abs() < prev.abs(),
+                None => true,
+            };
+            if is_closer {
+                closest = (diff, *target).into();
+            }
+        }
+    }
+
+    closest
+}
+
 fn window_resized(e_resized: EventReader<WindowResized>) -> bool {
     e_resized.len() > 0
 }
@@ -325,21 +552,27 @@ fn handle_window_resize(
     }
 }
 
+type FloatingPanelChanged = Or<(Changed<FloatingPanel>, Changed<FloatingPanelConfig>)>;
+
 fn update_panel_layout(
-    q_panels: Query<
-        (Entity, &FloatingPanel, Ref<FloatingPanelConfig>),
-        Or<(Changed<FloatingPanel>, Changed<FloatingPanelConfig>)>,
-    >,
+    q_panels: Query<(Entity, &FloatingPanel, Ref<FloatingPanelConfig>), FloatingPanelChanged>,
     mut commands: Commands,
 ) {
     for (entity, panel, config) in &q_panels {
         if config.is_changed() {
             commands
                 .style(panel.title_container)
-                .render(config.title.is_some());
+                .render(config.has_title());
+
+            if config.has_title() {
+                if let Some(localized_title) = config.localized_title.clone() {
+                    commands
+                        .entity(panel.title)
+                        .set_label_localized_text(localized_title);
+                } else if let Some(title) = config.title.clone() {
+                    commands.entity(panel.title).set_label_text(title);
+                }
 
-            if let Some(title) = config.title.clone() {
-                commands.entity(panel.title).set_label_text(title);
                 if config.draggable {
                     commands
                         .style(panel.title_container)
@@ -353,6 +586,10 @@ fn update_panel_layout(
                 commands.style(panel.drag_handle).render(config.draggable);
             }
 
+            commands.style(entity).render(!config.minimized);
+            commands
+                .style(panel.minimize_button)
+                .render(config.minimizable);
             commands.style(panel.content_view).render(!config.folded);
             if config.folded {
                 commands
@@ -424,6 +661,198 @@ fn update_panel_layout(
     }
 }
 
+fn update_panel_snap_guides(
+    q_panels: Query<(Entity, &FloatingPanel, Option<&Parent>)>,
+    q_nodes: Query<(&Node, &GlobalTransform)>,
+    q_changed_panels: Query<Entity, Changed<FloatingPanel>>,
+    q_guides: Query<(Entity, &FloatingPanelSnapGuide)>,
+    r_snapping: Res<FloatingPanelSnapping>,
+    mut commands: Commands,
+) {
+    for (entity, guide) in &q_guides {
+        if !q_panels.contains(guide.panel) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    for panel_id in &q_changed_panels {
+        // Safe unwrap: entity comes from a narrower query of the same component
+        let (_, panel, parent) = q_panels.get(panel_id).unwrap();
+        // Guides are placed in their parent, snap lines are in window space
+        let origin = parent
+            .and_then(|parent| q_nodes.get(parent.get()).ok())
+            .map(|(node, transform)| transform.translation().truncate() - (node.size() / 2.))
+            .unwrap_or_default();
+
+        for axis in [FloatingPanelSnapAxis::X, FloatingPanelSnapAxis::Y] {
+            let line = match r_snapping.show_guides {
+                true => match axis {
+                    FloatingPanelSnapAxis::X => panel.snap_lines.x.map(|x| x - origin.x),
+                    FloatingPanelSnapAxis::Y => panel.snap_lines.y.map(|y| y - origin.y),
+                },
+                false => None,
+            };
+            let guide = q_guides
+                .iter()
+                .find(|(_, guide)| guide.panel == panel_id && guide.axis == axis)
+                .map(|(entity, _)| entity);
+
+            match (line, guide) {
+                (Some(line), Some(guide)) => {
+                    FloatingPanelSnapGuide::place(guide, axis, line, &mut commands);
+                }
+                (Some(line), None) => {
+                    let bundle = FloatingPanelSnapGuide::bundle(panel_id, axis);
+                    let guide = match parent {
+                        Some(parent) => commands.ui_builder(parent.get()).spawn(bundle).id(),
+                        None => commands.spawn(bundle).id(),
+                    };
+                    FloatingPanelSnapGuide::place(guide, axis, line, &mut commands);
+                }
+                (None, Some(guide)) => {
+                    commands.entity(guide).despawn_recursive();
+                }
+                (None, None) => (),
+            }
+        }
+    }
+}
+
+/// Controls how floating panels snap to the window edges and to each other
+/// while they are dragged or resized.
+#[derive(Resource, Clone, Copy, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct FloatingPanelSnapping {
+    pub enabled: bool,
+    /// Maximum distance in logical pixels at which an edge is pulled to a snap target
+    pub distance: f32,
+    pub to_window: bool,
+    pub to_panels: bool,
+    /// Draws a guide line along the edge the panel snapped to while dragging
+    pub show_guides: bool,
+}
+
+impl Default for FloatingPanelSnapping {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            distance: 10.,
+            to_window: true,
+            to_panels: true,
+            show_guides: true,
+        }
+    }
+}
+
+/// The lines a panel is currently snapped to, in window space like the panel's position
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub struct FloatingPanelSnapLines {
+    pub x: Option<f32>,
+    pub y: Option<f32>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum FloatingPanelSnapAxis {
+    /// A vertical guide marking an X coordinate
+    #[default]
+    X,
+    /// A horizontal guide marking an Y coordinate
+    Y,
+}
+
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
+#[reflect(Component)]
+pub struct FloatingPanelSnapGuide {
+    panel: Entity,
+    axis: FloatingPanelSnapAxis,
+}
+
+impl Default for FloatingPanelSnapGuide {
+    fn default() -> Self {
+        Self {
+            panel: Entity::PLACEHOLDER,
+            axis: Default::default(),
+        }
+    }
+}
+
+impl DefaultTheme for FloatingPanelSnapGuide {
+    fn default_theme() -> Option<Theme<FloatingPanelSnapGuide>> {
+        FloatingPanelSnapGuide::theme().into()
+    }
+}
+
+impl FloatingPanelSnapGuide {
+    pub fn panel(&self) -> Entity {
+        self.panel
+    }
+
+    pub fn axis(&self) -> FloatingPanelSnapAxis {
+        self.axis
+    }
+
+    pub fn theme() -> Theme<FloatingPanelSnapGuide> {
+        let base_theme = PseudoTheme::deferred_context(None, FloatingPanelSnapGuide::primary_style);
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(
+        style_builder: &mut StyleBuilder,
+        guide: &FloatingPanelSnapGuide,
+        theme_data: &ThemeData,
+    ) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+
+        match guide.axis {
+            FloatingPanelSnapAxis::X => style_builder
+                .width(Val::Px(theme_spacing.borders.extra_small))
+                .height(Val::Percent(100.)),
+            FloatingPanelSnapAxis::Y => style_builder
+                .width(Val::Percent(100.))
+                .height(Val::Px(theme_spacing.borders.extra_small)),
+        };
+
+        style_builder.background_color(colors.accent(Accent::Primary).with_a(0.8));
+    }
+
+    fn place(guide: Entity, axis: FloatingPanelSnapAxis, line: f32, commands: &mut Commands) {
+        match axis {
+            FloatingPanelSnapAxis::X => commands
+                .style_unchecked(guide)
+                .left(Val::Px(line))
+                .top(Val::Px(0.)),
+            FloatingPanelSnapAxis::Y => commands
+                .style_unchecked(guide)
+                .left(Val::Px(0.))
+                .top(Val::Px(line)),
+        };
+    }
+
+    fn bundle(panel: Entity, axis: FloatingPanelSnapAxis) -> impl Bundle {
+        (
+            Name::new("Floating Panel Snap Guide"),
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                focus_policy: FocusPolicy::Pass,
+                z_index: ZIndex::Global(PRIORITY_FLOATING_PANEL_Z_INDEX as i32 + 1),
+                ..default()
+            },
+            LockedStyleAttributes::from_vec(vec![
+                LockableStyleAttribute::PositionType,
+                LockableStyleAttribute::FocusPolicy,
+                LockableStyleAttribute::ZIndex,
+                LockableStyleAttribute::Top,
+                LockableStyleAttribute::Left,
+            ]),
+            FloatingPanelSnapGuide { panel, axis },
+        )
+    }
+}
+
 #[derive(Component, Clone, Debug, Reflect)]
 #[reflect(Component)]
 pub struct FloatingPanelResizeHandle {
@@ -438,6 +867,12 @@ impl Default for FloatingPanelResizeHandle {
     }
 }
 
+impl FloatingPanelResizeHandle {
+    pub fn panel(&self) -> Entity {
+        self.panel
+    }
+}
+
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 pub struct FloatingPanelTitle {
@@ -486,6 +921,20 @@ impl Default for FloatingPanelFoldButton {
     }
 }
 
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct FloatingPanelMinimizeButton {
+    panel: Entity,
+}
+
+impl Default for FloatingPanelMinimizeButton {
+    fn default() -> Self {
+        Self {
+            panel: Entity::PLACEHOLDER,
+        }
+    }
+}
+
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 pub struct FloatingPanelCloseButton {
@@ -503,10 +952,14 @@ impl Default for FloatingPanelCloseButton {
 #[derive(Component, Clone, Debug, Reflect)]
 pub struct FloatingPanelConfig {
     pub title: Option<String>,
+    /// Displayed instead of `title` once the locale resolves it
+    pub localized_title: Option<LocalizedText>,
     pub draggable: bool,
     pub resizable: bool,
     pub foldable: bool,
     pub folded: bool,
+    pub minimizable: bool,
+    pub minimized: bool,
     pub closable: bool,
     pub restrict_scroll: Option<ScrollAxis>,
 }
@@ -515,10 +968,13 @@ impl Default for FloatingPanelConfig {
     fn default() -> Self {
         Self {
             title: None,
+            localized_title: None,
             draggable: true,
             resizable: true,
             foldable: true,
             folded: false,
+            minimizable: false,
+            minimized: false,
             closable: true,
             restrict_scroll: None,
         }
@@ -529,26 +985,42 @@ impl FloatingPanelConfig {
     pub fn title(&self) -> Option<String> {
         self.title.clone()
     }
+
+    pub fn has_title(&self) -> bool {
+        self.title.is_some() || self.localized_title.is_some()
+    }
 }
 
-#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[derive(Component, Clone, Copy, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct FloatingPanel {
     size: Vec2,
     position: Vec2,
     z_index: Option<usize>,
+    #[context("DragHandle")]
     drag_handle: Entity,
+    #[context("FoldButton")]
     fold_button: Entity,
+    #[context("TitleContainer")]
     title_container: Entity,
+    #[context("Title")]
     title: Entity,
+    #[context("CloseButtonContainer")]
     close_button_container: Entity,
+    #[context("MinimizeButton")]
+    minimize_button: Entity,
+    #[context("CloseButton")]
     close_button: Entity,
+    #[context("ContentView")]
     content_view: Entity,
     content_panel_container: Entity,
     content_panel: Entity,
     resize_handles: Entity,
     resizing: bool,
     moving: bool,
+    unsnapped_position: Option<Vec2>,
+    unsnapped_size: Option<Vec2>,
+    snap_lines: FloatingPanelSnapLines,
     pub priority: bool,
 }
 
@@ -563,6 +1035,7 @@ impl Default for FloatingPanel {
             title_container: Entity::PLACEHOLDER,
             title: Entity::PLACEHOLDER,
             close_button_container: Entity::PLACEHOLDER,
+            minimize_button: Entity::PLACEHOLDER,
             close_button: Entity::PLACEHOLDER,
             content_view: Entity::PLACEHOLDER,
             content_panel_container: Entity::PLACEHOLDER,
@@ -570,42 +1043,14 @@ impl Default for FloatingPanel {
             resize_handles: Entity::PLACEHOLDER,
             resizing: Default::default(),
             moving: Default::default(),
+            unsnapped_position: Default::default(),
+            unsnapped_size: Default::default(),
+            snap_lines: Default::default(),
             priority: Default::default(),
         }
     }
 }
 
-impl UiContext for FloatingPanel {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            FloatingPanel::DRAG_HANDLE => Ok(self.drag_handle),
-            FloatingPanel::TITLE_CONTAINER => Ok(self.title_container),
-            FloatingPanel::TITLE => Ok(self.title),
-            FloatingPanel::FOLD_BUTTON => Ok(self.fold_button),
-            FloatingPanel::CLOSE_BUTTON_CONTAINER => Ok(self.close_button_container),
-            FloatingPanel::CLOSE_BUTTON => Ok(self.close_button),
-            FloatingPanel::CONTENT_VIEW => Ok(self.content_view),
-            _ => Err(format!(
-                "{} doesn't exists for FloatingPanel. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
-    }
-
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![
-            FloatingPanel::DRAG_HANDLE,
-            FloatingPanel::TITLE_CONTAINER,
-            FloatingPanel::TITLE,
-            FloatingPanel::FOLD_BUTTON,
-            FloatingPanel::CLOSE_BUTTON_CONTAINER,
-            FloatingPanel::CLOSE_BUTTON,
-            FloatingPanel::CONTENT_VIEW,
-        ]
-    }
-}
-
 impl DefaultTheme for FloatingPanel {
     fn default_theme() -> Option<Theme<FloatingPanel>> {
         FloatingPanel::theme().into()
@@ -613,20 +1058,16 @@ impl DefaultTheme for FloatingPanel {
 }
 
 impl FloatingPanel {
-    pub const DRAG_HANDLE: &'static str = "DragHandle";
-    pub const TITLE_CONTAINER: &'static str = "TitleContainer";
-    pub const TITLE: &'static str = "Title";
-    pub const FOLD_BUTTON: &'static str = "FoldButton";
-    pub const CLOSE_BUTTON_CONTAINER: &'static str = "CloseButtonContainer";
-    pub const CLOSE_BUTTON: &'static str = "CloseButton";
-    pub const CONTENT_VIEW: &'static str = "ContentView";
-
     pub fn theme() -> Theme<FloatingPanel> {
         let base_theme = PseudoTheme::deferred_context(None, FloatingPanel::primary_style);
         let folded_theme =
             PseudoTheme::deferred_context(vec![PseudoState::Folded], FloatingPanel::folded_style);
+        let folded_rtl_theme = PseudoTheme::deferred(
+            vec![PseudoState::Folded, PseudoState::RightToLeft],
+            FloatingPanel::folded_rtl_style,
+        );
 
-        Theme::new(vec![base_theme, folded_theme])
+        Theme::new(vec![base_theme, folded_theme, folded_rtl_theme])
     }
 
     fn primary_style(
@@ -726,6 +1167,24 @@ impl FloatingPanel {
             })
             .copy_from(theme_data.interaction_animation);
 
+        style_builder
+            .switch_context(FloatingPanel::MINIMIZE_BUTTON, None)
+            .size(Val::Px(theme_spacing.icons.small))
+            .margin(UiRect::all(Val::Px(theme_spacing.gaps.small)))
+            .icon(
+                theme_data
+                    .icons
+                    .minimize
+                    .with(colors.on(On::Surface), theme_spacing.icons.small),
+            )
+            .animated()
+            .font_color(AnimatedVals {
+                idle: colors.on(On::SurfaceVariant),
+                hover: colors.on(On::Surface).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
         style_builder
             .switch_context(FloatingPanel::CLOSE_BUTTON, None)
             .size(Val::Px(theme_spacing.icons.small))
@@ -783,6 +1242,20 @@ impl FloatingPanel {
             );
     }
 
+    fn folded_rtl_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+
+        style_builder
+            .switch_target(FloatingPanel::FOLD_BUTTON)
+            .icon(
+                theme_data
+                    .icons
+                    .chevron_left
+                    .with(colors.on(On::Surface), theme_spacing.icons.small),
+            );
+    }
+
     pub fn content_panel_container(&self) -> Entity {
         self.content_panel_container
     }
@@ -795,6 +1268,33 @@ impl FloatingPanel {
         self.title_container
     }
 
+    pub fn snap_lines(&self) -> FloatingPanelSnapLines {
+        self.snap_lines
+    }
+
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    pub fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    pub fn z_index(&self) -> Option<usize> {
+        self.z_index
+    }
+
+    pub(crate) fn set_z_index(&mut self, z_index: usize) {
+        self.z_index = z_index.into();
+    }
+
+    pub(crate) fn arrange(&mut self, position: Vec2, size: Option<Vec2>) {
+        self.position = position;
+        if let Some(size) = size {
+            self.size = size.max(MIN_PANEL_SIZE);
+        }
+    }
+
     fn frame(title: String) -> impl Bundle {
         (
             Name::new(format!("Floating Panel [{}]", title)),
@@ -867,6 +1367,16 @@ impl FloatingPanel {
         )
     }
 
+    fn minimize_button(panel: Entity) -> impl Bundle {
+        (
+            Name::new("Minimize Button"),
+            ButtonBundle::default(),
+            ContentSize::default(),
+            TrackedInteraction::default(),
+            FloatingPanelMinimizeButton { panel },
+        )
+    }
+
     fn close_button(panel: Entity) -> impl Bundle {
         (
             Name::new("Close Button"),
@@ -921,9 +1431,10 @@ pub trait UiFloatingPanelExt {
 
 impl UiFloatingPanelExt for UiBuilder<'_, Entity> {
     /// A floating panel that can be optionally dragable, foldable, and closable.
-    /// 
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Folded` is used when the panel is folded
+    /// - `PseudoState::RightToLeft` mirrors the fold button icon
     /// - `PseudoState::Resizable(_)` is transiently used by its resize handles
     fn floating_panel(
         &mut self,
@@ -932,7 +1443,9 @@ impl UiFloatingPanelExt for UiBuilder<'_, Entity> {
         spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
     ) -> UiBuilder<Entity> {
         let restrict_to = config.restrict_scroll;
-        let title_text = if let Some(text) = config.title.clone() {
+        let title_text = if let Some(localized) = &config.localized_title {
+            localized.fallback()
+        } else if let Some(text) = config.title.clone() {
             text
         } else {
             "Untitled".into()
@@ -962,6 +1475,7 @@ impl UiFloatingPanelExt for UiBuilder<'_, Entity> {
                     floating_panel.title = container
                         .label(LabelConfig {
                             label: title_text.clone(),
+                            localized: config.localized_title.clone(),
                             ..default()
                         })
                         .id();
@@ -970,6 +1484,11 @@ impl UiFloatingPanelExt for UiBuilder<'_, Entity> {
                         .container(
                             FloatingPanel::close_button_container(),
                             |close_button_container| {
+                                floating_panel.minimize_button = close_button_container
+                                    .spawn(FloatingPanel::minimize_button(panel))
+                                    .style()
+                                    .render(config.minimizable)
+                                    .id();
                                 floating_panel.close_button = close_button_container
                                     .spawn(FloatingPanel::close_button(panel))
                                     .style()
@@ -979,7 +1498,7 @@ impl UiFloatingPanelExt for UiBuilder<'_, Entity> {
                         )
                         .id();
                 });
-            title_builder.style().render(config.title.is_some());
+            title_builder.style().render(config.has_title());
 
             if layout.droppable {
                 title_builder.insert(Droppable);
@@ -993,19 +1512,15 @@ impl UiFloatingPanelExt for UiBuilder<'_, Entity> {
                     FloatingPanelDragHandle { panel },
                 ))
                 .style()
-                .render(config.title.is_none())
+                .render(!config.has_title())
                 .id();
 
             floating_panel.content_view = container
                 .column(|column| {
                     column.scroll_view(restrict_to, |scroll_view| {
                         floating_panel.content_panel_container = scroll_view.id();
-                        floating_panel.content_panel = scroll_view
-                            .panel(
-                                config.title.clone().unwrap_or("Untitled".into()),
-                                spawn_children,
-                            )
-                            .id();
+                        floating_panel.content_panel =
+                            scroll_view.panel(title_text.clone(), spawn_children).id();
                     });
                 })
                 .style()
@@ -1018,6 +1533,9 @@ impl UiFloatingPanelExt for UiBuilder<'_, Entity> {
         if config.folded {
             frame.insert(PseudoStates::from(vec![PseudoState::Folded]));
         }
+        if config.minimized {
+            frame.style().render(false);
+        }
 
         frame.insert((config, floating_panel));
 
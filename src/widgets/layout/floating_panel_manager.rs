@@ -0,0 +1,482 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use sickle_macros::UiContext;
+use sickle_ui_scaffold::{prelude::*, ui_commands::RefreshThemeExt};
+
+use crate::widgets::WidgetLibraryUpdate;
+
+use super::{
+    container::UiContainerExt,
+    floating_panel::{
+        FloatingPanel, FloatingPanelConfig, FloatingPanelUpdate, MIN_FLOATING_PANEL_Z_INDEX,
+    },
+    label::{LabelConfig, UiLabelExt},
+};
+
+const CASCADE_ORIGIN: Vec2 = Vec2 { x: 20., y: 20. };
+const CASCADE_STEP: Vec2 = Vec2 { x: 30., y: 30. };
+
+pub struct FloatingPanelManagerPlugin;
+
+impl Plugin for FloatingPanelManagerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FloatingPanelManager>()
+            .add_plugins((
+                ComponentThemePlugin::<FloatingPanelTray>::default(),
+                ComponentThemePlugin::<FloatingPanelTrayItem>::default(),
+            ))
+            .add_systems(
+                Update,
+                (
+                    process_tray_item_pressed,
+                    process_floating_panel_manager_actions,
+                )
+                    .chain()
+                    .after(DroppableUpdate)
+                    .before(FloatingPanelUpdate),
+            )
+            .add_systems(
+                Update,
+                (update_floating_panel_manager, update_floating_panel_trays)
+                    .chain()
+                    .after(FloatingPanelUpdate)
+                    .before(WidgetLibraryUpdate),
+            );
+    }
+}
+
+fn process_tray_item_pressed(
+    q_items: Query<(&FloatingPanelTrayItem, &FluxInteraction), Changed<FluxInteraction>>,
+    mut r_manager: ResMut<FloatingPanelManager>,
+) {
+    for (item, interaction) in &q_items {
+        if *interaction == FluxInteraction::Released {
+            r_manager.restore(item.panel);
+        }
+    }
+}
+
+fn process_floating_panel_manager_actions(
+    mut r_manager: ResMut<FloatingPanelManager>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_trays: Query<(&Node, &InheritedVisibility), With<FloatingPanelTray>>,
+    mut q_panels: Query<(Entity, &mut FloatingPanel, &mut FloatingPanelConfig)>,
+    mut commands: Commands,
+) {
+    if r_manager.pending.is_empty() {
+        return;
+    }
+
+    let actions = std::mem::take(&mut r_manager.bypass_change_detection().pending);
+    for action in actions {
+        match action {
+            FloatingPanelManagerAction::BringToFront(panel) => {
+                restore_panel(panel, &mut q_panels);
+                bring_panel_to_front(panel, &mut q_panels);
+            }
+            FloatingPanelManagerAction::Minimize(panel) => {
+                let Ok((_, _, mut config)) = q_panels.get_mut(panel) else {
+                    warn!("Tried to minimize non-existent floating panel {:?}", panel);
+                    continue;
+                };
+
+                if !config.minimized {
+                    config.minimized = true;
+                }
+            }
+            FloatingPanelManagerAction::Restore(panel) => {
+                restore_panel(panel, &mut q_panels);
+                bring_panel_to_front(panel, &mut q_panels);
+            }
+            FloatingPanelManagerAction::Cascade => {
+                let panels = arrangeable_panels(&q_panels);
+                for (i, panel_id) in panels.iter().enumerate() {
+                    // Safe unwrap: entities come from the same query
+                    let (_, mut panel, config) = q_panels.get_mut(*panel_id).unwrap();
+                    panel.arrange(CASCADE_ORIGIN + (CASCADE_STEP * i as f32), None);
+                    apply_arrangement(*panel_id, &panel, config.folded, &mut commands);
+                }
+            }
+            FloatingPanelManagerAction::Tile => {
+                let Ok(window) = q_window.get_single() else {
+                    continue;
+                };
+
+                let panels = arrangeable_panels(&q_panels);
+                if panels.is_empty() {
+                    continue;
+                }
+
+                // Trays span the bottom of the window, panels are tiled above them
+                let tray_height = q_trays
+                    .iter()
+                    .filter(|(_, visibility)| visibility.get())
+                    .map(|(node, _)| node.size().y)
+                    .fold(0., f32::max);
+
+                let columns = (panels.len() as f32).sqrt().ceil() as usize;
+                let rows = panels.len().div_ceil(columns);
+                let cell_size = Vec2::new(
+                    window.width() / columns as f32,
+                    (window.height() - tray_height).max(0.) / rows as f32,
+                );
+
+                for (i, panel_id) in panels.iter().enumerate() {
+                    // Safe unwrap: entities come from the same query
+                    let (_, mut panel, mut config) = q_panels.get_mut(*panel_id).unwrap();
+                    if config.folded {
+                        config.folded = false;
+                    }
+
+                    let cell = Vec2::new((i % columns) as f32, (i / columns) as f32);
+                    panel.arrange(cell * cell_size, cell_size.into());
+                    apply_arrangement(*panel_id, &panel, false, &mut commands);
+                }
+            }
+        }
+    }
+}
+
+fn restore_panel(
+    panel: Entity,
+    q_panels: &mut Query<(Entity, &mut FloatingPanel, &mut FloatingPanelConfig)>,
+) {
+    let Ok((_, _, mut config)) = q_panels.get_mut(panel) else {
+        warn!("Tried to restore non-existent floating panel {:?}", panel);
+        return;
+    };
+
+    if config.minimized {
+        config.minimized = false;
+    }
+}
+
+fn bring_panel_to_front(
+    panel: Entity,
+    q_panels: &mut Query<(Entity, &mut FloatingPanel, &mut FloatingPanelConfig)>,
+) {
+    let mut panel_indices: Vec<(Entity, Option<usize>)> = q_panels
+        .iter()
+        .filter(|(entity, _, _)| *entity != panel)
+        .map(|(entity, panel, _)| (entity, panel.z_index()))
+        .collect();
+    panel_indices.sort_by_key(|(_, z_index)| *z_index);
+    panel_indices.push((panel, None));
+
+    for (i, (entity, _)) in panel_indices.iter().enumerate() {
+        if let Ok((_, mut panel, _)) = q_panels.get_mut(*entity) {
+            panel.set_z_index(MIN_FLOATING_PANEL_Z_INDEX + i + 1);
+        }
+    }
+}
+
+/// Visible panels that are not being dragged (i.e. popped out tabs), back to front
+fn arrangeable_panels(
+    q_panels: &Query<(Entity, &mut FloatingPanel, &mut FloatingPanelConfig)>,
+) -> Vec<Entity> {
+    let mut panels: Vec<(Entity, Option<usize>)> = q_panels
+        .iter()
+        .filter(|(_, panel, config)| !panel.priority && !config.minimized)
+        .map(|(entity, panel, _)| (entity, panel.z_index()))
+        .collect();
+    panels.sort_by_key(|(_, z_index)| *z_index);
+
+    panels.iter().map(|(entity, _)| *entity).collect()
+}
+
+fn apply_arrangement(entity: Entity, panel: &FloatingPanel, folded: bool, commands: &mut Commands) {
+    commands.style(entity).absolute_position(panel.position());
+    if !folded {
+        commands
+            .style(entity)
+            .width(Val::Px(panel.size().x))
+            .height(Val::Px(panel.size().y));
+    }
+
+    commands.entity(entity).refresh_theme::<FloatingPanel>();
+}
+
+fn update_floating_panel_manager(
+    q_panels: Query<(Entity, &FloatingPanel, &FloatingPanelConfig)>,
+    mut r_manager: ResMut<FloatingPanelManager>,
+) {
+    let mut panels: Vec<FloatingPanelEntry> = q_panels
+        .iter()
+        .map(|(entity, panel, config)| FloatingPanelEntry {
+            panel: entity,
+            title: config.title(),
+            z_index: panel.z_index(),
+            visibility: if config.minimized {
+                FloatingPanelVisibility::Minimized
+            } else if config.folded {
+                FloatingPanelVisibility::Folded
+            } else {
+                FloatingPanelVisibility::Visible
+            },
+        })
+        .collect();
+    panels.sort_by_key(|entry| entry.z_index);
+
+    if r_manager.panels != panels {
+        r_manager.panels = panels;
+    }
+}
+
+fn update_floating_panel_trays(
+    r_manager: Res<FloatingPanelManager>,
+    q_trays: Query<(Entity, Option<&Children>), With<FloatingPanelTray>>,
+    q_items: Query<&FloatingPanelTrayItem>,
+    mut commands: Commands,
+) {
+    if !r_manager.is_changed() {
+        return;
+    }
+
+    let minimized: Vec<&FloatingPanelEntry> = r_manager
+        .panels
+        .iter()
+        .filter(|entry| entry.visibility == FloatingPanelVisibility::Minimized)
+        .collect();
+
+    for (tray, children) in &q_trays {
+        let items: Vec<(Entity, Entity)> = match children {
+            Some(children) => children
+                .iter()
+                .filter_map(|child| q_items.get(*child).ok().map(|item| (*child, item.panel)))
+                .collect(),
+            None => Vec::new(),
+        };
+
+        let up_to_date = items.len() == minimized.len()
+            && items
+                .iter()
+                .zip(minimized.iter())
+                .all(|((_, panel), entry)| *panel == entry.panel);
+        if up_to_date {
+            continue;
+        }
+
+        for (item, _) in items {
+            commands.entity(item).despawn_recursive();
+        }
+
+        let mut builder = commands.ui_builder(tray);
+        for entry in minimized.iter() {
+            let title = entry.title.clone().unwrap_or("Untitled".into());
+            let mut tray_item = FloatingPanelTrayItem {
+                panel: entry.panel,
+                ..default()
+            };
+
+            let mut item = builder.container(FloatingPanelTrayItem::frame(&title), |item| {
+                tray_item.label = item.label(LabelConfig::from(title.clone())).id();
+            });
+            item.insert(tray_item);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum FloatingPanelVisibility {
+    #[default]
+    Visible,
+    Folded,
+    Minimized,
+}
+
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct FloatingPanelEntry {
+    pub panel: Entity,
+    pub title: Option<String>,
+    pub z_index: Option<usize>,
+    pub visibility: FloatingPanelVisibility,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FloatingPanelManagerAction {
+    BringToFront(Entity),
+    Minimize(Entity),
+    Restore(Entity),
+    Cascade,
+    Tile,
+}
+
+/// Keeps track of all floating panels and arranges them on request.
+///
+/// Requested actions are applied in the next `Update`, before [`FloatingPanelUpdate`].
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct FloatingPanelManager {
+    panels: Vec<FloatingPanelEntry>,
+    #[reflect(ignore)]
+    pending: Vec<FloatingPanelManagerAction>,
+}
+
+impl FloatingPanelManager {
+    /// All floating panels, ordered back to front
+    pub fn panels(&self) -> &Vec<FloatingPanelEntry> {
+        &self.panels
+    }
+
+    pub fn get(&self, panel: Entity) -> Option<&FloatingPanelEntry> {
+        self.panels.iter().find(|entry| entry.panel == panel)
+    }
+
+    pub fn minimized(&self) -> impl Iterator<Item = &FloatingPanelEntry> {
+        self.panels
+            .iter()
+            .filter(|entry| entry.visibility == FloatingPanelVisibility::Minimized)
+    }
+
+    /// Raises the panel above all other panels, restoring it if it was minimized
+    pub fn bring_to_front(&mut self, panel: Entity) {
+        self.pending
+            .push(FloatingPanelManagerAction::BringToFront(panel));
+    }
+
+    pub fn minimize(&mut self, panel: Entity) {
+        self.pending
+            .push(FloatingPanelManagerAction::Minimize(panel));
+    }
+
+    /// Restores a minimized panel and brings it to the front
+    pub fn restore(&mut self, panel: Entity) {
+        self.pending
+            .push(FloatingPanelManagerAction::Restore(panel));
+    }
+
+    /// Stacks visible panels diagonally from the top left corner, keeping their z-order and size
+    pub fn cascade(&mut self) {
+        self.pending.push(FloatingPanelManagerAction::Cascade);
+    }
+
+    /// Unfolds and resizes visible panels to fill the window above the tray in a grid
+    pub fn tile(&mut self) {
+        self.pending.push(FloatingPanelManagerAction::Tile);
+    }
+}
+
+#[derive(Component, Clone, Debug, Default, Reflect, UiContext)]
+#[reflect(Component)]
+pub struct FloatingPanelTray;
+
+impl DefaultTheme for FloatingPanelTray {
+    fn default_theme() -> Option<Theme<FloatingPanelTray>> {
+        FloatingPanelTray::theme().into()
+    }
+}
+
+impl FloatingPanelTray {
+    pub fn theme() -> Theme<FloatingPanelTray> {
+        let base_theme = PseudoTheme::deferred(None, FloatingPanelTray::primary_style);
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+
+        style_builder
+            .width(Val::Percent(100.))
+            .min_height(Val::Px(theme_spacing.areas.small))
+            .align_items(AlignItems::Center)
+            .padding(UiRect::horizontal(Val::Px(theme_spacing.gaps.small)))
+            .border(UiRect::top(Val::Px(theme_spacing.borders.extra_small)))
+            .border_color(colors.accent(Accent::Shadow))
+            .background_color(colors.container(Container::SurfaceMid));
+    }
+
+    fn frame() -> impl Bundle {
+        (
+            Name::new("Floating Panel Tray"),
+            NodeBundle::default(),
+            LockedStyleAttributes::lock(LockableStyleAttribute::FlexDirection),
+        )
+    }
+}
+
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
+#[reflect(Component)]
+pub struct FloatingPanelTrayItem {
+    panel: Entity,
+    #[context("Label")]
+    label: Entity,
+}
+
+impl Default for FloatingPanelTrayItem {
+    fn default() -> Self {
+        Self {
+            panel: Entity::PLACEHOLDER,
+            label: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl DefaultTheme for FloatingPanelTrayItem {
+    fn default_theme() -> Option<Theme<FloatingPanelTrayItem>> {
+        FloatingPanelTrayItem::theme().into()
+    }
+}
+
+impl FloatingPanelTrayItem {
+    pub fn panel(&self) -> Entity {
+        self.panel
+    }
+
+    pub fn theme() -> Theme<FloatingPanelTrayItem> {
+        let base_theme = PseudoTheme::deferred(None, FloatingPanelTrayItem::primary_style);
+        Theme::new(vec![base_theme])
+    }
+
+    fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+
+        style_builder
+            .align_items(AlignItems::Center)
+            .margin(UiRect::all(Val::Px(theme_spacing.gaps.extra_small)))
+            .padding(UiRect::axes(
+                Val::Px(theme_spacing.gaps.medium),
+                Val::Px(theme_spacing.gaps.small),
+            ))
+            .border(UiRect::all(Val::Px(theme_spacing.borders.extra_small)))
+            .border_color(colors.accent(Accent::Shadow))
+            .animated()
+            .background_color(AnimatedVals {
+                idle: colors.surface(Surface::Surface),
+                hover: colors.surface(Surface::SurfaceVariant).into(),
+                ..default()
+            })
+            .copy_from(theme_data.interaction_animation);
+
+        style_builder
+            .switch_target(FloatingPanelTrayItem::LABEL)
+            .sized_font(
+                theme_data
+                    .text
+                    .get(FontStyle::Body, FontScale::Medium, FontType::Regular),
+            )
+            .font_color(colors.on(On::Surface));
+    }
+
+    fn frame(title: &str) -> impl Bundle {
+        (
+            Name::new(format!("Floating Panel Tray Item [{}]", title)),
+            ButtonBundle::default(),
+            TrackedInteraction::default(),
+        )
+    }
+}
+
+pub trait UiFloatingPanelTrayExt {
+    fn floating_panel_tray(&mut self) -> UiBuilder<'_, Entity>;
+}
+
+impl UiFloatingPanelTrayExt for UiBuilder<'_, Entity> {
+    /// A strip listing minimized floating panels. Pressing an entry restores its panel.
+    ///
+    /// Entries are kept in sync with the [`FloatingPanelManager`].
+    fn floating_panel_tray(&mut self) -> UiBuilder<'_, Entity> {
+        self.spawn((FloatingPanelTray::frame(), FloatingPanelTray))
+    }
+}
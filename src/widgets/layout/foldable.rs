@@ -1,8 +1,19 @@
-use bevy::{prelude::*, ui::FocusPolicy};
+use bevy::{
+    a11y::accesskit::{NodeBuilder, Role},
+    prelude::*,
+    ui::FocusPolicy,
+};
 
-use sickle_ui_scaffold::prelude::*;
+use sickle_macros::UiContext;
+use sickle_ui_scaffold::{
+    prelude::*,
+    ui_commands::{AccessibilityNodeOutdated, SetAccessibilityNodeExt},
+};
 
-use crate::widgets::{menus::menu_item::MenuItemUpdate, WidgetLibraryUpdate};
+use crate::{
+    localization::LocalizedText,
+    widgets::{menus::menu_item::MenuItemUpdate, WidgetLibraryUpdate},
+};
 
 use super::{
     container::UiContainerExt,
@@ -23,7 +34,11 @@ impl Plugin for FoldablePlugin {
         .add_plugins(ComponentThemePlugin::<Foldable>::default())
         .add_systems(
             Update,
-            (handle_foldable_button_press, update_foldable_container)
+            (
+                handle_foldable_button_press,
+                update_foldable_container,
+                update_foldable_accessibility,
+            )
                 .chain()
                 .in_set(FoldableUpdate),
         );
@@ -76,13 +91,32 @@ fn update_foldable_container(
     }
 }
 
-#[derive(Component, Clone, Debug, Reflect)]
+fn update_foldable_accessibility(
+    q_foldables: Query<(Entity, &Foldable), AccessibilityNodeOutdated<Foldable>>,
+    mut commands: Commands,
+) {
+    for (entity, foldable) in &q_foldables {
+        let mut node = NodeBuilder::new(Role::Button);
+        if !foldable.empty {
+            node.set_expanded(foldable.open);
+        }
+
+        commands
+            .entity(entity)
+            .set_accessibility_node(node, foldable.label);
+    }
+}
+
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct Foldable {
     pub open: bool,
     pub empty: bool,
+    #[context("ButtonIcon", const = BUTTON_ICON)]
     icon: Entity,
+    #[context("ButtonLabel", const = BUTTON_LABEL)]
     label: Entity,
+    #[context("Container")]
     container: Entity,
 }
 
@@ -98,29 +132,6 @@ impl Default for Foldable {
     }
 }
 
-impl UiContext for Foldable {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            Foldable::BUTTON_ICON => Ok(self.icon),
-            Foldable::BUTTON_LABEL => Ok(self.label),
-            Foldable::CONTAINER => Ok(self.container),
-            _ => Err(format!(
-                "{} doesn't exists for Foldable. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
-    }
-
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![
-            Foldable::BUTTON_ICON,
-            Foldable::BUTTON_LABEL,
-            Foldable::CONTAINER,
-        ]
-    }
-}
-
 impl DefaultTheme for Foldable {
     fn default_theme() -> Option<Theme<Foldable>> {
         Foldable::theme().into()
@@ -128,16 +139,26 @@ impl DefaultTheme for Foldable {
 }
 
 impl Foldable {
-    pub const BUTTON_ICON: &'static str = "ButtonIcon";
-    pub const BUTTON_LABEL: &'static str = "ButtonLabel";
-    pub const CONTAINER: &'static str = "Container";
-
     pub fn theme() -> Theme<Foldable> {
         let base_theme = PseudoTheme::deferred(None, Foldable::primary_style);
         let folded_theme = PseudoTheme::deferred(vec![PseudoState::Folded], Foldable::folded_style);
         let empty_theme = PseudoTheme::deferred(vec![PseudoState::Empty], Foldable::empty_style);
+        let folded_rtl_theme = PseudoTheme::deferred(
+            vec![PseudoState::Folded, PseudoState::RightToLeft],
+            Foldable::folded_rtl_style,
+        );
+        let empty_rtl_theme = PseudoTheme::deferred(
+            vec![PseudoState::Empty, PseudoState::RightToLeft],
+            Foldable::empty_rtl_style,
+        );
 
-        Theme::new(vec![base_theme, folded_theme, empty_theme])
+        Theme::new(vec![
+            base_theme,
+            folded_theme,
+            empty_theme,
+            folded_rtl_theme,
+            empty_rtl_theme,
+        ])
     }
 
     fn primary_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
@@ -219,10 +240,38 @@ impl Foldable {
             .visibility(Visibility::Hidden);
     }
 
+    fn folded_rtl_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+
+        style_builder.switch_target(Foldable::BUTTON_ICON).icon(
+            theme_data
+                .icons
+                .chevron_left
+                .with(colors.on(On::Surface), theme_spacing.icons.small),
+        );
+    }
+
+    fn empty_rtl_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+
+        style_builder.switch_target(Foldable::BUTTON_ICON).icon(
+            theme_data
+                .icons
+                .arrow_left
+                .with(colors.on(On::Surface), theme_spacing.icons.small),
+        );
+    }
+
     pub fn container(&self) -> Entity {
         self.container
     }
 
+    pub fn label(&self) -> Entity {
+        self.label
+    }
+
     fn button(name: String) -> impl Bundle {
         (
             Name::new(format!("Foldable [{}] - Button", name)),
@@ -248,14 +297,58 @@ pub trait UiFoldableExt {
         empty: bool,
         spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
     ) -> UiBuilder<Entity>;
+
+    fn localized_foldable(
+        &mut self,
+        name: LocalizedText,
+        open: bool,
+        empty: bool,
+        spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
+    ) -> UiBuilder<'_, Entity>;
+}
+
+fn spawn_foldable<'a>(
+    builder: &'a mut UiBuilder<'_, Entity>,
+    label: LabelConfig,
+    open: bool,
+    empty: bool,
+    spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
+) -> UiBuilder<'a, Entity> {
+    let name = label.label.clone();
+
+    let mut foldable = Foldable {
+        open,
+        empty,
+        ..default()
+    };
+
+    let button = builder
+        .container(Foldable::button(name.clone()), |button| {
+            foldable.icon = button.spawn(Foldable::button_icon()).id();
+            foldable.label = button.label(label).id();
+        })
+        .id();
+
+    foldable.container = builder.panel(name, spawn_children).id();
+    if !open {
+        builder.commands().style(foldable.container).hide();
+        builder
+            .commands()
+            .entity(button)
+            .add_pseudo_state(PseudoState::Folded);
+    }
+
+    builder.commands().entity(button).insert(foldable);
+    builder.commands().ui_builder(button)
 }
 
 impl UiFoldableExt for UiBuilder<'_, Entity> {
     /// A simple foldable panel.
-    /// 
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Folded` is used when the panel is folded
     /// - `PseudoState::Empty` is used when the folded panel is set to be empty. This is used for styling its icon
+    /// - `PseudoState::RightToLeft` mirrors the folded and empty icons
     fn foldable(
         &mut self,
         name: impl Into<String>,
@@ -263,35 +356,33 @@ impl UiFoldableExt for UiBuilder<'_, Entity> {
         empty: bool,
         spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
     ) -> UiBuilder<Entity> {
-        let name = name.into();
-
-        let mut foldable = Foldable {
-            open,
-            empty,
+        let label = LabelConfig {
+            label: name.into(),
             ..default()
         };
 
-        let button = self
-            .container(Foldable::button(name.clone()), |button| {
-                foldable.icon = button.spawn(Foldable::button_icon()).id();
-                foldable.label = button
-                    .label(LabelConfig {
-                        label: name.clone(),
-                        ..default()
-                    })
-                    .id();
-            })
-            .id();
-
-        foldable.container = self.panel(name, spawn_children).id();
-        if !open {
-            self.commands().style(foldable.container).hide();
-            self.commands()
-                .entity(button)
-                .add_pseudo_state(PseudoState::Folded);
-        }
+        spawn_foldable(self, label, open, empty, spawn_children)
+    }
 
-        self.commands().entity(button).insert(foldable);
-        self.commands().ui_builder(button)
+    /// A foldable panel with a name that follows the current locale.
+    ///
+    /// ### PseudoState usage
+    /// - `PseudoState::Folded` is used when the panel is folded
+    /// - `PseudoState::Empty` is used when the folded panel is set to be empty. This is used for styling its icon
+    /// - `PseudoState::RightToLeft` mirrors the folded and empty icons
+    fn localized_foldable(
+        &mut self,
+        name: LocalizedText,
+        open: bool,
+        empty: bool,
+        spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
+    ) -> UiBuilder<'_, Entity> {
+        spawn_foldable(
+            self,
+            LabelConfig::localized(name),
+            open,
+            empty,
+            spawn_children,
+        )
     }
 }
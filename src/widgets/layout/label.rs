@@ -6,6 +6,8 @@ use bevy::{
 
 use sickle_ui_scaffold::ui_builder::UiBuilder;
 
+use crate::localization::LocalizedText;
+
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 pub struct LabelConfig {
@@ -14,6 +16,8 @@ pub struct LabelConfig {
     pub margin: UiRect,
     pub wrap: FlexWrap,
     pub flex_grow: f32,
+    /// Overrides `label` with the translation of the message once the locale resolves it
+    pub localized: Option<LocalizedText>,
 }
 
 impl Default for LabelConfig {
@@ -24,10 +28,23 @@ impl Default for LabelConfig {
             margin: Default::default(),
             wrap: FlexWrap::NoWrap,
             flex_grow: 0.,
+            localized: None,
         }
     }
 }
 
+impl From<&str> for LabelConfig {
+    fn from(value: &str) -> Self {
+        LabelConfig::from(value)
+    }
+}
+
+impl From<String> for LabelConfig {
+    fn from(value: String) -> Self {
+        LabelConfig::from(value)
+    }
+}
+
 impl LabelConfig {
     pub fn from(label: impl Into<String>) -> LabelConfig {
         LabelConfig {
@@ -36,6 +53,14 @@ impl LabelConfig {
         }
     }
 
+    pub fn localized(text: LocalizedText) -> LabelConfig {
+        LabelConfig {
+            label: text.fallback(),
+            localized: text.into(),
+            ..default()
+        }
+    }
+
     fn text_style(&self) -> TextStyle {
         TextStyle {
             color: self.color,
@@ -71,12 +96,20 @@ impl LabelConfig {
 }
 
 pub trait UiLabelExt {
-    fn label(&mut self, config: LabelConfig) -> UiBuilder<Entity>;
+    /// A text label. Accepts a [`LabelConfig`], or the text itself.
+    fn label(&mut self, config: impl Into<LabelConfig>) -> UiBuilder<Entity>;
 }
 
 impl UiLabelExt for UiBuilder<'_, Entity> {
-    fn label(&mut self, config: LabelConfig) -> UiBuilder<Entity> {
-        self.spawn((config.frame(), Label))
+    fn label(&mut self, config: impl Into<LabelConfig>) -> UiBuilder<Entity> {
+        let config: LabelConfig = config.into();
+        let localized = config.localized.clone();
+        let mut label = self.spawn((config.frame(), Label));
+        if let Some(localized) = localized {
+            label.insert(localized);
+        }
+
+        label
     }
 }
 
@@ -105,11 +138,15 @@ impl EntityCommand for UpdateLabelText {
         };
 
         text.sections = vec![TextSection::new(self.text, style)];
+
+        // A plain text replaces the localized message, otherwise a locale change would restore it
+        world.entity_mut(entity).remove::<LocalizedText>();
     }
 }
 
 pub trait SetLabelTextExt {
     fn set_label_text(&mut self, text: impl Into<String>) -> &mut Self;
+    fn set_label_localized_text(&mut self, text: LocalizedText) -> &mut Self;
 }
 
 impl SetLabelTextExt for EntityCommands<'_> {
@@ -118,4 +155,10 @@ impl SetLabelTextExt for EntityCommands<'_> {
 
         self
     }
+
+    fn set_label_localized_text(&mut self, text: LocalizedText) -> &mut Self {
+        self.insert(text);
+
+        self
+    }
 }
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use bevy::ui::RelativeCursorPosition;
 use bevy_reflect::Reflect;
 
+use sickle_macros::UiContext;
 use sickle_ui_scaffold::{prelude::*, ui_commands::SetCursorExt};
 
 use super::container::UiContainerExt;
@@ -163,16 +164,24 @@ impl ResizeHandle {
     }
 }
 
-#[derive(Component, Clone, Debug, Reflect)]
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct ResizeHandles {
+    #[context("HandleNorth")]
     pub handle_north: Entity,
+    #[context("HandleNorthEast")]
     pub handle_north_east: Entity,
+    #[context("HandleEast")]
     pub handle_east: Entity,
+    #[context("HandleSouthEast")]
     pub handle_south_east: Entity,
+    #[context("HandleSouth")]
     pub handle_south: Entity,
+    #[context("HandleSouthWest")]
     pub handle_south_west: Entity,
+    #[context("HandleWest")]
     pub handle_west: Entity,
+    #[context("HandleNorthWest")]
     pub handle_north_west: Entity,
 }
 
@@ -191,39 +200,6 @@ impl Default for ResizeHandles {
     }
 }
 
-impl UiContext for ResizeHandles {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            ResizeHandles::HANDLE_NORTH => Ok(self.handle_north),
-            ResizeHandles::HANDLE_NORTH_EAST => Ok(self.handle_north_east),
-            ResizeHandles::HANDLE_EAST => Ok(self.handle_east),
-            ResizeHandles::HANDLE_SOUTH_EAST => Ok(self.handle_south_east),
-            ResizeHandles::HANDLE_SOUTH => Ok(self.handle_south),
-            ResizeHandles::HANDLE_SOUTH_WEST => Ok(self.handle_south_west),
-            ResizeHandles::HANDLE_WEST => Ok(self.handle_west),
-            ResizeHandles::HANDLE_NORTH_WEST => Ok(self.handle_north_west),
-            _ => Err(format!(
-                "{} doesn't exists for ResizeHandles. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
-    }
-
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![
-            ResizeHandles::HANDLE_NORTH,
-            ResizeHandles::HANDLE_NORTH_EAST,
-            ResizeHandles::HANDLE_EAST,
-            ResizeHandles::HANDLE_SOUTH_EAST,
-            ResizeHandles::HANDLE_SOUTH,
-            ResizeHandles::HANDLE_SOUTH_WEST,
-            ResizeHandles::HANDLE_WEST,
-            ResizeHandles::HANDLE_NORTH_WEST,
-        ]
-    }
-}
-
 impl DefaultTheme for ResizeHandles {
     fn default_theme() -> Option<Theme<ResizeHandles>> {
         ResizeHandles::theme().into()
@@ -231,15 +207,6 @@ impl DefaultTheme for ResizeHandles {
 }
 
 impl ResizeHandles {
-    pub const HANDLE_NORTH: &'static str = "HandleNorth";
-    pub const HANDLE_NORTH_EAST: &'static str = "HandleNorthEast";
-    pub const HANDLE_EAST: &'static str = "HandleEast";
-    pub const HANDLE_SOUTH_EAST: &'static str = "HandleSouthEast";
-    pub const HANDLE_SOUTH: &'static str = "HandleSouth";
-    pub const HANDLE_SOUTH_WEST: &'static str = "HandleSouthWest";
-    pub const HANDLE_WEST: &'static str = "HandleWest";
-    pub const HANDLE_NORTH_WEST: &'static str = "HandleNorthWest";
-
     pub fn theme() -> Theme<ResizeHandles> {
         let base_theme = PseudoTheme::deferred_world(None, ResizeHandles::primary_style);
         let theme_north = PseudoTheme::deferred(
@@ -1,19 +1,33 @@
 use bevy::{
+    ecs::system::EntityCommands,
     input::mouse::MouseScrollUnit,
     prelude::*,
     ui::{FocusPolicy, RelativeCursorPosition},
 };
 
+use sickle_math::ease::{Ease, ValueEasing};
+use sickle_macros::UiContext;
 use sickle_ui_scaffold::prelude::*;
 
 use super::container::UiContainerExt;
 
+const AUTO_SCROLL_EDGE_SIZE: f32 = 30.;
+const AUTO_SCROLL_SPEED: f32 = 600.;
+const SMOOTH_SCROLL_DURATION: f32 = 0.15;
+const OVERSCROLL_BOUNCE_DURATION: f32 = 0.3;
+const OVERSCROLL_LIMIT: f32 = 100.;
+const OVERSCROLL_RESISTANCE: f32 = 0.4;
+const KINETIC_DECELERATION: f32 = 0.05;
+const KINETIC_MIN_VELOCITY: f32 = 10.;
+const SCROLL_INTO_VIEW_MAX_WAIT: u32 = 60;
+
 // TODO: implement click-to-scroll
 pub struct ScrollViewPlugin;
 
 impl Plugin for ScrollViewPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(ComponentThemePlugin::<ScrollView>::default())
+            .add_event::<ScrollViewOffsetChanged>()
             .add_systems(
                 Update,
                 (
@@ -21,6 +35,10 @@ impl Plugin for ScrollViewPlugin {
                     update_scroll_view_on_content_change,
                     update_scroll_view_on_scroll.after(ScrollableUpdate),
                     update_scroll_view_on_drag.after(DraggableUpdate),
+                    update_scroll_view_on_content_drag.after(DraggableUpdate),
+                    update_scroll_view_on_drag_hover,
+                    update_scroll_into_view_requests,
+                    update_scroll_view_motion,
                     update_scroll_view_offset,
                     update_scroll_view_layout.in_set(ScrollViewLayoutUpdate),
                 )
@@ -59,15 +77,18 @@ fn update_scroll_view_on_content_change(
     }
 }
 
+/// The viewport or a scroll bar handle of a scroll view, with its scroll state
+type ScrollViewScrollable = (
+    AnyOf<(&'static ScrollViewViewport, &'static ScrollBarHandle)>,
+    &'static Scrollable,
+);
+
 fn update_scroll_view_on_scroll(
-    q_scrollables: Query<
-        (AnyOf<(&ScrollViewViewport, &ScrollBarHandle)>, &Scrollable),
-        Changed<Scrollable>,
-    >,
+    q_scrollables: Query<ScrollViewScrollable, Changed<Scrollable>>,
     mut q_scroll_view: Query<&mut ScrollView>,
 ) {
     for ((viewport, handle), scrollable) in &q_scrollables {
-        let Some((axis, diff, unit)) = scrollable.last_change() else {
+        let Some((delta, unit)) = scrollable.last_delta() else {
             continue;
         };
 
@@ -87,15 +108,12 @@ fn update_scroll_view_on_scroll(
             continue;
         }
 
-        let offset = match axis {
-            ScrollAxis::Horizontal => Vec2 { x: diff, y: 0. },
-            ScrollAxis::Vertical => Vec2 { x: 0., y: diff },
-        };
         let diff = match unit {
-            MouseScrollUnit::Line => offset * 20.,
-            MouseScrollUnit::Pixel => offset,
+            MouseScrollUnit::Line => delta * Scrollable::LINE_SIZE,
+            MouseScrollUnit::Pixel => delta,
         };
-        scroll_view.scroll_offset = scroll_view.scroll_offset + diff;
+
+        scroll_view.scroll_by(diff);
     }
 }
 
@@ -165,11 +183,317 @@ fn update_scroll_view_on_drag(
     }
 }
 
+fn update_scroll_view_on_content_drag(
+    q_draggable: Query<(&Draggable, &ScrollViewViewport), Changed<Draggable>>,
+    mut q_scroll_view: Query<&mut ScrollView>,
+    time: Res<Time>,
+) {
+    for (draggable, viewport) in &q_draggable {
+        // Content is only panned by touch, mouse drags are left to the content
+        let DragSource::Touch(_) = draggable.source else {
+            continue;
+        };
+        let Ok(mut scroll_view) = q_scroll_view.get_mut(viewport.scroll_view) else {
+            continue;
+        };
+        if scroll_view.disabled {
+            continue;
+        }
+
+        match draggable.state {
+            DragState::DragStart | DragState::Dragging => {
+                let Some(diff) = draggable.diff else {
+                    continue;
+                };
+
+                let mut diff = -diff;
+                if scroll_view.overflow.x <= 0. {
+                    diff.x = 0.;
+                }
+                if scroll_view.overflow.y <= 0. {
+                    diff.y = 0.;
+                }
+
+                if scroll_view.overscroll_bounce {
+                    let min = Vec2::ZERO;
+                    let max = scroll_view.overflow.max(Vec2::ZERO);
+                    let offset = scroll_view.scroll_offset;
+                    if offset.x < min.x || offset.x > max.x {
+                        diff.x *= OVERSCROLL_RESISTANCE;
+                    }
+                    if offset.y < min.y || offset.y > max.y {
+                        diff.y *= OVERSCROLL_RESISTANCE;
+                    }
+                }
+
+                let delta_seconds = time.delta_seconds();
+                if delta_seconds > 0. {
+                    scroll_view.velocity = scroll_view.velocity.lerp(diff / delta_seconds, 0.5);
+                }
+
+                scroll_view.animation = None;
+                scroll_view.touch_dragging = true;
+                scroll_view.scroll_offset += diff;
+            }
+            DragState::DragEnd => {
+                scroll_view.touch_dragging = false;
+                if !scroll_view.kinetic_scroll {
+                    scroll_view.velocity = Vec2::ZERO;
+                }
+            }
+            // A new touch stops the momentum of the previous drag
+            DragState::MaybeDragged | DragState::DragCanceled => {
+                scroll_view.touch_dragging = false;
+                scroll_view.velocity = Vec2::ZERO;
+            }
+            // Follows the drag end, the momentum is kept
+            DragState::Inactive => {
+                scroll_view.touch_dragging = false;
+            }
+        }
+    }
+}
+
+fn update_scroll_view_motion(mut q_scroll_views: Query<&mut ScrollView>, time: Res<Time>) {
+    let delta_seconds = time.delta_seconds();
+
+    for mut scroll_view in &mut q_scroll_views {
+        if scroll_view.disabled || scroll_view.touch_dragging {
+            continue;
+        }
+
+        if let Some(mut animation) = scroll_view.animation {
+            animation.elapsed += delta_seconds;
+            let t = (animation.elapsed / animation.duration).clamp(0., 1.);
+            let offset = animation.from.lerp(animation.to, t.ease(Ease::OutCubic));
+
+            scroll_view.scroll_offset = offset;
+            scroll_view.animation = match t < 1. {
+                true => animation.into(),
+                false => None,
+            };
+
+            continue;
+        }
+
+        let min = Vec2::ZERO;
+        let max = scroll_view.overflow.max(Vec2::ZERO);
+
+        if scroll_view.velocity != Vec2::ZERO {
+            let mut offset = scroll_view.scroll_offset + (scroll_view.velocity * delta_seconds);
+            let mut velocity = scroll_view.velocity * KINETIC_DECELERATION.powf(delta_seconds);
+
+            if !scroll_view.overscroll_bounce {
+                if offset.x < min.x || offset.x > max.x {
+                    velocity.x = 0.;
+                }
+                if offset.y < min.y || offset.y > max.y {
+                    velocity.y = 0.;
+                }
+                offset = offset.clamp(min, max);
+            } else if offset.x < min.x || offset.x > max.x || offset.y < min.y || offset.y > max.y {
+                // Momentum running into the edge is absorbed quickly, then bounces back
+                velocity *= OVERSCROLL_RESISTANCE.powf(delta_seconds * 10.);
+            }
+
+            if velocity.length() < KINETIC_MIN_VELOCITY {
+                velocity = Vec2::ZERO;
+            }
+
+            scroll_view.velocity = velocity;
+            scroll_view.scroll_offset = offset;
+
+            continue;
+        }
+
+        if scroll_view.overscroll_bounce {
+            let offset = scroll_view.scroll_offset;
+            let clamped = scroll_view.clamp_offset(offset);
+            if offset != clamped {
+                scroll_view.animation = ScrollViewAnimation {
+                    from: offset,
+                    to: clamped,
+                    elapsed: 0.,
+                    duration: OVERSCROLL_BOUNCE_DURATION,
+                }
+                .into();
+            }
+        }
+    }
+}
+
+fn update_scroll_into_view_requests(
+    mut q_requests: Query<(Entity, &mut ScrollIntoViewRequest)>,
+    q_parents: Query<&Parent>,
+    q_node: Query<(&Node, &GlobalTransform)>,
+    mut q_scroll_views: Query<&mut ScrollView>,
+    mut commands: Commands,
+) {
+    for (entity, mut request) in &mut q_requests {
+        let Ok((node, transform)) = q_node.get(entity) else {
+            commands.entity(entity).remove::<ScrollIntoViewRequest>();
+            continue;
+        };
+
+        let scroll_views: Vec<Entity> = q_parents
+            .iter_ancestors(entity)
+            .filter(|ancestor| q_scroll_views.contains(*ancestor))
+            .collect();
+
+        if scroll_views.is_empty() {
+            commands.entity(entity).remove::<ScrollIntoViewRequest>();
+            continue;
+        }
+
+        // Wait for layout and for scroll views that are temporarily disabled, i.e. animating in
+        let pending = node.size() == Vec2::ZERO
+            || scroll_views.iter().any(|scroll_view| {
+                q_scroll_views
+                    .get(*scroll_view)
+                    .map_or(true, |scroll_view| scroll_view.disabled)
+            });
+        if pending {
+            // Hidden targets never get a size, give up instead of polling forever
+            request.waited += 1;
+            if request.waited >= SCROLL_INTO_VIEW_MAX_WAIT {
+                warn!(
+                    "Gave up scrolling {:?} into view, it wasn't laid out within {} frames",
+                    entity, SCROLL_INTO_VIEW_MAX_WAIT
+                );
+                commands.entity(entity).remove::<ScrollIntoViewRequest>();
+            }
+
+            continue;
+        }
+
+        // Innermost first, the target moves with each inner scroll view's change
+        let mut target = node.logical_rect(transform);
+        for scroll_view_id in scroll_views {
+            let Ok(mut scroll_view) = q_scroll_views.get_mut(scroll_view_id) else {
+                continue;
+            };
+            let Ok((viewport_node, viewport_transform)) = q_node.get(scroll_view.viewport) else {
+                continue;
+            };
+
+            let viewport = viewport_node.logical_rect(viewport_transform);
+            let reveal = |target_min: f32, target_max: f32, view_min: f32, view_max: f32| {
+                if target_min < view_min || target_max - target_min > view_max - view_min {
+                    target_min - view_min
+                } else if target_max > view_max {
+                    target_max - view_max
+                } else {
+                    0.
+                }
+            };
+
+            let mut diff = Vec2::ZERO;
+            if scroll_view.overflow.x > 0. {
+                diff.x = reveal(target.min.x, target.max.x, viewport.min.x, viewport.max.x);
+            }
+            if scroll_view.overflow.y > 0. {
+                diff.y = reveal(target.min.y, target.max.y, viewport.min.y, viewport.max.y);
+            }
+
+            if diff != Vec2::ZERO {
+                let offset = scroll_view.scroll_offset;
+                scroll_view.scroll_to(offset + diff);
+            }
+
+            target = Rect::from_corners(target.min - diff, target.max - diff);
+        }
+
+        commands.entity(entity).remove::<ScrollIntoViewRequest>();
+    }
+}
+
+/// Dragged entities that can be dropped or leave a ghost, except the scroll bars themselves
+type DraggedContent = (
+    Or<(With<Droppable>, With<DragGhost>)>,
+    Without<ScrollBarHandle>,
+);
+
+fn update_scroll_view_on_drag_hover(
+    q_draggable: Query<&Draggable, DraggedContent>,
+    mut q_scroll_views: Query<(Entity, &mut ScrollView, &Node, &GlobalTransform)>,
+    time: Res<Time>,
+) {
+    for draggable in &q_draggable {
+        if draggable.state != DragState::DragStart && draggable.state != DragState::Dragging {
+            continue;
+        }
+        let Some(position) = draggable.position else {
+            continue;
+        };
+
+        // Nested scroll views are contained by their parent, the smallest one is the innermost
+        let Some((hovered, rect)) = q_scroll_views
+            .iter()
+            .filter(|(_, scroll_view, _, _)| !scroll_view.disabled)
+            .map(|(entity, _, node, transform)| (entity, node.logical_rect(transform)))
+            .filter(|(_, rect)| rect.contains(position))
+            .min_by(|(_, a), (_, b)| {
+                a.size()
+                    .length_squared()
+                    .total_cmp(&b.size().length_squared())
+            })
+        else {
+            continue;
+        };
+
+        let Ok((_, mut scroll_view, _, _)) = q_scroll_views.get_mut(hovered) else {
+            continue;
+        };
+
+        let edge_size = Vec2::splat(AUTO_SCROLL_EDGE_SIZE).min(rect.size() / 2.);
+        let closeness = |min: f32, max: f32, pos: f32, edge: f32| {
+            if edge <= 0. {
+                0.
+            } else if pos < min + edge {
+                -(min + edge - pos) / edge
+            } else if pos > max - edge {
+                (pos - (max - edge)) / edge
+            } else {
+                0.
+            }
+        };
+
+        let mut diff = Vec2::ZERO;
+        if scroll_view.overflow.x > 0. && scroll_view.restricted_to != Some(ScrollAxis::Vertical) {
+            diff.x = closeness(rect.min.x, rect.max.x, position.x, edge_size.x);
+        }
+        if scroll_view.overflow.y > 0. && scroll_view.restricted_to != Some(ScrollAxis::Horizontal)
+        {
+            diff.y = closeness(rect.min.y, rect.max.y, position.y, edge_size.y);
+        }
+
+        if diff == Vec2::ZERO {
+            continue;
+        }
+
+        // Edge scrolling takes over from wheel animations and kinetic scrolling,
+        // which would otherwise overwrite the offset on the next frame
+        if scroll_view.animation.is_some() || scroll_view.velocity != Vec2::ZERO {
+            scroll_view.animation = None;
+            scroll_view.velocity = Vec2::ZERO;
+        }
+
+        let offset = scroll_view.scroll_offset + diff * AUTO_SCROLL_SPEED * time.delta_seconds();
+        let offset = scroll_view.clamp_offset(offset);
+        if offset != scroll_view.scroll_offset {
+            scroll_view.scroll_offset = offset;
+        }
+    }
+}
+
+type ScrollViewOrNodeChanged = Or<(Changed<ScrollView>, Changed<Node>)>;
+
 fn update_scroll_view_offset(
-    mut q_scroll_views: Query<(&Node, &mut ScrollView), Or<(Changed<ScrollView>, Changed<Node>)>>,
+    mut q_scroll_views: Query<(Entity, &Node, &mut ScrollView), ScrollViewOrNodeChanged>,
     q_node: Query<&Node>,
+    mut offset_changed: EventWriter<ScrollViewOffsetChanged>,
 ) {
-    for (container_node, mut scroll_view) in &mut q_scroll_views {
+    for (entity, container_node, mut scroll_view) in &mut q_scroll_views {
         let container_width = container_node.unrounded_size().x;
         let container_height = container_node.unrounded_size().y;
         if container_width == 0. || container_height == 0. {
@@ -181,18 +505,29 @@ fn update_scroll_view_offset(
         let content_width = content_node.unrounded_size().x;
         let content_height = content_node.unrounded_size().y;
 
+        let overscroll = match scroll_view.overscroll_bounce {
+            true => OVERSCROLL_LIMIT,
+            false => 0.,
+        };
+
         let overflow_x = content_width - container_width;
         let scroll_offset_x = if overflow_x > 0. {
-            scroll_view.scroll_offset.x.clamp(0., overflow_x)
+            scroll_view
+                .scroll_offset
+                .x
+                .clamp(-overscroll, overflow_x + overscroll)
         } else {
-            scroll_view.scroll_offset.x
+            0.
         };
 
         let overflow_y = content_height - container_height;
         let scroll_offset_y = if overflow_y > 0. {
-            scroll_view.scroll_offset.y.clamp(0., overflow_y)
+            scroll_view
+                .scroll_offset
+                .y
+                .clamp(-overscroll, overflow_y + overscroll)
         } else {
-            scroll_view.scroll_offset.y
+            0.
         };
 
         scroll_view.scroll_offset = Vec2 {
@@ -209,11 +544,25 @@ fn update_scroll_view_offset(
             x: (container_width / content_width).clamp(0., 1.),
             y: (container_height / content_height).clamp(0., 1.),
         };
+
+        scroll_view.laid_out = true;
+        if let Some(offset) = scroll_view.requested_offset.take() {
+            scroll_view.scroll_offset = scroll_view.clamp_offset(offset);
+        }
+
+        if scroll_view.reported_offset != scroll_view.scroll_offset {
+            offset_changed.send(ScrollViewOffsetChanged {
+                scroll_view: entity,
+                previous: scroll_view.reported_offset,
+                offset: scroll_view.scroll_offset,
+            });
+            scroll_view.reported_offset = scroll_view.scroll_offset;
+        }
     }
 }
 
 fn update_scroll_view_layout(
-    q_scroll_view: Query<(Entity, &ScrollView), Or<(Changed<ScrollView>, Changed<Node>)>>,
+    q_scroll_view: Query<(Entity, &ScrollView), ScrollViewOrNodeChanged>,
     q_node: Query<&Node>,
     mut commands: Commands,
 ) {
@@ -253,7 +602,7 @@ fn update_scroll_view_layout(
             let bar_width = (scroll_view.visible_ratio.x * bar_container_width)
                 .clamp(5., bar_container_width.max(5.));
             let remaining_space = bar_container_width - bar_width;
-            let bar_offset = (scroll_offset_x / overflow_x) * remaining_space;
+            let bar_offset = (scroll_offset_x / overflow_x).clamp(0., 1.) * remaining_space;
             commands
                 .style_unchecked(scroll_view.horizontal_scroll_bar_handle)
                 .width(Val::Px(bar_width))
@@ -282,7 +631,7 @@ fn update_scroll_view_layout(
             let bar_height = (scroll_view.visible_ratio.y * bar_container_height)
                 .clamp(5., bar_container_height.max(5.));
             let remaining_space = bar_container_height - bar_height;
-            let bar_offset = (scroll_offset_y / overflow_y) * remaining_space;
+            let bar_offset = (scroll_offset_y / overflow_y).clamp(0., 1.) * remaining_space;
             commands
                 .style_unchecked(scroll_view.vertical_scroll_bar_handle)
                 .height(Val::Px(bar_height))
@@ -360,20 +709,76 @@ impl Default for ScrollViewViewport {
     }
 }
 
-#[derive(Component, Clone, Debug, Reflect)]
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct ScrollView {
+    #[context("Viewport")]
     viewport: Entity,
+    #[context("ContentContainer")]
     content_container: Entity,
+    #[context("HorizontalScrollBar")]
     horizontal_scroll_bar: Entity,
+    #[context("HorizontalScrollHandle", const = HORIZONTAL_SCROLL_HANDLE)]
     horizontal_scroll_bar_handle: Entity,
+    #[context("VerticalScrollBar")]
     vertical_scroll_bar: Entity,
+    #[context("VerticalScrollHandle", const = VERTICAL_SCROLL_HANDLE)]
     vertical_scroll_bar_handle: Entity,
     scroll_offset: Vec2,
     overflow: Vec2,
     visible_ratio: Vec2,
     restricted_to: Option<ScrollAxis>,
+    animation: Option<ScrollViewAnimation>,
+    velocity: Vec2,
+    touch_dragging: bool,
+    reported_offset: Vec2,
+    /// Offset set by `scroll_to` before the first layout, applied once the overflow is known
+    requested_offset: Option<Vec2>,
+    laid_out: bool,
     pub disabled: bool,
+    /// Interpolates wheel scrolling instead of jumping to the new offset
+    pub smooth_scroll: bool,
+    /// Keeps scrolling with decaying momentum after a touch drag is released
+    pub kinetic_scroll: bool,
+    /// Allows touch and kinetic scrolling past the content edges, then springs back
+    pub overscroll_bounce: bool,
+}
+
+/// Sent when the scroll offset of a [`ScrollView`] changes, including animated and kinetic scrolling
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ScrollViewOffsetChanged {
+    pub scroll_view: Entity,
+    pub previous: Vec2,
+    pub offset: Vec2,
+}
+
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+struct ScrollIntoViewRequest {
+    /// Frames spent waiting for layout or disabled scroll views
+    waited: u32,
+}
+
+pub trait ScrollIntoViewExt {
+    /// Scrolls every enclosing [`ScrollView`] so that the entity's node becomes visible.
+    /// Applied once the node is laid out and the scroll views are enabled. Dropped if that
+    /// doesn't happen within 60 frames, i.e. when the node is not displayed.
+    fn scroll_into_view(&mut self) -> &mut Self;
+}
+
+impl ScrollIntoViewExt for EntityCommands<'_> {
+    fn scroll_into_view(&mut self) -> &mut Self {
+        self.insert(ScrollIntoViewRequest::default());
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Reflect)]
+struct ScrollViewAnimation {
+    from: Vec2,
+    to: Vec2,
+    elapsed: f32,
+    duration: f32,
 }
 
 impl Default for ScrollView {
@@ -390,39 +795,19 @@ impl Default for ScrollView {
             visible_ratio: Vec2::ZERO,
             disabled: false,
             restricted_to: None,
+            animation: None,
+            velocity: Vec2::ZERO,
+            touch_dragging: false,
+            reported_offset: Vec2::ZERO,
+            requested_offset: None,
+            laid_out: false,
+            smooth_scroll: true,
+            kinetic_scroll: true,
+            overscroll_bounce: false,
         }
     }
 }
 
-impl UiContext for ScrollView {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            ScrollView::VIEWPORT => Ok(self.viewport),
-            ScrollView::CONTENT_CONTAINER => Ok(self.content_container),
-            ScrollView::HORIZONTAL_SCROLL_BAR => Ok(self.horizontal_scroll_bar),
-            ScrollView::HORIZONTAL_SCROLL_HANDLE => Ok(self.horizontal_scroll_bar_handle),
-            ScrollView::VERTICAL_SCROLL_BAR => Ok(self.vertical_scroll_bar),
-            ScrollView::VERTICAL_SCROLL_HANDLE => Ok(self.vertical_scroll_bar_handle),
-            _ => Err(format!(
-                "{} doesn't exists for ScrollView. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
-    }
-
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![
-            ScrollView::VIEWPORT,
-            ScrollView::CONTENT_CONTAINER,
-            ScrollView::HORIZONTAL_SCROLL_BAR,
-            ScrollView::HORIZONTAL_SCROLL_HANDLE,
-            ScrollView::VERTICAL_SCROLL_BAR,
-            ScrollView::VERTICAL_SCROLL_HANDLE,
-        ]
-    }
-}
-
 impl DefaultTheme for ScrollView {
     fn default_theme() -> Option<Theme<ScrollView>> {
         ScrollView::theme().into()
@@ -430,17 +815,67 @@ impl DefaultTheme for ScrollView {
 }
 
 impl ScrollView {
-    pub const VIEWPORT: &'static str = "Viewport";
-    pub const CONTENT_CONTAINER: &'static str = "ContentContainer";
-    pub const HORIZONTAL_SCROLL_BAR: &'static str = "HorizontalScrollBar";
-    pub const HORIZONTAL_SCROLL_HANDLE: &'static str = "HorizontalScrollHandle";
-    pub const VERTICAL_SCROLL_BAR: &'static str = "VerticalScrollBar";
-    pub const VERTICAL_SCROLL_HANDLE: &'static str = "VerticalScrollHandle";
-
     pub fn viewport_id(&self) -> Entity {
         self.viewport
     }
 
+    pub fn scroll_offset(&self) -> Vec2 {
+        self.scroll_offset
+    }
+
+    pub fn overflow(&self) -> Vec2 {
+        self.overflow
+    }
+
+    /// Scrolls to the offset, clamped to the content. Animated if `smooth_scroll` is set.
+    /// Before the first layout the offset is kept and applied once the content size is known.
+    pub fn scroll_to(&mut self, offset: Vec2) {
+        if !self.laid_out {
+            self.requested_offset = offset.into();
+            return;
+        }
+
+        let to = self.clamp_offset(offset);
+
+        self.velocity = Vec2::ZERO;
+        if self.smooth_scroll {
+            self.animation = ScrollViewAnimation {
+                from: self.scroll_offset,
+                to,
+                elapsed: 0.,
+                duration: SMOOTH_SCROLL_DURATION,
+            }
+            .into();
+        } else {
+            self.animation = None;
+            self.scroll_offset = to;
+        }
+    }
+
+    /// Scrolls relative to the current offset, or to the target of an ongoing animation
+    pub fn scroll_by(&mut self, diff: Vec2) {
+        let target = match (self.requested_offset, self.animation) {
+            (Some(requested), _) => requested,
+            (None, Some(animation)) => animation.to,
+            (None, None) => self.scroll_offset,
+        };
+
+        self.scroll_to(target + diff);
+    }
+
+    /// Clamps the offset to the content. Axes that don't overflow or are excluded by
+    /// `restricted_to` can't be scrolled and are kept at zero.
+    fn clamp_offset(&self, offset: Vec2) -> Vec2 {
+        let mut max = self.overflow.max(Vec2::ZERO);
+        match self.restricted_to {
+            Some(ScrollAxis::Horizontal) => max.y = 0.,
+            Some(ScrollAxis::Vertical) => max.x = 0.,
+            None => (),
+        }
+
+        offset.clamp(Vec2::ZERO, max)
+    }
+
     pub fn theme() -> Theme<ScrollView> {
         let base_theme = PseudoTheme::deferred_context(None, ScrollView::primary_style);
         let disabled_theme =
@@ -609,6 +1044,9 @@ impl ScrollView {
                 ..default()
             },
             Interaction::default(),
+            TrackedInteraction::default(),
+            Draggable::default(),
+            RelativeCursorPosition::default(),
             Scrollable::default(),
             ScrollViewViewport { scroll_view },
             LockedStyleAttributes::from_vec(vec![
@@ -744,7 +1182,9 @@ pub trait UiScrollViewExt {
 impl UiScrollViewExt for UiBuilder<'_, Entity> {
     /// A simple scroll view. When the content overflows, scroll bars appear for the given direction.
     /// Can be restricted to scroll only on one axis.
-    /// 
+    /// Wheel scrolling is smoothed and touch drags keep their momentum by default, see the
+    /// `smooth_scroll`, `kinetic_scroll` and `overscroll_bounce` fields of [`ScrollView`].
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Disabled` is used when the view is disabled, preventing scroll bars from showing
     /// - `PseudoState::OverflowX` and `PseudoState::OverflowY` is used to indicate which axis the content
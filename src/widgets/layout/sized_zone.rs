@@ -1,5 +1,6 @@
 use bevy::{prelude::*, ui::UiSystem};
 
+use sickle_macros::UiContext;
 use sickle_ui_scaffold::{prelude::*, ui_commands::LogHierarchyExt};
 
 use super::{
@@ -571,13 +572,14 @@ pub struct SizedZoneConfig {
     pub min_size: f32,
 }
 
-#[derive(Component, Clone, Debug, Reflect)]
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct SizedZone {
     size_percent: f32,
     min_size: f32,
     children_size: f32,
     flex_direction: FlexDirection,
+    #[context("Label", no_clear)]
     resize_handles: Entity,
     top_handle: Entity,
     right_handle: Entity,
@@ -601,27 +603,6 @@ impl Default for SizedZone {
     }
 }
 
-impl UiContext for SizedZone {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            SizedZone::RESIZE_HANDLES => Ok(self.resize_handles),
-            _ => Err(format!(
-                "{} doesn't exists for SizedZone. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
-    }
-
-    fn cleared_contexts(&self) -> Vec<&'static str> {
-        vec![]
-    }
-
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![SizedZone::RESIZE_HANDLES]
-    }
-}
-
 impl DefaultTheme for SizedZone {
     fn default_theme() -> Option<Theme<SizedZone>> {
         SizedZone::theme().into()
@@ -629,7 +610,6 @@ impl DefaultTheme for SizedZone {
 }
 
 impl SizedZone {
-    pub const RESIZE_HANDLES: &'static str = "Label";
     pub const RESIZE_HANDLES_Z_INDEX: i32 = 200;
 
     pub fn direction(&self) -> FlexDirection {
@@ -1,14 +1,25 @@
-use bevy::{ecs::system::Command, prelude::*, ui::RelativeCursorPosition};
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode,
+    },
+    ecs::system::Command,
+    prelude::*,
+    ui::RelativeCursorPosition,
+};
 
 use sickle_macros::UiContext;
-use sickle_ui_scaffold::prelude::*;
-
-use crate::widgets::menus::{
-    context_menu::{
-        ContextMenu, ContextMenuGenerator, ContextMenuUpdate, GenerateContextMenu,
-        ReflectContextMenuGenerator,
+use sickle_ui_scaffold::{prelude::*, ui_commands::SetAccessibilityNodeExt};
+
+use crate::{
+    localization::LocalizedText,
+    widgets::menus::{
+        context_menu::{
+            ContextMenu, ContextMenuGenerator, ContextMenuUpdate, GenerateContextMenu,
+            ReflectContextMenuGenerator,
+        },
+        menu_item::{MenuItem, MenuItemConfig, MenuItemUpdate, UiMenuItemExt},
     },
-    menu_item::{MenuItem, MenuItemConfig, MenuItemUpdate, UiMenuItemExt},
 };
 
 use super::{
@@ -61,6 +72,7 @@ impl Plugin for TabContainerPlugin {
             (
                 update_tab_container_on_tab_press,
                 update_tab_container_on_change,
+                update_tab_container_accessibility,
                 update_sized_zone_resize_handles_on_tab_drag,
                 handle_tab_dragging,
             )
@@ -80,6 +92,7 @@ fn dock_panel_in_tab_container(
     >,
     q_floating_panel: Query<&FloatingPanel>,
     q_panel: Query<&Panel>,
+    q_localized: Query<&LocalizedText>,
     mut commands: Commands,
 ) {
     for (container_id, mut tab_container, dock_ref) in &mut q_docking_panels {
@@ -105,6 +118,11 @@ fn dock_panel_in_tab_container(
 
         let bar_id = tab_container.bar;
         let viewport_id = tab_container.viewport;
+        let localized_title = floating_panel
+            .get(FloatingPanel::TITLE)
+            .ok()
+            .and_then(|title| q_localized.get(title).ok())
+            .cloned();
 
         let mut tab = Tab {
             container: container_id,
@@ -121,6 +139,7 @@ fn dock_panel_in_tab_container(
                     tab.label = container
                         .label(LabelConfig {
                             label: panel.title(),
+                            localized: localized_title,
                             ..default()
                         })
                         .id();
@@ -142,6 +161,7 @@ fn popout_panel_from_tab(
         Added<PopoutPanelFromTabContainer>,
     >,
     q_panel: Query<&Panel>,
+    q_localized: Query<&LocalizedText>,
     q_parent: Query<&Parent>,
     q_ui_context_root: Query<&UiContextRoot>,
     mut q_tab_container: Query<&mut TabContainer>,
@@ -179,6 +199,7 @@ fn popout_panel_from_tab(
             continue;
         };
         let title = panel.title();
+        let localized_title = q_localized.get(tab.label).ok().cloned();
 
         let root_node = q_parent
             .iter_ancestors(tab_contaier_id)
@@ -192,6 +213,7 @@ fn popout_panel_from_tab(
             .floating_panel(
                 FloatingPanelConfig {
                     title: title.into(),
+                    localized_title,
                     ..default()
                 },
                 FloatingPanelLayout {
@@ -334,6 +356,50 @@ fn update_tab_container_on_change(
     }
 }
 
+fn update_tab_container_accessibility(
+    q_changed_containers: Query<Entity, Changed<TabContainer>>,
+    q_new_tab_nodes: Query<&Tab, Added<AccessibilityNode>>,
+    q_tab_containers: Query<&TabContainer>,
+    q_tab: Query<&Tab>,
+    q_children: Query<&Children>,
+    mut commands: Commands,
+) {
+    let mut containers: Vec<Entity> = q_changed_containers
+        .iter()
+        .chain(q_new_tab_nodes.iter().map(|tab| tab.container))
+        .collect();
+    containers.sort();
+    containers.dedup();
+
+    for container in containers {
+        let Ok(tab_container) = q_tab_containers.get(container) else {
+            continue;
+        };
+
+        commands
+            .entity(tab_container.bar)
+            .set_accessibility_node(NodeBuilder::new(Role::TabList), None);
+
+        let Ok(tabs) = q_children.get(tab_container.bar) else {
+            continue;
+        };
+
+        for (i, id) in tabs.iter().enumerate() {
+            let Ok(tab) = q_tab.get(*id) else {
+                continue;
+            };
+
+            let mut node = NodeBuilder::new(Role::Tab);
+            node.set_selected(i == tab_container.active);
+            commands.entity(*id).set_accessibility_node(node, tab.label);
+
+            commands
+                .entity(tab.panel)
+                .set_accessibility_node(NodeBuilder::new(Role::TabPanel), tab.label);
+        }
+    }
+}
+
 // TODO: Replace this when focus management is implemented
 fn update_sized_zone_resize_handles_on_tab_drag(
     q_accepted_types: Query<&Draggable, (With<Tab>, Changed<Draggable>)>,
@@ -609,12 +675,14 @@ impl Default for PopoutTabContextMenu {
     }
 }
 
-#[derive(Component, Clone, Debug, Reflect)]
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
 #[reflect(Component, ContextMenuGenerator)]
 pub struct Tab {
     container: Entity,
     bar: Entity,
+    #[context("Panel")]
     panel: Entity,
+    #[context("Label")]
     label: Entity,
     placeholder: Option<Entity>,
     original_index: Option<usize>,
@@ -658,24 +726,6 @@ impl ContextMenuGenerator for Tab {
     }
 }
 
-impl UiContext for Tab {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            Tab::LABEL => Ok(self.label),
-            Tab::PANEL => Ok(self.panel),
-            _ => Err(format!(
-                "{} doesn't exists for Tab. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
-    }
-
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![Tab::LABEL, Tab::PANEL]
-    }
-}
-
 impl DefaultTheme for Tab {
     fn default_theme() -> Option<Theme<Tab>> {
         Tab::theme().into()
@@ -683,9 +733,6 @@ impl DefaultTheme for Tab {
 }
 
 impl Tab {
-    pub const LABEL: &'static str = "Label";
-    pub const PANEL: &'static str = "Panel";
-
     pub fn theme() -> Theme<Tab> {
         let base_theme = PseudoTheme::deferred(None, Tab::primary_style);
         let selected_theme =
@@ -881,10 +928,11 @@ impl UiTabPlaceholderExt for UiBuilder<'_, Entity> {
     }
 }
 
-#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[derive(Component, Clone, Copy, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct TabContainer {
     active: usize,
+    #[context("TabBar", const = TAB_BAR)]
     bar: Entity,
     viewport: Entity,
     tab_count: usize,
@@ -907,26 +955,7 @@ impl DefaultTheme for TabContainer {
     }
 }
 
-impl UiContext for TabContainer {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            TabContainer::TAB_BAR => Ok(self.bar),
-            _ => Err(format!(
-                "{} doesn't exists for TabContainer. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
-    }
-
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![TabContainer::TAB_BAR]
-    }
-}
-
 impl TabContainer {
-    pub const TAB_BAR: &'static str = "TabBar";
-
     pub fn bar_id(&self) -> Entity {
         self.bar
     }
@@ -1043,16 +1072,67 @@ pub trait UiTabContainerSubExt {
         spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
     ) -> UiBuilder<(Entity, TabContainer)>;
 
+    fn add_localized_tab(
+        &mut self,
+        title: LocalizedText,
+        spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
+    ) -> UiBuilder<'_, (Entity, TabContainer)>;
+
     fn dock_panel(&mut self, floating_panel: Entity) -> UiBuilder<(Entity, TabContainer)>;
 }
 
+fn spawn_tab(
+    builder: &mut UiBuilder<'_, (Entity, TabContainer)>,
+    title: String,
+    localized_title: Option<LocalizedText>,
+    spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
+) {
+    let context = *builder.context();
+    let container_id = context.0;
+    let bar_id = context.1.bar;
+    let viewport_id = context.1.viewport;
+    let panel = builder
+        .commands()
+        .ui_builder(viewport_id)
+        .panel(title.clone(), spawn_children)
+        .id();
+
+    let mut tab = Tab {
+        container: container_id,
+        bar: bar_id,
+        panel,
+        ..default()
+    };
+
+    builder
+        .commands()
+        .ui_builder(bar_id)
+        .container(
+            Tab::frame(format!("Tab [{}]", title.clone())),
+            |container| {
+                tab.label = container
+                    .label(LabelConfig {
+                        label: title,
+                        localized: localized_title,
+                        ..default()
+                    })
+                    .id();
+            },
+        )
+        .insert(tab);
+
+    builder.commands().add(IncrementTabCount {
+        container: container_id,
+    });
+}
+
 impl UiTabContainerSubExt for UiBuilder<'_, (Entity, TabContainer)> {
     fn id(&self) -> Entity {
         self.context().0
     }
 
     /// Adds a tab to the TabContainer
-    /// 
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Selected` is added to the tab currently selected per TabContainer
     fn add_tab(
@@ -1061,40 +1141,20 @@ impl UiTabContainerSubExt for UiBuilder<'_, (Entity, TabContainer)> {
         spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
     ) -> UiBuilder<(Entity, TabContainer)> {
         let context = self.context().clone();
-        let container_id = context.0;
-        let bar_id = context.1.bar;
-        let viewport_id = context.1.viewport;
-        let panel = self
-            .commands()
-            .ui_builder(viewport_id)
-            .panel(title.clone(), spawn_children)
-            .id();
+        spawn_tab(self, title, None, spawn_children);
 
-        let mut tab = Tab {
-            container: container_id,
-            bar: bar_id,
-            panel,
-            ..default()
-        };
+        self.commands().ui_builder(context)
+    }
 
-        self.commands()
-            .ui_builder(bar_id)
-            .container(
-                Tab::frame(format!("Tab [{}]", title.clone())),
-                |container| {
-                    tab.label = container
-                        .label(LabelConfig {
-                            label: title,
-                            ..default()
-                        })
-                        .id();
-                },
-            )
-            .insert(tab);
+    /// Adds a tab with a title that follows the current locale
+    fn add_localized_tab(
+        &mut self,
+        title: LocalizedText,
+        spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
+    ) -> UiBuilder<'_, (Entity, TabContainer)> {
+        let context = *self.context();
+        spawn_tab(self, title.fallback(), title.into(), spawn_children);
 
-        self.commands().add(IncrementTabCount {
-            container: container_id,
-        });
         self.commands().ui_builder(context)
     }
 
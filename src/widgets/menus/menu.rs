@@ -1,5 +1,6 @@
 use bevy::{prelude::*, ui::FocusPolicy};
 
+use sickle_macros::UiContext;
 use sickle_ui_scaffold::prelude::*;
 
 use crate::widgets::layout::{
@@ -160,10 +161,12 @@ pub struct MenuConfig {
     pub alt_code: Option<KeyCode>,
 }
 
-#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[derive(Component, Clone, Copy, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct Menu {
+    #[context("Label")]
     label: Entity,
+    #[context("Container")]
     container: Entity,
     is_open: bool,
 }
@@ -178,24 +181,6 @@ impl Default for Menu {
     }
 }
 
-impl UiContext for Menu {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            Menu::LABEL => Ok(self.label),
-            Menu::CONTAINER => Ok(self.container),
-            _ => Err(format!(
-                "{} doesn't exists for Menu. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
-    }
-
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![Menu::LABEL, Menu::CONTAINER]
-    }
-}
-
 impl DefaultTheme for Menu {
     fn default_theme() -> Option<Theme<Menu>> {
         Menu::theme().into()
@@ -203,9 +188,6 @@ impl DefaultTheme for Menu {
 }
 
 impl Menu {
-    pub const CONTAINER: &'static str = "Container";
-    pub const LABEL: &'static str = "Label";
-
     pub fn theme() -> Theme<Menu> {
         let base_theme = PseudoTheme::deferred(None, Menu::primary_style);
         let open_theme = PseudoTheme::deferred(vec![PseudoState::Open], Menu::open_style);
@@ -1,12 +1,24 @@
-use bevy::{prelude::*, ui::FocusPolicy};
+use bevy::{
+    a11y::accesskit::{NodeBuilder, Role},
+    prelude::*,
+    ui::FocusPolicy,
+};
 
-use sickle_ui_scaffold::prelude::*;
+use sickle_macros::UiContext;
+use sickle_ui_scaffold::{
+    prelude::*,
+    ui_commands::{AccessibilityNodeOutdated, SetAccessibilityNodeExt},
+};
 
 use crate::{
     input_extension::ShortcutTextExt,
-    widgets::layout::{
-        container::UiContainerExt,
-        label::{LabelConfig, UiLabelExt},
+    localization::LocalizedText,
+    widgets::{
+        layout::{
+            container::UiContainerExt,
+            label::{LabelConfig, UiLabelExt},
+        },
+        WidgetEvent, WidgetEventPlugin,
     },
 };
 
@@ -29,13 +41,17 @@ impl Plugin for MenuItemPlugin {
                 .before(SubmenuUpdate)
                 .before(ContextMenuUpdate),
         )
-        .add_plugins(ComponentThemePlugin::<MenuItem>::default())
+        .add_plugins((
+            ComponentThemePlugin::<MenuItem>::default(),
+            WidgetEventPlugin::<MenuItemPressed>::default(),
+        ))
         .add_systems(
             Update,
             (
                 update_menu_item_on_change,
                 update_menu_item_on_pressed,
                 update_menu_item_on_shortcut_press,
+                update_menu_item_accessibility,
             )
                 .chain()
                 .in_set(MenuItemUpdate),
@@ -55,42 +71,85 @@ fn update_menu_item_on_change(mut q_menu_items: Query<&mut MenuItem, Changed<Men
 }
 
 fn update_menu_item_on_pressed(
-    mut q_menu_items: Query<(&mut MenuItem, &FluxInteraction), Changed<FluxInteraction>>,
+    mut q_menu_items: Query<(Entity, &mut MenuItem, &FluxInteraction), Changed<FluxInteraction>>,
+    mut events: EventWriter<MenuItemPressed>,
 ) {
-    for (mut item, interaction) in &mut q_menu_items {
+    for (entity, mut item, interaction) in &mut q_menu_items {
         if *interaction == FluxInteraction::Released {
             item.interacted = true;
+            events.send(MenuItemPressed {
+                entity,
+                shortcut: false,
+            });
         }
     }
 }
 
 fn update_menu_item_on_shortcut_press(
-    mut q_menu_items: Query<(&mut MenuItem, &Shortcut), Changed<Shortcut>>,
+    mut q_menu_items: Query<(Entity, &mut MenuItem, &Shortcut), Changed<Shortcut>>,
+    mut events: EventWriter<MenuItemPressed>,
 ) {
-    for (mut item, shortcut) in &mut q_menu_items {
+    for (entity, mut item, shortcut) in &mut q_menu_items {
         if shortcut.pressed() && !item.interacted {
             item.interacted = true;
+            events.send(MenuItemPressed {
+                entity,
+                shortcut: true,
+            });
         }
     }
 }
 
+fn update_menu_item_accessibility(
+    q_menu_items: Query<(Entity, &MenuItem), AccessibilityNodeOutdated<MenuItem>>,
+    mut commands: Commands,
+) {
+    for (entity, item) in &q_menu_items {
+        commands
+            .entity(entity)
+            .set_accessibility_node(NodeBuilder::new(Role::MenuItem), item.label);
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct MenuItemConfig {
     pub name: String,
+    /// Displayed instead of `name` once the locale resolves it
+    pub localized_name: Option<LocalizedText>,
     pub leading_icon: IconData,
     pub trailing_icon: IconData,
     pub alt_code: Option<KeyCode>,
     pub shortcut: Option<Vec<KeyCode>>,
 }
 
-#[derive(Component, Clone, Debug, Reflect)]
+/// Sent when a menu item is clicked, or its shortcut is pressed.
+/// Menu items can only be pressed by the user.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct MenuItemPressed {
+    pub entity: Entity,
+    /// Whether the item was pressed through its keyboard shortcut
+    pub shortcut: bool,
+}
+
+impl WidgetEvent for MenuItemPressed {
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct MenuItem {
     interacted: bool,
+    #[context("LeadingIcon", const = LEADING_ICON)]
     leading: Entity,
+    #[context("Label")]
     label: Entity,
+    #[context("ShortcutContainer")]
     shortcut_container: Entity,
+    #[context("Shortcut")]
     shortcut: Entity,
+    #[context("TrailingIcon", const = TRAILING_ICON)]
     trailing: Entity,
     leading_icon: IconData,
     trailing_icon: IconData,
@@ -119,40 +178,7 @@ impl DefaultTheme for MenuItem {
     }
 }
 
-impl UiContext for MenuItem {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            MenuItem::LEADING_ICON => Ok(self.leading),
-            MenuItem::LABEL => Ok(self.label),
-            MenuItem::SHORTCUT_CONTAINER => Ok(self.shortcut_container),
-            MenuItem::SHORTCUT => Ok(self.shortcut),
-            MenuItem::TRAILING_ICON => Ok(self.trailing),
-            _ => Err(format!(
-                "{} doesn't exists for MenuItem. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
-    }
-
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![
-            MenuItem::LEADING_ICON,
-            MenuItem::LABEL,
-            MenuItem::SHORTCUT_CONTAINER,
-            MenuItem::SHORTCUT,
-            MenuItem::TRAILING_ICON,
-        ]
-    }
-}
-
 impl MenuItem {
-    pub const LEADING_ICON: &'static str = "LeadingIcon";
-    pub const LABEL: &'static str = "Label";
-    pub const SHORTCUT_CONTAINER: &'static str = "ShortcutContainer";
-    pub const SHORTCUT: &'static str = "Shortcut";
-    pub const TRAILING_ICON: &'static str = "TrailingIcon";
-
     pub fn interacted(&self) -> bool {
         self.interacted
     }
@@ -340,6 +366,7 @@ impl MenuItem {
             menu_item.label = container
                 .label(LabelConfig {
                     label: config.name,
+                    localized: config.localized_name,
                     ..default()
                 })
                 .id();
@@ -369,6 +396,10 @@ impl MenuItem {
 }
 
 pub trait UiMenuItemExt {
+    /// A menu item in a menu, context menu, or submenu
+    ///
+    /// ### Events
+    /// - `MenuItemPressed`, when the item is clicked or its shortcut is pressed
     fn menu_item(&mut self, config: MenuItemConfig) -> UiBuilder<Entity>;
 }
 
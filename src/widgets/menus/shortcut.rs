@@ -1,4 +1,4 @@
-use bevy::prelude::*;
+use bevy::{input::InputSystem, prelude::*};
 
 use crate::input_extension::SymmetricKeysExt;
 
@@ -6,7 +6,7 @@ pub struct ShortcutPlugin;
 
 impl Plugin for ShortcutPlugin {
     fn build(&self, app: &mut App) {
-        app.configure_sets(PreUpdate, ShortcutPreUpdate)
+        app.configure_sets(PreUpdate, ShortcutPreUpdate.after(InputSystem))
             .add_systems(
                 PreUpdate,
                 (reset_pressed_shortcuts, update_shortcut_on_key_press)
@@ -1,7 +1,10 @@
 use bevy::{prelude::*, ui::FocusPolicy};
 
+use sickle_macros::UiContext;
 use sickle_ui_scaffold::prelude::*;
 
+use crate::localization::LocalizedText;
+
 use super::{
     context_menu::{ContextMenu, ContextMenuUpdate, UiContextMenuExt},
     menu::{Menu, MenuUpdate, UiMenuSubExt},
@@ -256,6 +259,7 @@ impl Default for SubmenuContainer {
 #[derive(Clone, Debug, Default)]
 pub struct SubmenuConfig {
     pub name: String,
+    pub localized_name: Option<LocalizedText>,
     pub alt_code: Option<KeyCode>,
     pub leading_icon: IconData,
 }
@@ -264,6 +268,7 @@ impl Into<MenuItemConfig> for SubmenuConfig {
     fn into(self) -> MenuItemConfig {
         MenuItemConfig {
             name: self.name,
+            localized_name: self.localized_name,
             alt_code: self.alt_code,
             leading_icon: self.leading_icon,
             ..default()
@@ -271,18 +276,24 @@ impl Into<MenuItemConfig> for SubmenuConfig {
     }
 }
 
-#[derive(Component, Clone, Debug, Reflect)]
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct Submenu {
     is_open: bool,
     is_focused: bool,
+    #[context("MenuContainer", const = MENU_CONTAINER)]
     container: Entity,
     external_container: Entity,
+    #[context("LeadingIcon", const = LEADING_ICON)]
     leading: Entity,
     leading_icon: IconData,
+    #[context("Label")]
     label: Entity,
+    #[context("ShortcutContainer")]
     shortcut_container: Entity,
+    #[context("Shortcut")]
     shortcut: Entity,
+    #[context("TrailingIcon", const = TRAILING_ICON)]
     trailing: Entity,
     alt_code: Option<KeyCode>,
 }
@@ -329,43 +340,13 @@ impl DefaultTheme for Submenu {
     }
 }
 
-impl UiContext for Submenu {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            MenuItem::LEADING_ICON => Ok(self.leading),
-            MenuItem::LABEL => Ok(self.label),
-            MenuItem::SHORTCUT_CONTAINER => Ok(self.shortcut_container),
-            MenuItem::SHORTCUT => Ok(self.shortcut),
-            MenuItem::TRAILING_ICON => Ok(self.trailing),
-            Submenu::MENU_CONTAINER => Ok(self.container),
-            _ => Err(format!(
-                "{} doesn't exists for MenuItem. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
-    }
-
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![
-            MenuItem::LEADING_ICON,
-            MenuItem::LABEL,
-            MenuItem::SHORTCUT_CONTAINER,
-            MenuItem::SHORTCUT,
-            MenuItem::TRAILING_ICON,
-            Submenu::MENU_CONTAINER,
-        ]
-    }
-}
-
 impl Submenu {
-    pub const MENU_CONTAINER: &'static str = "MenuContainer";
-
     pub fn theme() -> Theme<Submenu> {
         let base_theme = PseudoTheme::deferred_context(None, Submenu::primary_style);
         let open_theme = PseudoTheme::deferred_world(vec![PseudoState::Open], Submenu::open_style);
+        let rtl_theme = PseudoTheme::deferred(vec![PseudoState::RightToLeft], Submenu::rtl_style);
 
-        Theme::new(vec![base_theme, open_theme])
+        Theme::new(vec![base_theme, open_theme, rtl_theme])
     }
 
     fn primary_style(
@@ -403,13 +384,32 @@ impl Submenu {
         // Unsafe unwrap: if the menu item doesn't have a node, panic!
         let node = world.get::<Node>(entity).unwrap();
 
+        // Submenus open towards the end of the line
+        let (left, right) = match LayoutDirection::of(entity, world).is_right_to_left() {
+            true => (Val::Auto, Val::Px(node.size().x)),
+            false => (Val::Px(node.size().x), Val::Auto),
+        };
+
         style_builder
             .switch_target(Submenu::MENU_CONTAINER)
-            .left(Val::Px(node.size().x))
+            .left(left)
+            .right(right)
             .display(Display::Flex)
             .visibility(Visibility::Inherited);
     }
 
+    fn rtl_style(style_builder: &mut StyleBuilder, theme_data: &ThemeData) {
+        let theme_spacing = theme_data.spacing;
+        let colors = theme_data.colors();
+
+        style_builder.switch_target(MenuItem::TRAILING_ICON).icon(
+            theme_data
+                .icons
+                .arrow_left
+                .with(colors.on(On::SurfaceVariant), theme_spacing.icons.small),
+        );
+    }
+
     fn container_bundle(external_container: Entity) -> impl Bundle {
         (
             Name::new("Submenu Container"),
@@ -451,6 +451,7 @@ pub trait UiSubmenuExt {
     ///
     /// ### PseudoState usage
     /// - `PseudoState::Open` is used when the submenu panel is visible
+    /// - `PseudoState::RightToLeft` mirrors the trailing arrow and opens the panel to the left
     fn submenu(
         &mut self,
         config: SubmenuConfig,
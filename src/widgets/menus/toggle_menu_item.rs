@@ -1,7 +1,16 @@
 use bevy::prelude::*;
 
+use sickle_macros::UiContext;
 use sickle_ui_scaffold::prelude::*;
 
+use crate::{
+    localization::LocalizedText,
+    widgets::{
+        binding::{BindableWidget, WidgetBindingPlugin},
+        ValueChangeSource, WidgetEvent, WidgetEventPlugin,
+    },
+};
+
 use super::{
     context_menu::{ContextMenu, UiContextMenuExt},
     menu::{Menu, UiMenuSubExt},
@@ -15,12 +24,17 @@ pub struct ToggleMenuItemPlugin;
 impl Plugin for ToggleMenuItemPlugin {
     fn build(&self, app: &mut App) {
         app.configure_sets(Update, ToggleMenuItemUpdate.after(MenuItemUpdate))
-            .add_plugins(ComponentThemePlugin::<ToggleMenuItem>::default())
+            .add_plugins((
+                ComponentThemePlugin::<ToggleMenuItem>::default(),
+                WidgetEventPlugin::<ToggleMenuItemChanged>::default(),
+                WidgetBindingPlugin::<ToggleMenuItem>::default(),
+            ))
             .add_systems(
                 Update,
                 (
                     update_toggle_menu_item_value,
                     update_toggle_menu_item_on_shortcut_press,
+                    report_toggle_menu_item_change,
                     update_toggle_menu_checkmark,
                 )
                     .chain()
@@ -33,21 +47,43 @@ impl Plugin for ToggleMenuItemPlugin {
 pub struct ToggleMenuItemUpdate;
 
 fn update_toggle_menu_item_value(
-    mut q_menu_items: Query<(&mut ToggleMenuItem, &FluxInteraction), Changed<FluxInteraction>>,
+    mut q_menu_items: Query<
+        (Entity, &mut ToggleMenuItem, &FluxInteraction),
+        Changed<FluxInteraction>,
+    >,
+    mut events: EventWriter<ToggleMenuItemChanged>,
 ) {
-    for (mut toggle, interaction) in &mut q_menu_items {
+    for (entity, mut toggle, interaction) in &mut q_menu_items {
         if interaction.is_pressed() {
-            toggle.checked = !toggle.checked;
+            toggle.toggle_by_user(entity, &mut events);
         }
     }
 }
 
 fn update_toggle_menu_item_on_shortcut_press(
-    mut q_menu_items: Query<(&mut ToggleMenuItem, &Shortcut), Changed<Shortcut>>,
+    mut q_menu_items: Query<(Entity, &mut ToggleMenuItem, &Shortcut), Changed<Shortcut>>,
+    mut events: EventWriter<ToggleMenuItemChanged>,
 ) {
-    for (mut toggle, shortcut) in &mut q_menu_items {
+    for (entity, mut toggle, shortcut) in &mut q_menu_items {
         if shortcut.pressed() {
-            toggle.checked = !toggle.checked;
+            toggle.toggle_by_user(entity, &mut events);
+        }
+    }
+}
+
+fn report_toggle_menu_item_change(
+    mut q_menu_items: Query<(Entity, &mut ToggleMenuItem), Changed<ToggleMenuItem>>,
+    mut events: EventWriter<ToggleMenuItemChanged>,
+) {
+    for (entity, mut toggle) in &mut q_menu_items {
+        if toggle.checked != toggle.reported_checked {
+            toggle.bypass_change_detection().reported_checked = toggle.checked;
+
+            events.send(ToggleMenuItemChanged {
+                entity,
+                value: toggle.checked,
+                source: ValueChangeSource::Code,
+            });
         }
     }
 }
@@ -72,6 +108,7 @@ fn update_toggle_menu_checkmark(
 #[derive(Clone, Debug, Default)]
 pub struct ToggleMenuItemConfig {
     pub name: String,
+    pub localized_name: Option<LocalizedText>,
     pub trailing_icon: IconData,
     pub alt_code: Option<KeyCode>,
     pub shortcut: Option<Vec<KeyCode>>,
@@ -82,6 +119,7 @@ impl Into<MenuItemConfig> for ToggleMenuItemConfig {
     fn into(self) -> MenuItemConfig {
         MenuItemConfig {
             name: self.name,
+            localized_name: self.localized_name,
             alt_code: self.alt_code,
             shortcut: self.shortcut,
             trailing_icon: self.trailing_icon,
@@ -90,14 +128,34 @@ impl Into<MenuItemConfig> for ToggleMenuItemConfig {
     }
 }
 
-#[derive(Component, Clone, Debug, Reflect)]
+/// Sent when a toggle menu item is checked or unchecked
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ToggleMenuItemChanged {
+    pub entity: Entity,
+    pub value: bool,
+    pub source: ValueChangeSource,
+}
+
+impl WidgetEvent for ToggleMenuItemChanged {
+    fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
+#[derive(Component, Clone, Debug, Reflect, UiContext)]
 #[reflect(Component)]
 pub struct ToggleMenuItem {
     pub checked: bool,
+    reported_checked: bool,
+    #[context("LeadingIcon", const = LEADING_ICON)]
     leading: Entity,
+    #[context("Label")]
     label: Entity,
+    #[context("ShortcutContainer")]
     shortcut_container: Entity,
+    #[context("Shortcut")]
     shortcut: Entity,
+    #[context("TrailingIcon", const = TRAILING_ICON)]
     trailing: Entity,
     trailing_icon: IconData,
     alt_code: Option<KeyCode>,
@@ -107,6 +165,7 @@ impl Default for ToggleMenuItem {
     fn default() -> Self {
         Self {
             checked: Default::default(),
+            reported_checked: Default::default(),
             leading: Entity::PLACEHOLDER,
             label: Entity::PLACEHOLDER,
             shortcut_container: Entity::PLACEHOLDER,
@@ -122,6 +181,7 @@ impl Into<ToggleMenuItem> for MenuItem {
     fn into(self) -> ToggleMenuItem {
         ToggleMenuItem {
             checked: false,
+            reported_checked: false,
             label: self.label(),
             leading: self.leading(),
             shortcut_container: self.shortcut_container(),
@@ -133,40 +193,41 @@ impl Into<ToggleMenuItem> for MenuItem {
     }
 }
 
+impl BindableWidget for ToggleMenuItem {
+    type Value = bool;
+    type Changed = ToggleMenuItemChanged;
+
+    fn bound_value(&self) -> bool {
+        self.checked
+    }
+
+    fn set_bound_value(&mut self, value: bool) {
+        self.checked = value;
+    }
+
+    fn user_edit(event: &ToggleMenuItemChanged) -> Option<bool> {
+        (event.source == ValueChangeSource::User).then_some(event.value)
+    }
+}
+
 impl DefaultTheme for ToggleMenuItem {
     fn default_theme() -> Option<Theme<ToggleMenuItem>> {
         ToggleMenuItem::theme().into()
     }
 }
 
-impl UiContext for ToggleMenuItem {
-    fn get(&self, target: &str) -> Result<Entity, String> {
-        match target {
-            MenuItem::LEADING_ICON => Ok(self.leading),
-            MenuItem::LABEL => Ok(self.label),
-            MenuItem::SHORTCUT_CONTAINER => Ok(self.shortcut_container),
-            MenuItem::SHORTCUT => Ok(self.shortcut),
-            MenuItem::TRAILING_ICON => Ok(self.trailing),
-            _ => Err(format!(
-                "{} doesn't exists for MenuItem. Possible contexts: {:?}",
-                target,
-                self.contexts()
-            )),
-        }
-    }
+impl ToggleMenuItem {
+    fn toggle_by_user(&mut self, entity: Entity, events: &mut EventWriter<ToggleMenuItemChanged>) {
+        self.checked = !self.checked;
+        self.reported_checked = self.checked;
 
-    fn contexts(&self) -> Vec<&'static str> {
-        vec![
-            MenuItem::LEADING_ICON,
-            MenuItem::LABEL,
-            MenuItem::SHORTCUT_CONTAINER,
-            MenuItem::SHORTCUT,
-            MenuItem::TRAILING_ICON,
-        ]
+        events.send(ToggleMenuItemChanged {
+            entity,
+            value: self.checked,
+            source: ValueChangeSource::User,
+        });
     }
-}
 
-impl ToggleMenuItem {
     pub fn theme() -> Theme<ToggleMenuItem> {
         let base_theme = PseudoTheme::deferred_context(None, ToggleMenuItem::primary_style);
         let checked_theme =
@@ -198,9 +259,12 @@ impl ToggleMenuItem {
 
 pub trait UiToggleMenuItemExt {
     /// A toggle menu item in a menu, context menu, or submenu
-    /// 
+    ///
     /// ### PseudoState usage
     /// - `PseudoState::Checked` is used when the item is checked
+    ///
+    /// ### Events
+    /// - `ToggleMenuItemChanged`, when the item is checked or unchecked
     fn toggle_menu_item(&mut self, config: ToggleMenuItemConfig) -> UiBuilder<Entity>;
 }
 
@@ -210,6 +274,7 @@ impl UiToggleMenuItemExt for UiBuilder<'_, Entity> {
         let (id, menu_item) = MenuItem::scaffold(self, config.into());
         let toggle_item = ToggleMenuItem {
             checked,
+            reported_checked: checked,
             ..menu_item.into()
         };
 
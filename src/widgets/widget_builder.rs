@@ -0,0 +1,100 @@
+use std::{any::type_name, marker::PhantomData};
+
+use bevy::{prelude::*, utils::get_short_name};
+
+use sickle_ui_scaffold::{prelude::*, theme::ThemeRegistry};
+
+/// Registers the theme of widget `W` when the app is built.
+///
+/// Optional: [`UiWidgetBuilderExt::widget`] registers the theme when the first widget is spawned.
+pub struct WidgetPlugin<W: WidgetBuilder>(PhantomData<W>);
+
+impl<W: WidgetBuilder> Default for WidgetPlugin<W> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<W: WidgetBuilder> Plugin for WidgetPlugin<W> {
+    fn build(&self, app: &mut App) {
+        register_widget_theme::<W>(&mut app.world);
+    }
+}
+
+/// Registers the theme of `W` through its `ComponentThemePlugin`, unless it is already registered.
+///
+/// Panics if `W` has no default theme.
+fn register_widget_theme<W: WidgetBuilder>(world: &mut World) {
+    if world
+        .get_resource::<ThemeRegistry>()
+        .is_some_and(|registry| registry.contains::<W>())
+    {
+        return;
+    }
+
+    assert!(
+        W::default_theme().is_some(),
+        "widget {} has no default theme, implement DefaultTheme::default_theme for it",
+        get_short_name(type_name::<W>())
+    );
+
+    // The running schedule is taken out of `Schedules`, systems added to it now would be lost
+    if !world.resource::<Schedules>().contains(PostUpdate) {
+        warn!(
+            "widget {} was first spawned during PostUpdate and cannot register its theme, \
+            add WidgetPlugin::<{0}>::default() to the app",
+            get_short_name(type_name::<W>())
+        );
+        return;
+    }
+
+    ComponentThemePlugin::<W>::new().register(world);
+}
+
+/// Describes how a themed widget component is spawned by [`UiWidgetBuilderExt::widget`].
+///
+/// Deriving `Widget` on the component generates its `Ui*Ext` trait from this.
+pub trait WidgetBuilder: DefaultTheme {
+    /// The bundle of the widget's root node
+    fn frame() -> impl Bundle;
+
+    /// The name of the widget's root node, the type name by default
+    fn name(&self) -> Name {
+        Name::new(get_short_name(type_name::<Self>()))
+    }
+
+    /// Spawns the internal entities of the widget and stores them for its contexts.
+    /// Runs before the children passed to [`UiWidgetBuilderExt::widget`] are spawned.
+    fn build(&mut self, _builder: &mut UiBuilder<Entity>) {}
+}
+
+pub trait UiWidgetBuilderExt {
+    fn widget<W: WidgetBuilder>(
+        &mut self,
+        widget: W,
+        spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
+    ) -> UiBuilder<'_, Entity>;
+}
+
+impl UiWidgetBuilderExt for UiBuilder<'_, Entity> {
+    /// Spawns the widget's frame with its name and pseudo states, builds the widget,
+    /// then inserts it as a themed component.
+    ///
+    /// The widget's theme is registered on first use if [`WidgetPlugin<W>`] or its
+    /// `ComponentThemePlugin` was not added to the app. Panics if `W` has no default theme.
+    fn widget<W: WidgetBuilder>(
+        &mut self,
+        widget: W,
+        spawn_children: impl FnOnce(&mut UiBuilder<Entity>),
+    ) -> UiBuilder<'_, Entity> {
+        self.commands().add(register_widget_theme::<W>);
+
+        let mut widget = widget;
+        let mut builder = self.spawn((W::frame(), widget.name(), PseudoStates::new()));
+        widget.build(&mut builder);
+        spawn_children(&mut builder);
+        builder.insert(widget);
+
+        builder
+    }
+}
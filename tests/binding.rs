@@ -0,0 +1,143 @@
+use bevy::prelude::*;
+
+use sickle_ui::{
+    prelude::*, test_support::UiTestHarness, widgets::inputs::slider::SliderDragHandle,
+};
+
+#[derive(Component)]
+struct Speed {
+    value: f32,
+}
+
+#[derive(Resource)]
+struct Volume {
+    value: f32,
+}
+
+#[derive(Resource, Default)]
+struct SpeedChanges(usize);
+
+fn count_speed_changes(q_speed: Query<(), Changed<Speed>>, mut changes: ResMut<SpeedChanges>) {
+    changes.0 += q_speed.iter().count();
+}
+
+/// Spawns a 0-100 slider bound to the `Speed` of a new entity
+fn spawn_bound_slider(harness: &mut UiTestHarness, speed: f32) -> (Entity, Entity) {
+    harness
+        .app()
+        .init_resource::<SpeedChanges>()
+        .add_systems(Last, count_speed_changes);
+    let target = harness.world_mut().spawn(Speed { value: speed }).id();
+
+    let slider = harness.spawn_ui(|ui| {
+        let mut slider = Entity::PLACEHOLDER;
+        ui.column(|column| {
+            column.style().width(Val::Px(400.));
+            slider = column
+                .slider(SliderConfig::horizontal(None, 0., 100., 0., false))
+                .bind_to::<Speed>(target, |speed| &mut speed.value)
+                .id();
+        });
+
+        slider
+    });
+    harness.run_frames(2);
+
+    (slider, target)
+}
+
+fn slider_value(harness: &UiTestHarness, slider: Entity) -> f32 {
+    harness.world().get::<Slider>(slider).unwrap().value()
+}
+
+fn speed(harness: &UiTestHarness, target: Entity) -> f32 {
+    harness.world().get::<Speed>(target).unwrap().value
+}
+
+fn drag_slider_to_end(harness: &mut UiTestHarness, slider: Entity) {
+    let handle = harness
+        .world_mut()
+        .query::<(Entity, &SliderDragHandle)>()
+        .iter(harness.world())
+        .find(|(_, handle)| handle.slider == slider)
+        .map(|(entity, _)| entity)
+        .expect("slider has no drag handle");
+
+    let from = harness.center(handle);
+    harness.drag(from, from + Vec2::new(1000., 0.));
+    harness.run_frames(2);
+}
+
+#[test]
+fn source_changes_update_widget() {
+    let mut harness = UiTestHarness::new();
+    let (slider, target) = spawn_bound_slider(&mut harness, 25.);
+    assert_eq!(slider_value(&harness, slider), 25.);
+
+    harness.world_mut().get_mut::<Speed>(target).unwrap().value = 75.;
+    harness.run_frames(2);
+    assert_eq!(slider_value(&harness, slider), 75.);
+}
+
+#[test]
+fn user_edits_update_source() {
+    let mut harness = UiTestHarness::new();
+    let (slider, target) = spawn_bound_slider(&mut harness, 25.);
+
+    drag_slider_to_end(&mut harness, slider);
+    assert_eq!(slider_value(&harness, slider), 100.);
+    assert_eq!(speed(&harness, target), 100.);
+}
+
+#[test]
+fn user_edits_update_resource() {
+    let mut harness = UiTestHarness::new();
+    harness.world_mut().insert_resource(Volume { value: 10. });
+    let slider = harness.spawn_ui(|ui| {
+        let mut slider = Entity::PLACEHOLDER;
+        ui.column(|column| {
+            column.style().width(Val::Px(400.));
+            slider = column
+                .slider(SliderConfig::horizontal(None, 0., 100., 0., false))
+                .bind_to_resource::<Volume>(|volume| &mut volume.value)
+                .id();
+        });
+
+        slider
+    });
+    harness.run_frames(2);
+    assert_eq!(slider_value(&harness, slider), 10.);
+
+    drag_slider_to_end(&mut harness, slider);
+    assert_eq!(harness.world().resource::<Volume>().value, 100.);
+}
+
+#[test]
+fn clamped_source_value_is_not_written_back() {
+    let mut harness = UiTestHarness::new();
+    let (slider, target) = spawn_bound_slider(&mut harness, 150.);
+    let changes = harness.world().resource::<SpeedChanges>().0;
+
+    harness.run_frames(5);
+    assert_eq!(slider_value(&harness, slider), 100.);
+    assert_eq!(speed(&harness, target), 150.);
+    assert_eq!(harness.world().resource::<SpeedChanges>().0, changes);
+}
+
+#[test]
+fn code_side_value_change_is_not_written_back() {
+    let mut harness = UiTestHarness::new();
+    let (slider, target) = spawn_bound_slider(&mut harness, 50.);
+    let changes = harness.world().resource::<SpeedChanges>().0;
+
+    harness
+        .world_mut()
+        .get_mut::<Slider>(slider)
+        .unwrap()
+        .set_value(30.);
+    harness.run_frames(5);
+
+    assert!((slider_value(&harness, slider) - 30.).abs() < 0.001);
+    assert_eq!(speed(&harness, target), 50.);
+    assert_eq!(harness.world().resource::<SpeedChanges>().0, changes);
+}
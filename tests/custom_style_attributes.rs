@@ -0,0 +1,144 @@
+use bevy::{ecs::system::EntityCommand, prelude::*};
+
+use sickle_ui::{prelude::*, test_support::UiTestHarness};
+
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+struct Glow(f32);
+
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+struct Spin(f32);
+
+#[derive(StyleCommands)]
+#[custom_style_attributes]
+enum _EffectStyle {
+    #[animatable]
+    #[target_tupl(Glow)]
+    Glow { glow: f32 },
+    #[animatable]
+    #[target_fn(set_spin)]
+    Spin { spin: f32 },
+    #[static_style_only]
+    #[target_component(Transform, scale)]
+    Scale3d { scale_3d: Vec3 },
+}
+
+fn set_spin(entity: Entity, spin: f32, world: &mut World) {
+    world.entity_mut(entity).insert(Spin(spin));
+}
+
+fn effect_node() -> impl Bundle {
+    (
+        NodeBundle {
+            style: Style {
+                width: Val::Px(100.),
+                height: Val::Px(100.),
+                ..default()
+            },
+            ..default()
+        },
+        Interaction::default(),
+        TrackedInteraction::default(),
+        Glow::default(),
+    )
+}
+
+#[test]
+fn applies_static_custom_attributes() {
+    let mut harness = UiTestHarness::new();
+    let mut style_builder = StyleBuilder::new();
+    style_builder
+        .glow(0.5)
+        .spin(90.)
+        .scale_3d(Vec3::splat(2.))
+        .width(Val::Px(50.));
+
+    let entity = harness.spawn_styled(effect_node(), style_builder);
+    harness.run_frames(2);
+
+    let world = harness.world();
+    assert_eq!(world.get::<Glow>(entity), Some(&Glow(0.5)));
+    assert_eq!(world.get::<Spin>(entity), Some(&Spin(90.)));
+    assert_eq!(
+        world.get::<Transform>(entity).unwrap().scale,
+        Vec3::splat(2.)
+    );
+    assert_eq!(world.get::<Style>(entity).unwrap().width, Val::Px(50.));
+}
+
+#[test]
+fn later_custom_attributes_replace_earlier_ones() {
+    let mut style_builder = StyleBuilder::new();
+    style_builder.glow(0.5).spin(0.).glow(1.);
+    style_builder.animated().glow(AnimatedVals {
+        idle: 0.,
+        hover: Some(1.),
+        ..default()
+    });
+
+    let dynamic_style = DynamicStyle::from(style_builder);
+    assert_eq!(dynamic_style.attributes().len(), 2);
+}
+
+#[test]
+fn animates_custom_attributes() {
+    let mut harness = UiTestHarness::new();
+    let mut style_builder = StyleBuilder::new();
+    style_builder
+        .animated()
+        .glow(AnimatedVals {
+            idle: 0.,
+            hover: Some(1.),
+            ..default()
+        })
+        .pointer_enter(0.1, None, None);
+    style_builder
+        .interactive()
+        .spin(InteractiveVals::new(0.).hover(45.));
+
+    let entity = harness.spawn_styled(effect_node(), style_builder);
+    harness.run_frames(2);
+    assert_eq!(harness.world().get::<Glow>(entity), Some(&Glow(0.)));
+    assert_eq!(harness.world().get::<Spin>(entity), Some(&Spin(0.)));
+
+    harness.hover(entity);
+    harness.run_frames(30);
+
+    assert_eq!(harness.world().get::<Glow>(entity), Some(&Glow(1.)));
+    assert_eq!(harness.world().get::<Spin>(entity), Some(&Spin(45.)));
+}
+
+#[test]
+fn styles_entities_through_ui_style() {
+    let mut harness = UiTestHarness::new();
+    let entity = harness.spawn_ui(|ui| {
+        let mut node = ui.spawn((NodeBundle::default(), Glow::default()));
+        node.style().glow(0.25).scale_3d(Vec3::splat(3.));
+
+        node.id()
+    });
+
+    let world = harness.world();
+    assert_eq!(world.get::<Glow>(entity), Some(&Glow(0.25)));
+    assert_eq!(
+        world.get::<Transform>(entity).unwrap().scale,
+        Vec3::splat(3.)
+    );
+}
+
+#[test]
+fn plays_custom_attribute_keyframes() {
+    let mut harness = UiTestHarness::new();
+    let mut style_builder = StyleBuilder::new();
+    style_builder
+        .keyframes()
+        .glow(KeyframeVals::new(0.).keyframe(0.1, 1., None))
+        .loop_type(AnimationLoop::PingPong(2));
+
+    let entity = harness.spawn_styled(effect_node(), style_builder);
+    harness.run_frames(2);
+    let Glow(glow) = *harness.world().get::<Glow>(entity).unwrap();
+    assert!(glow > 0. && glow < 1.);
+
+    harness.run_frames(20);
+    assert_eq!(harness.world().get::<Glow>(entity), Some(&Glow(0.)));
+}
@@ -0,0 +1,444 @@
+use bevy::prelude::*;
+
+use sickle_ui::{
+    prelude::*,
+    test_support::UiTestHarness,
+    widgets::layout::{
+        floating_panel::{
+            FloatingPanel, FloatingPanelResizeHandle, FloatingPanelSnapAxis,
+            FloatingPanelSnapGuide, FloatingPanelSnapLines, FloatingPanelTitle,
+        },
+        floating_panel_manager::{FloatingPanelTray, FloatingPanelTrayItem},
+        resize_handles::ResizeDirection,
+    },
+};
+
+/// Spawns a window-filling column with a floating panel per title and a tray at the bottom
+fn spawn_floating_panels(harness: &mut UiTestHarness, titles: &[&str]) -> (Vec<Entity>, Entity) {
+    let (panels, tray) = harness.spawn_ui(|ui| {
+        let mut panels = Vec::new();
+        let mut tray = Entity::PLACEHOLDER;
+        ui.column(|column| {
+            column
+                .style()
+                .width(Val::Percent(100.))
+                .height(Val::Percent(100.))
+                .justify_content(JustifyContent::End);
+
+            for (i, title) in titles.iter().enumerate() {
+                let panel = column
+                    .floating_panel(
+                        FloatingPanelConfig {
+                            title: Some(title.to_string()),
+                            ..default()
+                        },
+                        FloatingPanelLayout {
+                            size: Vec2::new(200., 150.),
+                            position: Vec2::new(100. + 150. * i as f32, 100.).into(),
+                            droppable: false,
+                        },
+                        |panel| {
+                            panel.label(LabelConfig::from(*title));
+                        },
+                    )
+                    .id();
+                panels.push(panel);
+            }
+
+            tray = column.floating_panel_tray().id();
+        });
+
+        (panels, tray)
+    });
+    harness.run_frames(2);
+
+    (panels, tray)
+}
+
+fn manager(harness: &mut UiTestHarness) -> Mut<'_, FloatingPanelManager> {
+    harness.world_mut().resource_mut::<FloatingPanelManager>()
+}
+
+fn panel(harness: &UiTestHarness, entity: Entity) -> &FloatingPanel {
+    harness.world().get::<FloatingPanel>(entity).unwrap()
+}
+
+fn tray_items(harness: &mut UiTestHarness, tray: Entity) -> Vec<Entity> {
+    let children = harness
+        .world()
+        .get::<Children>(tray)
+        .map(|children| children.to_vec())
+        .unwrap_or_default();
+
+    children
+        .into_iter()
+        .filter(|child| {
+            harness
+                .world()
+                .get::<FloatingPanelTrayItem>(*child)
+                .is_some()
+        })
+        .collect()
+}
+
+/// Panels in the order the manager reports them, back to front
+fn panel_order(harness: &UiTestHarness) -> Vec<Entity> {
+    harness
+        .world()
+        .resource::<FloatingPanelManager>()
+        .panels()
+        .iter()
+        .map(|entry| entry.panel)
+        .collect()
+}
+
+#[test]
+fn manager_brings_panel_to_front() {
+    let mut harness = UiTestHarness::new();
+    let (panels, _) = spawn_floating_panels(&mut harness, &["First", "Second", "Third"]);
+
+    manager(&mut harness).bring_to_front(panels[0]);
+    harness.run_frames(2);
+
+    let front = panel(&harness, panels[0]).z_index().unwrap();
+    for other in &panels[1..] {
+        assert!(panel(&harness, *other).z_index() < Some(front));
+    }
+    assert_eq!(panel_order(&harness).last(), Some(&panels[0]));
+}
+
+#[test]
+fn manager_minimizes_and_restores_panels() {
+    let mut harness = UiTestHarness::new();
+    let (panels, tray) = spawn_floating_panels(&mut harness, &["First", "Second"]);
+    assert!(tray_items(&mut harness, tray).is_empty());
+
+    manager(&mut harness).minimize(panels[0]);
+    harness.run_frames(2);
+
+    let world = harness.world();
+    assert!(
+        world
+            .get::<FloatingPanelConfig>(panels[0])
+            .unwrap()
+            .minimized
+    );
+    let entry = world
+        .resource::<FloatingPanelManager>()
+        .get(panels[0])
+        .unwrap();
+    assert_eq!(entry.visibility, FloatingPanelVisibility::Minimized);
+
+    let items = tray_items(&mut harness, tray);
+    assert_eq!(items.len(), 1);
+    let item = harness
+        .world()
+        .get::<FloatingPanelTrayItem>(items[0])
+        .unwrap();
+    assert_eq!(item.panel(), panels[0]);
+    let label = item.get(FloatingPanelTrayItem::LABEL).unwrap();
+    assert_eq!(
+        harness.world().get::<Text>(label).unwrap().sections[0].value,
+        "First"
+    );
+    assert_eq!(
+        harness.world().get::<Name>(items[0]).unwrap().as_str(),
+        "Floating Panel Tray Item [First]"
+    );
+
+    harness.click(items[0]);
+    harness.run_frames(2);
+
+    assert!(
+        !harness
+            .world()
+            .get::<FloatingPanelConfig>(panels[0])
+            .unwrap()
+            .minimized
+    );
+    assert_eq!(panel_order(&harness).last(), Some(&panels[0]));
+    assert!(tray_items(&mut harness, tray).is_empty());
+}
+
+#[test]
+fn manager_cascades_panels() {
+    let mut harness = UiTestHarness::new();
+    let (panels, _) = spawn_floating_panels(&mut harness, &["First", "Second", "Third"]);
+    manager(&mut harness).bring_to_front(panels[0]);
+    harness.run_frames(2);
+
+    let order = panel_order(&harness);
+    manager(&mut harness).cascade();
+    harness.run_frames(2);
+
+    for (i, entity) in order.iter().enumerate() {
+        let expected = Vec2::new(20., 20.) + Vec2::new(30., 30.) * i as f32;
+        assert_eq!(panel(&harness, *entity).position(), expected);
+        assert_eq!(panel(&harness, *entity).size(), Vec2::new(200., 150.));
+        assert_eq!(harness.rect(*entity).min, expected);
+    }
+}
+
+#[test]
+fn manager_tiles_panels_above_tray() {
+    let mut harness = UiTestHarness::new();
+    let (panels, tray) = spawn_floating_panels(&mut harness, &["First", "Second", "Third"]);
+    let tray_rect = harness.rect(tray);
+    assert!(tray_rect.height() > 0.);
+    assert_eq!(
+        harness
+            .world_mut()
+            .query_filtered::<Entity, With<FloatingPanelTray>>()
+            .single(harness.world()),
+        tray
+    );
+
+    manager(&mut harness).tile();
+    // Let the size animation settle
+    harness.run_frames(30);
+
+    let window = UiTestHarness::WINDOW_SIZE;
+    let cell_size = Vec2::new(window.x / 2., (window.y - tray_rect.height()) / 2.);
+    for entity in &panels {
+        assert_eq!(panel(&harness, *entity).size(), cell_size);
+
+        let rect = harness.rect(*entity);
+        assert!(rect.max.y <= tray_rect.min.y + 0.5);
+        assert!((rect.size() - cell_size).length() < 0.5);
+    }
+}
+
+const PANEL_SIZE: Vec2 = Vec2::new(200., 150.);
+
+/// Spawns 200x150 panels at `positions` into a container offset by `origin` from the window corner
+fn spawn_panels_at(harness: &mut UiTestHarness, origin: Vec2, positions: &[Vec2]) -> Vec<Entity> {
+    let panels = harness.spawn_ui(|ui| {
+        let mut panels = Vec::new();
+        ui.column(|column| {
+            column
+                .style()
+                .margin(UiRect {
+                    left: Val::Px(origin.x),
+                    top: Val::Px(origin.y),
+                    ..default()
+                })
+                .width(Val::Px(500.))
+                .height(Val::Px(400.));
+
+            for position in positions {
+                let panel = column
+                    .floating_panel(
+                        FloatingPanelConfig {
+                            title: Some("Panel".into()),
+                            ..default()
+                        },
+                        FloatingPanelLayout {
+                            size: PANEL_SIZE,
+                            position: (*position).into(),
+                            droppable: false,
+                        },
+                        |_| {},
+                    )
+                    .id();
+                panels.push(panel);
+            }
+        });
+
+        panels
+    });
+    // Let the size animation settle
+    harness.run_frames(30);
+
+    panels
+}
+
+fn title(harness: &mut UiTestHarness, panel: Entity) -> Entity {
+    harness
+        .world_mut()
+        .query::<(Entity, &FloatingPanelTitle)>()
+        .iter(harness.world())
+        .find(|(_, title)| title.panel() == panel)
+        .map(|(entity, _)| entity)
+        .expect("panel has no title")
+}
+
+fn east_resize_handle(harness: &mut UiTestHarness, panel: Entity) -> Entity {
+    harness
+        .world_mut()
+        .query::<(Entity, &ResizeHandle, &FloatingPanelResizeHandle)>()
+        .iter(harness.world())
+        .find(|(_, handle, handle_ref)| {
+            handle_ref.panel() == panel && handle.direction() == ResizeDirection::East
+        })
+        .map(|(entity, _, _)| entity)
+        .expect("panel has no east resize handle")
+}
+
+/// Presses at `from`, then moves the cursor by each offset in turn without releasing
+fn start_drag(harness: &mut UiTestHarness, from: Vec2, moves: &[Vec2]) -> Vec2 {
+    let mut cursor = from;
+    harness.move_cursor(cursor);
+    harness.press_mouse(MouseButton::Left);
+    for offset in moves {
+        cursor += *offset;
+        harness.move_cursor(cursor);
+    }
+
+    cursor
+}
+
+#[test]
+fn panel_snaps_to_window_edges() {
+    let mut harness = UiTestHarness::new();
+    let panels = spawn_panels_at(&mut harness, Vec2::ZERO, &[Vec2::new(100., 100.)]);
+
+    // Right edge ends up 4px short of the window edge
+    let title = title(&mut harness, panels[0]);
+    let from = harness.center(title);
+    harness.drag(from, from + Vec2::new(496., 0.));
+    harness.run_frames(2);
+
+    let window = UiTestHarness::WINDOW_SIZE;
+    assert_eq!(
+        panel(&harness, panels[0]).position(),
+        Vec2::new(window.x - PANEL_SIZE.x, 100.)
+    );
+    assert_eq!(harness.rect(panels[0]).max.x, window.x);
+    assert_eq!(
+        panel(&harness, panels[0]).snap_lines(),
+        FloatingPanelSnapLines::default()
+    );
+}
+
+#[test]
+fn snap_guide_is_placed_in_window_space() {
+    let mut harness = UiTestHarness::new();
+    let panels = spawn_panels_at(&mut harness, Vec2::new(100., 50.), &[Vec2::ZERO]);
+
+    let title = title(&mut harness, panels[0]);
+    let from = harness.center(title);
+    let start = panel(&harness, panels[0]).position();
+    let window = UiTestHarness::WINDOW_SIZE;
+    let offset = window.x - PANEL_SIZE.x - start.x - 4.;
+    start_drag(&mut harness, from, &[Vec2::new(offset, 0.)]);
+
+    assert_eq!(panel(&harness, panels[0]).snap_lines().x, Some(window.x));
+    assert_eq!(harness.rect(panels[0]).max.x, window.x);
+
+    let guide = harness
+        .world_mut()
+        .query::<(Entity, &FloatingPanelSnapGuide)>()
+        .iter(harness.world())
+        .find(|(_, guide)| guide.panel() == panels[0] && guide.axis() == FloatingPanelSnapAxis::X)
+        .map(|(entity, _)| entity)
+        .expect("no snap guide shown");
+    assert_eq!(harness.rect(guide).min.x, window.x);
+
+    harness.release_mouse(MouseButton::Left);
+    harness.run_frames(2);
+    assert!(harness.world().get_entity(guide).is_none());
+}
+#[test]
+fn panel_snaps_to_sibling_panels() {
+    let mut harness = UiTestHarness::new();
+    let panels = spawn_panels_at(
+        &mut harness,
+        Vec2::ZERO,
+        &[Vec2::new(100., 100.), Vec2::new(400., 100.)],
+    );
+
+    let title = title(&mut harness, panels[1]);
+    let from = harness.center(title);
+    harness.drag(from, from + Vec2::new(-94., 0.));
+    harness.run_frames(2);
+
+    assert_eq!(panel(&harness, panels[1]).position(), Vec2::new(300., 100.));
+}
+
+#[test]
+fn panel_snapping_can_be_disabled() {
+    let mut harness = UiTestHarness::new();
+    let panels = spawn_panels_at(
+        &mut harness,
+        Vec2::ZERO,
+        &[Vec2::new(100., 100.), Vec2::new(400., 100.)],
+    );
+    harness
+        .world_mut()
+        .resource_mut::<FloatingPanelSnapping>()
+        .enabled = false;
+
+    let title = title(&mut harness, panels[1]);
+    let from = harness.center(title);
+    harness.drag(from, from + Vec2::new(-94., 0.));
+    harness.run_frames(2);
+
+    assert_eq!(panel(&harness, panels[1]).position(), Vec2::new(306., 100.));
+}
+
+#[test]
+fn dragged_panel_leaves_snap_line() {
+    let mut harness = UiTestHarness::new();
+    let panels = spawn_panels_at(
+        &mut harness,
+        Vec2::ZERO,
+        &[Vec2::new(100., 100.), Vec2::new(400., 100.)],
+    );
+
+    let title = title(&mut harness, panels[1]);
+    let from = harness.center(title);
+    let cursor = start_drag(
+        &mut harness,
+        from,
+        &[Vec2::new(-47., 0.), Vec2::new(-47., 0.)],
+    );
+    assert_eq!(panel(&harness, panels[1]).position().x, 300.);
+    assert_eq!(panel(&harness, panels[1]).snap_lines().x, Some(300.));
+
+    // Movement continues from the unsnapped position
+    harness.move_cursor(cursor + Vec2::new(-20., 0.));
+    assert_eq!(panel(&harness, panels[1]).position().x, 286.);
+    assert_eq!(panel(&harness, panels[1]).snap_lines().x, None);
+
+    harness.release_mouse(MouseButton::Left);
+    assert_eq!(
+        panel(&harness, panels[1]).snap_lines(),
+        FloatingPanelSnapLines::default()
+    );
+}
+
+#[test]
+fn resized_panel_leaves_snap_line() {
+    let mut harness = UiTestHarness::new();
+    let panels = spawn_panels_at(
+        &mut harness,
+        Vec2::ZERO,
+        &[Vec2::new(100., 100.), Vec2::new(400., 100.)],
+    );
+
+    // Resize handles are shown from the first panel update after spawning
+    manager(&mut harness).bring_to_front(panels[0]);
+    harness.run_frames(2);
+
+    let handle = east_resize_handle(&mut harness, panels[0]);
+    let from = harness.center(handle);
+    let cursor = start_drag(
+        &mut harness,
+        from,
+        &[Vec2::new(47., 0.), Vec2::new(47., 0.)],
+    );
+    assert_eq!(panel(&harness, panels[0]).size().x, 300.);
+    assert_eq!(panel(&harness, panels[0]).snap_lines().x, Some(400.));
+
+    // Resizing continues from the unsnapped size
+    harness.move_cursor(cursor + Vec2::new(20., 0.));
+    assert_eq!(panel(&harness, panels[0]).size().x, 314.);
+    assert_eq!(panel(&harness, panels[0]).position().x, 100.);
+
+    harness.release_mouse(MouseButton::Left);
+    assert_eq!(
+        panel(&harness, panels[0]).snap_lines(),
+        FloatingPanelSnapLines::default()
+    );
+}
+
@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+
+use sickle_ui::{
+    dev_panels::hierarchy::{
+        HierarchyContainer, HierarchyNode, HierarchyTreeViewPlugin, UiHierarchyExt,
+    },
+    prelude::*,
+    test_support::UiTestHarness,
+    widgets::menus::toggle_menu_item::ToggleMenuItem,
+};
+
+fn hierarchy_node(harness: &mut UiTestHarness, target: Entity) -> Option<Entity> {
+    harness
+        .world_mut()
+        .query::<(Entity, &HierarchyNode)>()
+        .iter(harness.world())
+        .find(|(_, node)| node.target() == target)
+        .map(|(entity, _)| entity)
+}
+
+#[test]
+fn picking_during_search_clears_search_and_reveals_pick() {
+    let mut harness = UiTestHarness::new();
+    harness.app().add_plugins(HierarchyTreeViewPlugin);
+
+    let target = harness.spawn_ui(|ui| {
+        let mut target = Entity::PLACEHOLDER;
+        ui.row(|row| {
+            row.style()
+                .width(Val::Percent(100.))
+                .height(Val::Percent(100.));
+
+            target = row
+                .column(|column| {
+                    column.style().width(Val::Px(200.));
+                    for name in ["Alpha", "Beta"] {
+                        column
+                            .container(NodeBundle::default(), |_| {})
+                            .insert(Name::new(name))
+                            .style()
+                            .width(Val::Px(100.))
+                            .height(Val::Px(50.));
+                    }
+                })
+                .insert(Name::new("Target"))
+                .id();
+
+            row.column(|column| {
+                column.style().width(Val::Px(500.));
+                column.hierarchy_for(target);
+            });
+        });
+
+        target
+    });
+    harness.run_frames(5);
+
+    let alpha = harness.find_named("Alpha").unwrap();
+    let beta = harness.find_named("Beta").unwrap();
+    assert!(hierarchy_node(&mut harness, target).is_some());
+
+    let search = harness
+        .world_mut()
+        .query_filtered::<Entity, With<TextInput>>()
+        .single(harness.world());
+    harness
+        .world_mut()
+        .get_mut::<TextInput>(search)
+        .unwrap()
+        .value = "beta".into();
+    harness.run_frames(5);
+    assert!(hierarchy_node(&mut harness, alpha).is_none());
+    assert!(hierarchy_node(&mut harness, beta).is_some());
+
+    let pick_button = harness
+        .world_mut()
+        .query_filtered::<Entity, With<ToggleMenuItem>>()
+        .single(harness.world());
+    harness.click(pick_button);
+    harness.click(alpha);
+    harness.run_frames(5);
+
+    let container = harness
+        .world_mut()
+        .query::<&HierarchyContainer>()
+        .single(harness.world());
+    assert_eq!(container.selected(), Some(alpha));
+    assert!(harness
+        .world()
+        .get::<TextInput>(search)
+        .unwrap()
+        .value
+        .is_empty());
+    assert!(hierarchy_node(&mut harness, alpha).is_some());
+    assert!(hierarchy_node(&mut harness, beta).is_some());
+    assert!(hierarchy_node(&mut harness, target).is_some());
+}
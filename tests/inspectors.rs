@@ -0,0 +1,214 @@
+use bevy::prelude::*;
+
+use sickle_ui::{
+    dev_panels::{
+        entity_inspector::{EntityInspectorPlugin, InspectorNumberField, UiEntityInspectorExt},
+        resource_inspector::{ResourceInspector, ResourceInspectorPlugin, UiResourceInspectorExt},
+        style_inspector::{StyleInspectorPlugin, UiStyleInspectorExt},
+    },
+    prelude::*,
+    test_support::UiTestHarness,
+};
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Health {
+    value: f32,
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Shield {
+    active: bool,
+}
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Settings {
+    volume: f32,
+}
+
+/// Spawns an entity with [`Health`] and an inspector for it
+fn spawn_inspected(harness: &mut UiTestHarness) -> (Entity, Entity) {
+    harness
+        .app()
+        .add_plugins(EntityInspectorPlugin)
+        .register_type::<Health>()
+        .register_type::<Shield>();
+
+    let target = harness.world_mut().spawn(Health { value: 10. }).id();
+    let inspector = harness.spawn_ui(|ui| {
+        let mut inspector = Entity::PLACEHOLDER;
+        ui.column(|column| {
+            inspector = column.entity_inspector(Some(target)).id();
+        });
+
+        inspector
+    });
+    harness.run_frames(3);
+
+    (target, inspector)
+}
+
+fn inspector_children(harness: &UiTestHarness, inspector: Entity) -> Vec<Entity> {
+    harness
+        .world()
+        .get::<Children>(inspector)
+        .map(|children| children.to_vec())
+        .unwrap_or_default()
+}
+
+fn find_text(harness: &mut UiTestHarness, value: &str) -> Option<Entity> {
+    harness
+        .world_mut()
+        .query::<(Entity, &Text)>()
+        .iter(harness.world())
+        .find(|(_, text)| text.sections.first().is_some_and(|s| s.value == value))
+        .map(|(entity, _)| entity)
+}
+
+fn readout_text(harness: &mut UiTestHarness) -> String {
+    let field = harness
+        .world_mut()
+        .query::<&InspectorNumberField>()
+        .single(harness.world())
+        .clone();
+    let readout = field.get(InspectorNumberField::READOUT).unwrap();
+
+    harness.world().get::<Text>(readout).unwrap().sections[0]
+        .value
+        .clone()
+}
+
+#[test]
+fn inspector_rebuilds_when_components_change() {
+    let mut harness = UiTestHarness::new();
+    let (target, inspector) = spawn_inspected(&mut harness);
+
+    let children = inspector_children(&harness, inspector);
+    assert!(!children.is_empty());
+
+    harness
+        .world_mut()
+        .entity_mut(target)
+        .insert(Shield::default());
+    harness.run_frames(2);
+
+    let with_shield = inspector_children(&harness, inspector);
+    assert_ne!(with_shield, children);
+    assert!(with_shield.len() > children.len());
+
+    harness.world_mut().entity_mut(target).remove::<Shield>();
+    harness.run_frames(2);
+
+    assert_eq!(
+        inspector_children(&harness, inspector).len(),
+        children.len()
+    );
+}
+
+#[test]
+fn inspector_is_not_rebuilt_without_changes() {
+    let mut harness = UiTestHarness::new();
+    let (_, inspector) = spawn_inspected(&mut harness);
+
+    let children = inspector_children(&harness, inspector);
+    harness.run_frames(5);
+
+    assert_eq!(inspector_children(&harness, inspector), children);
+}
+
+#[test]
+fn inspector_syncs_values_without_rebuilding() {
+    let mut harness = UiTestHarness::new();
+    let (target, inspector) = spawn_inspected(&mut harness);
+
+    let children = inspector_children(&harness, inspector);
+    assert_eq!(readout_text(&mut harness), "10.000");
+
+    harness.world_mut().get_mut::<Health>(target).unwrap().value = 25.;
+    harness.run_frames(2);
+
+    assert_eq!(readout_text(&mut harness), "25.000");
+    assert_eq!(inspector_children(&harness, inspector), children);
+}
+
+#[test]
+fn style_inspector_explains_themes() {
+    let mut harness = UiTestHarness::new();
+    harness.app().add_plugins(StyleInspectorPlugin);
+
+    let checkbox = harness.spawn_ui(|ui| {
+        let mut checkbox = Entity::PLACEHOLDER;
+        ui.column(|column| {
+            checkbox = column.checkbox(None, true).id();
+            column.style_inspector(Some(checkbox));
+        });
+
+        checkbox
+    });
+    harness.run_frames(3);
+
+    assert!(find_text(&mut harness, "Checkbox").is_some());
+    assert!(find_text(&mut harness, "default").is_some());
+    assert!(find_text(&mut harness, "Checked").is_some());
+
+    // Rebuilt when the themes of the entity change
+    harness
+        .world_mut()
+        .entity_mut(checkbox)
+        .insert(Theme::<Checkbox>::new(vec![PseudoTheme::build(
+            None,
+            |style_builder| {
+                style_builder.width(Val::Px(100.));
+            },
+        )]));
+    harness.run_frames(3);
+
+    assert!(find_text(&mut harness, "default").is_none());
+    assert!(find_text(&mut harness, "default > own").is_some());
+}
+
+#[test]
+fn resource_inspector_edits_selected_resource() {
+    // Tall enough to list every resource without scrolling
+    let mut harness = UiTestHarness::with_window_size(Vec2::new(800., 2000.));
+    harness
+        .app()
+        .add_plugins(ResourceInspectorPlugin)
+        .register_type::<Settings>()
+        .insert_resource(Settings { volume: 0.5 });
+
+    let inspector = harness.spawn_ui(|ui| {
+        let mut inspector = Entity::PLACEHOLDER;
+        ui.column(|column| {
+            column
+                .style()
+                .width(Val::Percent(100.))
+                .height(Val::Percent(100.));
+            inspector = column.resource_inspector(None).id();
+        });
+
+        inspector
+    });
+    harness.run_frames(3);
+
+    let entry = find_text(&mut harness, "Settings").expect("resource is not listed");
+    harness.click(entry);
+    harness.run_frames(2);
+
+    assert_eq!(
+        harness
+            .world()
+            .get::<ResourceInspector>(inspector)
+            .unwrap()
+            .selected
+            .as_deref(),
+        Some(std::any::type_name::<Settings>())
+    );
+    assert_eq!(readout_text(&mut harness), "0.500");
+
+    harness.world_mut().resource_mut::<Settings>().volume = 0.75;
+    harness.run_frames(2);
+    assert_eq!(readout_text(&mut harness), "0.750");
+}
@@ -0,0 +1,133 @@
+use bevy::{ecs::system::CommandQueue, prelude::*};
+
+use sickle_ui::{prelude::*, test_support::UiTestHarness};
+
+fn width(harness: &UiTestHarness, entity: Entity) -> Val {
+    harness.world().get::<Style>(entity).unwrap().width
+}
+
+#[test]
+fn plays_keyframes_on_enter() {
+    let mut harness = UiTestHarness::new();
+    harness.collect_events::<KeyframesCompleted>();
+    let mut style_builder = StyleBuilder::new();
+    style_builder.keyframes().width(
+        KeyframeVals::new(Val::Px(0.))
+            .keyframe(0.2, Val::Px(100.), None)
+            .keyframe(0.4, Val::Px(50.), None),
+    );
+
+    let entity = harness.spawn_styled((NodeBundle::default(), PseudoStates::new()), style_builder);
+    assert_eq!(width(&harness, entity), Val::Px(0.));
+
+    harness.run_frames(6);
+    let Val::Px(midway) = width(&harness, entity) else {
+        panic!("width is not in pixels");
+    };
+    assert!(midway > 0. && midway < 100.);
+
+    harness.run_frames(30);
+    assert_eq!(width(&harness, entity), Val::Px(50.));
+
+    let completed = harness.collected_events::<KeyframesCompleted>();
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].target, entity);
+    assert_eq!(completed[0].trigger, KeyframeTrigger::Enter);
+    assert!(completed[0].finished);
+}
+
+#[test]
+fn reports_loop_iterations() {
+    let mut harness = UiTestHarness::new();
+    harness.collect_events::<KeyframesCompleted>();
+    let mut style_builder = StyleBuilder::new();
+    style_builder
+        .keyframes()
+        .flex_grow(KeyframeVals::new(0.).keyframe(0.1, 1., None))
+        .loop_type(AnimationLoop::Times(3, true));
+
+    let entity = harness.spawn_styled((NodeBundle::default(), PseudoStates::new()), style_builder);
+    harness.run_frames(30);
+
+    let flex_grow = harness.world().get::<Style>(entity).unwrap().flex_grow;
+    assert_eq!(flex_grow, 0.);
+
+    let completed: Vec<(u32, bool)> = harness
+        .collected_events::<KeyframesCompleted>()
+        .iter()
+        .map(|event| (event.iteration, event.finished))
+        .collect();
+    assert_eq!(completed, vec![(0, false), (1, false), (2, true)]);
+}
+
+#[test]
+fn plays_keyframes_on_pseudo_state() {
+    let mut harness = UiTestHarness::new();
+    harness.collect_events::<KeyframesCompleted>();
+    let mut style_builder = StyleBuilder::new();
+    style_builder
+        .keyframes()
+        .width(KeyframeVals::new(Val::Px(10.)).keyframe(0.1, Val::Px(20.), None))
+        .on_pseudo_state(PseudoState::Error);
+
+    let entity = harness.spawn_styled((NodeBundle::default(), PseudoStates::new()), style_builder);
+    harness.run_frames(20);
+    assert_eq!(width(&harness, entity), Val::Px(10.));
+    assert!(harness.collected_events::<KeyframesCompleted>().is_empty());
+
+    harness
+        .world_mut()
+        .get_mut::<PseudoStates>(entity)
+        .unwrap()
+        .add(PseudoState::Error);
+    harness.run_frames(20);
+
+    assert_eq!(width(&harness, entity), Val::Px(20.));
+    assert_eq!(harness.collected_events::<KeyframesCompleted>().len(), 1);
+}
+
+#[test]
+fn plays_keyframes_on_command() {
+    let mut harness = UiTestHarness::new();
+    harness.collect_events::<KeyframesCompleted>();
+    let mut style_builder = StyleBuilder::new();
+    style_builder
+        .keyframes()
+        .width(KeyframeVals::new(Val::Px(10.)).keyframe(0.1, Val::Px(20.), None))
+        .on_command("grow");
+
+    let entity = harness.spawn_styled((NodeBundle::default(), PseudoStates::new()), style_builder);
+    harness.run_frames(20);
+    assert_eq!(width(&harness, entity), Val::Px(10.));
+
+    let mut queue = CommandQueue::default();
+    Commands::new(&mut queue, harness.world())
+        .entity(entity)
+        .play_keyframes("grow");
+    queue.apply(harness.world_mut());
+    harness.run_frames(20);
+
+    assert_eq!(width(&harness, entity), Val::Px(20.));
+    let completed = harness.collected_events::<KeyframesCompleted>();
+    assert_eq!(completed.len(), 1);
+    assert_eq!(
+        completed[0].trigger,
+        KeyframeTrigger::Command("grow".into())
+    );
+}
+
+#[test]
+fn samples_between_keyframes() {
+    let keyframes = KeyframeVals::from(vec![
+        Keyframe::new(1., 10., None),
+        Keyframe::new(0., 0., None),
+        Keyframe::new(2., 0., None),
+    ]);
+
+    assert_eq!(keyframes.duration(), 2.);
+    assert_eq!(keyframes.sample(-1.), 0.);
+    assert_eq!(keyframes.sample(0.5), 5.);
+    assert_eq!(keyframes.sample(1.), 10.);
+    assert_eq!(keyframes.sample(1.5), 5.);
+    assert_eq!(keyframes.sample(3.), 0.);
+}
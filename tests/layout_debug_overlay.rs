@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+
+use sickle_ui::{
+    dev_panels::layout_debug_overlay::{LayoutDebugOverlay, LayoutDebugOverlayPlugin},
+    prelude::*,
+    test_support::UiTestHarness,
+};
+
+fn spawn_sized_node(harness: &mut UiTestHarness) -> Entity {
+    harness.app().add_plugins(LayoutDebugOverlayPlugin);
+
+    let node = harness.spawn_ui(|ui| {
+        let mut node = Entity::PLACEHOLDER;
+        ui.column(|column| {
+            node = column
+                .container(NodeBundle::default(), |_| {})
+                .style()
+                .width(Val::Px(120.))
+                .height(Val::Px(40.))
+                .id();
+        });
+
+        node
+    });
+    harness.run_frames(2);
+
+    node
+}
+
+/// The size labels of the overlay that read `label`
+fn size_labels(harness: &mut UiTestHarness, label: &str) -> usize {
+    harness
+        .world_mut()
+        .query::<&Text>()
+        .iter(harness.world())
+        .filter(|text| text.sections.first().is_some_and(|s| s.value == label))
+        .count()
+}
+
+#[test]
+fn overlay_toggles_with_key() {
+    let mut harness = UiTestHarness::new();
+    spawn_sized_node(&mut harness);
+    assert!(harness.find_named("Layout Debug Overlay").is_none());
+
+    harness.press_keys([KeyCode::F12]);
+    assert!(harness.world().resource::<LayoutDebugOverlay>().enabled);
+    assert!(harness.find_named("Layout Debug Overlay").is_some());
+    assert_eq!(size_labels(&mut harness, "120 x 40"), 1);
+
+    harness.press_keys([KeyCode::F12]);
+    assert!(!harness.world().resource::<LayoutDebugOverlay>().enabled);
+    assert!(harness.find_named("Layout Debug Overlay").is_none());
+    assert_eq!(size_labels(&mut harness, "120 x 40"), 0);
+}
+
+#[test]
+fn overlay_follows_layout_changes() {
+    let mut harness = UiTestHarness::new();
+    let node = spawn_sized_node(&mut harness);
+    harness
+        .world_mut()
+        .resource_mut::<LayoutDebugOverlay>()
+        .enabled = true;
+    harness.run_frames(3);
+    assert_eq!(size_labels(&mut harness, "120 x 40"), 1);
+
+    harness.world_mut().get_mut::<Style>(node).unwrap().width = Val::Px(80.);
+    harness.run_frames(3);
+    assert_eq!(size_labels(&mut harness, "120 x 40"), 0);
+    assert_eq!(size_labels(&mut harness, "80 x 40"), 1);
+
+    harness.world_mut().entity_mut(node).despawn_recursive();
+    harness.run_frames(3);
+    assert_eq!(size_labels(&mut harness, "80 x 40"), 0);
+}
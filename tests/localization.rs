@@ -0,0 +1,171 @@
+use bevy::{a11y::AccessibilityNode, prelude::*};
+
+use sickle_ui::{prelude::*, test_support::UiTestHarness};
+
+const EN: &str = "
+mute = Mute
+volume = Volume
+quality-low = Low
+quality-high = High
+advanced = Advanced
+";
+
+const DE: &str = "
+mute = Stumm
+volume = Lautstärke
+quality-low = Niedrig
+quality-high = Hoch
+advanced = Erweitert
+";
+
+fn localized_harness() -> UiTestHarness {
+    let mut harness = UiTestHarness::new();
+    let catalog = MessageCatalog::new()
+        .with_resource("en-US", EN)
+        .with_resource("de-DE", DE);
+    harness
+        .world_mut()
+        .insert_resource(Locale::new("en-US", catalog));
+
+    harness
+}
+
+/// The rendered text of every localized label, by message id
+fn localized_texts(harness: &mut UiTestHarness) -> Vec<(String, String)> {
+    let mut texts: Vec<(String, String)> = harness
+        .world_mut()
+        .query::<(&LocalizedText, &Text)>()
+        .iter(harness.world())
+        .map(|(localized, text)| (localized.id().into(), text.sections[0].value.clone()))
+        .collect();
+    texts.sort();
+
+    texts
+}
+
+fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(id, text)| (id.to_string(), text.to_string()))
+        .collect()
+}
+
+#[test]
+fn input_labels_follow_locale() {
+    let mut harness = localized_harness();
+    harness.spawn_ui(|ui| {
+        ui.column(|column| {
+            column.localized_checkbox(LocalizedText::new("mute"), false);
+            column.slider(
+                SliderConfig::horizontal(None, 0., 1., 0.5, false)
+                    .with_localized_label(LocalizedText::new("volume")),
+            );
+            column.localized_radio_group(
+                vec![
+                    LocalizedText::new("quality-low"),
+                    LocalizedText::new("quality-high"),
+                ],
+                0,
+                false,
+            );
+            column.localized_foldable(LocalizedText::new("advanced"), true, false, |_| {});
+        });
+    });
+
+    assert_eq!(
+        localized_texts(&mut harness),
+        pairs(&[
+            ("advanced", "Advanced"),
+            ("mute", "Mute"),
+            ("quality-high", "High"),
+            ("quality-low", "Low"),
+            ("volume", "Volume"),
+        ])
+    );
+
+    harness
+        .world_mut()
+        .resource_mut::<Locale>()
+        .set_current("de-DE");
+    harness.update();
+
+    assert_eq!(
+        localized_texts(&mut harness),
+        pairs(&[
+            ("advanced", "Erweitert"),
+            ("mute", "Stumm"),
+            ("quality-high", "Hoch"),
+            ("quality-low", "Niedrig"),
+            ("volume", "Lautstärke"),
+        ])
+    );
+}
+
+#[test]
+fn accessible_names_follow_locale() {
+    let mut harness = localized_harness();
+    harness.spawn_ui(|ui| {
+        ui.column(|column| {
+            column.localized_checkbox(LocalizedText::new("mute"), false);
+            column.localized_dropdown(
+                vec![
+                    LocalizedText::new("quality-low"),
+                    LocalizedText::new("quality-high"),
+                ],
+                1,
+            );
+        });
+    });
+    harness.update();
+
+    assert_eq!(
+        accessible_texts(&mut harness),
+        pairs(&[("", "High"), ("Mute", "")])
+    );
+
+    harness
+        .world_mut()
+        .resource_mut::<Locale>()
+        .set_current("de-DE");
+    harness.update();
+
+    assert_eq!(
+        accessible_texts(&mut harness),
+        pairs(&[("", "Hoch"), ("Stumm", "")])
+    );
+}
+
+/// The name and value of every checkbox and dropdown accessibility node
+fn accessible_texts(harness: &mut UiTestHarness) -> Vec<(String, String)> {
+    let mut texts: Vec<(String, String)> = harness
+        .world_mut()
+        .query_filtered::<&AccessibilityNode, Or<(With<Checkbox>, With<Dropdown>)>>()
+        .iter(harness.world())
+        .map(|node| {
+            (
+                node.name().unwrap_or_default().into(),
+                node.value().unwrap_or_default().into(),
+            )
+        })
+        .collect();
+    texts.sort();
+
+    texts
+}
+
+#[test]
+fn localized_inputs_are_named_after_message_id() {
+    let mut harness = localized_harness();
+    harness.spawn_ui(|ui| {
+        ui.column(|column| {
+            column.localized_checkbox(LocalizedText::new("mute").with_fallback("Silence"), false);
+            column.slider(
+                SliderConfig::horizontal(None, 0., 1., 0.5, false)
+                    .with_localized_label(LocalizedText::new("volume")),
+            );
+        });
+    });
+
+    assert!(harness.find_named("Checkbox [mute]").is_some());
+    assert!(harness.find_named("Slider [volume]").is_some());
+}
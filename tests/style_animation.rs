@@ -0,0 +1,119 @@
+use bevy::prelude::*;
+
+use sickle_ui::{prelude::*, test_support::UiTestHarness};
+
+fn hovered_node() -> impl Bundle {
+    (
+        NodeBundle {
+            style: Style {
+                height: Val::Px(100.),
+                ..default()
+            },
+            ..default()
+        },
+        Interaction::default(),
+        TrackedInteraction::default(),
+    )
+}
+
+fn hover_width() -> AnimatedVals<Val> {
+    AnimatedVals {
+        idle: Val::Px(100.),
+        hover: Some(Val::Px(200.)),
+        hover_alt: Some(Val::Px(150.)),
+        ..default()
+    }
+}
+
+fn completions(harness: &UiTestHarness, state: InteractionStyle) -> Vec<AnimationCompletion> {
+    harness
+        .collected_events::<AnimationCompleted>()
+        .iter()
+        .filter(|completed| completed.state == state)
+        .map(|completed| completed.completion)
+        .collect()
+}
+
+#[test]
+fn reports_reached_state() {
+    let mut harness = UiTestHarness::new();
+    harness.collect_events::<AnimationCompleted>();
+    let mut style_builder = StyleBuilder::new();
+    style_builder
+        .animated()
+        .width(hover_width())
+        .pointer_enter(0.2, None, None);
+
+    let entity = harness.spawn_styled(hovered_node(), style_builder);
+    harness.run_frames(2);
+    assert_eq!(
+        completions(&harness, InteractionStyle::Idle),
+        vec![AnimationCompletion::Transition]
+    );
+
+    harness.hover(entity);
+    assert!(completions(&harness, InteractionStyle::Hover).is_empty());
+
+    harness.run_frames(30);
+    assert_eq!(
+        completions(&harness, InteractionStyle::Hover),
+        vec![AnimationCompletion::Transition]
+    );
+
+    let completed = harness
+        .collected_events::<AnimationCompleted>()
+        .last()
+        .unwrap();
+    assert_eq!(completed.entity, entity);
+    assert_eq!(completed.target, entity);
+}
+
+#[test]
+fn reports_loop_iterations() {
+    let mut harness = UiTestHarness::new();
+    harness.collect_events::<AnimationCompleted>();
+    let mut style_builder = StyleBuilder::new();
+    style_builder
+        .animated()
+        .width(hover_width())
+        .pointer_enter(0.1, None, None)
+        .hover(0.1, None, None, None, AnimationLoop::Times(2, false));
+
+    let entity = harness.spawn_styled(hovered_node(), style_builder);
+    harness.run_frames(2);
+    harness.hover(entity);
+    harness.run_frames(30);
+
+    assert_eq!(
+        completions(&harness, InteractionStyle::Hover),
+        vec![
+            AnimationCompletion::Transition,
+            AnimationCompletion::Loop(0),
+            AnimationCompletion::Loop(1),
+        ]
+    );
+}
+
+#[test]
+fn calls_on_complete() {
+    let mut harness = UiTestHarness::new();
+    harness.collect_events::<AnimationCompleted>();
+    let mut style_builder = StyleBuilder::new();
+    style_builder
+        .animated()
+        .width(hover_width())
+        .pointer_enter(0.1, None, None)
+        .on_complete(|completed, world| {
+            if completed.state == InteractionStyle::Hover {
+                world.entity_mut(completed.target).despawn_recursive();
+            }
+        });
+
+    let entity = harness.spawn_styled(hovered_node(), style_builder);
+    harness.run_frames(2);
+    harness.hover(entity);
+    assert!(harness.world().get_entity(entity).is_some());
+
+    harness.run_frames(20);
+    assert!(harness.world().get_entity(entity).is_none());
+}
@@ -0,0 +1,143 @@
+use bevy::prelude::*;
+
+use sickle_ui::{prelude::*, test_support::UiTestHarness, theme::ThemeRegistry};
+
+#[derive(Component, Clone, Debug, UiContext)]
+struct Swatch {
+    #[context("Inner")]
+    inner: Entity,
+}
+
+impl DefaultTheme for Swatch {
+    fn default_theme() -> Option<Theme<Swatch>> {
+        // Deferred, so the style can be placed to the context
+        let base_theme = PseudoTheme::deferred(None, |style_builder, _| {
+            style_builder
+                .width(Val::Px(10.))
+                .switch_target(Swatch::INNER)
+                .height(Val::Px(5.));
+        });
+        let selected_theme = PseudoTheme::build(vec![PseudoState::Selected], |style_builder| {
+            style_builder.width(Val::Px(70.));
+        });
+
+        Theme::new(vec![base_theme, selected_theme]).into()
+    }
+}
+
+/// Spawns a swatch with its own theme, under a parent with an ancestor theme
+fn spawn_swatch(harness: &mut UiTestHarness) -> (Entity, Entity, Entity) {
+    harness
+        .app()
+        .add_plugins(ComponentThemePlugin::<Swatch>::new());
+
+    harness.spawn_ui(|ui| {
+        let (mut swatch, mut inner) = (Entity::PLACEHOLDER, Entity::PLACEHOLDER);
+        let parent = ui
+            .container(NodeBundle::default(), |container| {
+                swatch = container
+                    .container(NodeBundle::default(), |swatch| {
+                        inner = swatch.spawn(NodeBundle::default()).id();
+                    })
+                    .id();
+            })
+            .insert(Theme::<Swatch>::new(vec![PseudoTheme::build(
+                None,
+                |style_builder| {
+                    style_builder.height(Val::Px(30.));
+                },
+            )]))
+            .id();
+
+        ui.commands().entity(swatch).insert((
+            Swatch { inner },
+            PseudoStates::new(),
+            Theme::<Swatch>::new(vec![PseudoTheme::build(None, |style_builder| {
+                style_builder.width(Val::Px(50.));
+            })]),
+        ));
+
+        (parent, swatch, inner)
+    })
+}
+
+fn select(harness: &mut UiTestHarness, swatch: Entity) {
+    harness
+        .world_mut()
+        .get_mut::<PseudoStates>(swatch)
+        .unwrap()
+        .add(PseudoState::Selected);
+    harness.run_frames(2);
+}
+
+#[test]
+fn theme_builds_styles_in_order_of_application() {
+    let mut harness = UiTestHarness::new();
+    let (_, swatch, inner) = spawn_swatch(&mut harness);
+    harness.run_frames(2);
+
+    // Own theme over the ancestor theme over the default theme
+    let style = harness.world().get::<Style>(swatch).unwrap();
+    assert_eq!(style.width, Val::Px(50.));
+    assert_eq!(style.height, Val::Px(30.));
+    assert_eq!(
+        harness.world().get::<Style>(inner).unwrap().height,
+        Val::Px(5.)
+    );
+
+    // Pseudo themes apply after every base theme, even from the default theme
+    select(&mut harness, swatch);
+    let style = harness.world().get::<Style>(swatch).unwrap();
+    assert_eq!(style.width, Val::Px(70.));
+    assert_eq!(style.height, Val::Px(30.));
+}
+
+#[test]
+fn theme_explains_styles() {
+    let mut harness = UiTestHarness::new();
+    let (parent, swatch, inner) = spawn_swatch(&mut harness);
+    select(&mut harness, swatch);
+
+    let world = harness.world();
+    let explanations = world.resource::<ThemeRegistry>().explain(swatch, world);
+    assert_eq!(explanations.len(), 1);
+    let explanation = &explanations[0];
+
+    assert!(explanation.component.ends_with("Swatch"));
+    assert_eq!(explanation.entity, swatch);
+    assert_eq!(explanation.themes, vec![None, Some(parent), Some(swatch)]);
+    assert_eq!(explanation.pseudo_states, vec![PseudoState::Selected]);
+
+    let candidates: Vec<(Option<Entity>, usize, Option<usize>)> = explanation
+        .candidates
+        .iter()
+        .map(|candidate| (candidate.source, candidate.score, candidate.applied))
+        .collect();
+    assert_eq!(
+        candidates,
+        vec![
+            (None, 0, Some(0)),
+            (None, 1, Some(3)),
+            (Some(parent), 0, Some(1)),
+            (Some(swatch), 0, Some(2)),
+        ]
+    );
+
+    // Context targets are merged into the placement of the themed entity
+    assert_eq!(explanation.styles.len(), 1);
+    let style = &explanation.styles[0];
+    assert_eq!((style.context, style.entity), (None, swatch));
+    let targets: Vec<Option<Entity>> = style
+        .style
+        .attributes()
+        .iter()
+        .map(|attribute| attribute.target())
+        .collect();
+    assert_eq!(targets, vec![None, Some(inner), None]);
+
+    // Entities without the themed component have nothing to explain
+    assert!(world
+        .resource::<ThemeRegistry>()
+        .explain(parent, world)
+        .is_empty());
+}
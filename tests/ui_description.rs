@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+
+use sickle_ui::{
+    prelude::*,
+    test_support::UiTestHarness,
+    ui_description::{UiDescription, UiDescriptionExt},
+};
+
+const SETTINGS: &str = r#"(
+    name: "Settings",
+    widget: Column,
+    style: [Width(Px(300.)), RowGap(Px(4.))],
+    children: [
+        (widget: Label(text: "Audio")),
+        (name: "Volume", widget: Slider(label: Some("Volume"), max: 100., value: 50.)),
+        (name: "Mute", widget: Checkbox(label: Some("Mute"), checked: true)),
+        (widget: TabContainer, children: [
+            (widget: Tab(title: "General"), name: "General Panel", children: [
+                (widget: Label(text: "General settings")),
+            ]),
+        ]),
+    ],
+)"#;
+
+fn parse(harness: &UiTestHarness, ron: &str) -> UiDescription {
+    let registry = harness.world().resource::<AppTypeRegistry>().read();
+    UiDescription::from_ron(ron.as_bytes(), &registry).unwrap()
+}
+
+#[test]
+fn spawns_widgets_from_description() {
+    let mut harness = UiTestHarness::new();
+    let description = parse(&harness, SETTINGS);
+    let handle = harness
+        .world_mut()
+        .resource_mut::<Assets<UiDescription>>()
+        .add(description);
+
+    harness.spawn_ui(|ui| ui.ui_description(handle).id());
+    harness.update();
+
+    let settings = harness.find_named("Settings").unwrap();
+    assert_eq!(
+        harness.world().get::<Style>(settings).unwrap().width,
+        Val::Px(300.)
+    );
+
+    let volume = harness.find_named("Volume").unwrap();
+    assert_eq!(harness.world().get::<Slider>(volume).unwrap().value(), 50.);
+
+    let mute = harness.find_named("Mute").unwrap();
+    assert!(harness.world().get::<Checkbox>(mute).unwrap().checked);
+
+    assert!(harness.find_named("General Panel").is_some());
+}
+
+#[test]
+fn respawns_when_description_changes() {
+    let mut harness = UiTestHarness::new();
+    let description = parse(&harness, SETTINGS);
+    let handle = harness
+        .world_mut()
+        .resource_mut::<Assets<UiDescription>>()
+        .add(description);
+
+    harness.spawn_ui(|ui| ui.ui_description(handle.clone()).id());
+    harness.update();
+    assert!(harness.find_named("Volume").is_some());
+
+    let changed = parse(&harness, r#"(name: "Brightness", widget: Slider())"#);
+    harness
+        .world_mut()
+        .resource_mut::<Assets<UiDescription>>()
+        .insert(handle, changed);
+    harness.run_frames(2);
+
+    assert!(harness.find_named("Volume").is_none());
+    assert!(harness.find_named("Brightness").is_some());
+}
+
+#[test]
+fn reports_invalid_fields() {
+    let harness = UiTestHarness::new();
+    let registry = harness.world().resource::<AppTypeRegistry>().read();
+    let error = UiDescription::from_ron(b"(widget: Row, colour: Red)", &registry).unwrap_err();
+
+    assert!(error.to_string().contains("colour"), "{}", error);
+}
+
+#[test]
+fn example_description_parses() {
+    let harness = UiTestHarness::new();
+    parse(&harness, include_str!("../assets/examples/settings.ui.ron"));
+}
@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+
+use sickle_ui::{prelude::*, test_support::UiTestHarness};
+
+#[derive(Component)]
+struct Boost;
+
+#[test]
+fn spawns_markup_through_builders() {
+    let mut harness = UiTestHarness::new();
+    let initial = 0.5;
+
+    harness.spawn_ui(|builder| {
+        ui!(builder,
+            column {
+                label("Speed")
+                slider(SliderConfig::horizontal(None, 0., 1., initial, true)).named("speed")
+                row {
+                    checkbox(Some("Boost".to_string()), false).insert(Boost)
+                }
+                .named("options")
+                .style()
+                .justify_content(JustifyContent::End)
+            }
+            .named("panel")
+        );
+    });
+
+    let panel = harness.find_named("panel").unwrap();
+    let speed = harness.find_named("speed").unwrap();
+    let options = harness.find_named("options").unwrap();
+
+    let children = harness.world().get::<Children>(panel).unwrap().to_vec();
+    assert_eq!(children.len(), 3);
+    assert_eq!(children[1], speed);
+    assert_eq!(children[2], options);
+
+    assert_eq!(harness.world().get::<Slider>(speed).unwrap().value(), 0.5);
+    assert_eq!(
+        harness
+            .world()
+            .get::<Style>(options)
+            .unwrap()
+            .justify_content,
+        JustifyContent::End
+    );
+
+    let checkbox = harness.world().get::<Children>(options).unwrap()[0];
+    assert!(harness.world().get::<Boost>(checkbox).is_some());
+    assert!(harness.world().get::<Checkbox>(checkbox).is_some());
+}
+
+#[test]
+fn reports_markup_errors() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui_macro_errors/*.rs");
+}
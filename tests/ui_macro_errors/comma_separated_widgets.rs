@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use sickle_ui::prelude::*;
+
+fn spawn(mut commands: Commands) {
+    let mut builder = commands.ui_builder(UiRoot);
+    ui!(builder,
+        column {
+            label("Speed"),
+            label("Boost")
+        }
+    );
+}
+
+fn main() {}
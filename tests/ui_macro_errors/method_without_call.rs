@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use sickle_ui::prelude::*;
+
+fn spawn(mut commands: Commands) {
+    let mut builder = commands.ui_builder(UiRoot);
+    ui!(builder,
+        label("Speed").style
+    );
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+use sickle_ui::prelude::*;
+
+fn spawn(mut commands: Commands) {
+    let mut builder = commands.ui_builder(UiRoot);
+    ui!(builder,
+        column {
+            checkbox(None)
+        }
+    );
+}
+
+fn main() {}
@@ -0,0 +1,209 @@
+use bevy::prelude::*;
+
+use sickle_ui::{prelude::*, test_support::UiTestHarness, theme::ThemeRegistry};
+
+#[derive(Component, Clone, Debug, Reflect, UiContext, Widget)]
+#[widget(children)]
+struct Card {
+    #[context("Title")]
+    title: Entity,
+    title_text: String,
+}
+
+impl From<&str> for Card {
+    fn from(title: &str) -> Self {
+        Self {
+            title: Entity::PLACEHOLDER,
+            title_text: title.into(),
+        }
+    }
+}
+
+impl DefaultTheme for Card {
+    fn default_theme() -> Option<Theme<Card>> {
+        Theme::new(vec![PseudoTheme::deferred(None, Card::primary_style)]).into()
+    }
+}
+
+impl WidgetBuilder for Card {
+    fn frame() -> impl Bundle {
+        NodeBundle::default()
+    }
+
+    fn build(&mut self, builder: &mut UiBuilder<Entity>) {
+        self.title = builder.label(self.title_text.clone()).id();
+    }
+}
+
+impl Card {
+    fn primary_style(style_builder: &mut StyleBuilder, _: &ThemeData) {
+        style_builder
+            .width(Val::Px(240.))
+            .switch_target(Card::TITLE)
+            .margin(UiRect::bottom(Val::Px(8.)));
+    }
+}
+
+#[derive(Component, Clone, Debug, Default, UiContext, Widget)]
+#[widget(method = badge_widget)]
+struct Badge;
+
+impl DefaultTheme for Badge {
+    fn default_theme() -> Option<Theme<Badge>> {
+        Theme::new(vec![PseudoTheme::build(None, |style_builder| {
+            style_builder.height(Val::Px(16.));
+        })])
+        .into()
+    }
+}
+
+impl WidgetBuilder for Badge {
+    fn frame() -> impl Bundle {
+        NodeBundle::default()
+    }
+}
+
+#[derive(Component, Clone, Debug, Default, UiContext, Widget)]
+struct UIButton;
+
+impl DefaultTheme for UIButton {
+    fn default_theme() -> Option<Theme<UIButton>> {
+        Theme::new(vec![PseudoTheme::build(None, |style_builder| {
+            style_builder.width(Val::Px(64.));
+        })])
+        .into()
+    }
+}
+
+impl WidgetBuilder for UIButton {
+    fn frame() -> impl Bundle {
+        NodeBundle::default()
+    }
+}
+
+/// Has no default theme, so it cannot be spawned
+#[derive(Component, Clone, Debug, Default, UiContext, Widget)]
+struct Unthemed;
+
+impl DefaultTheme for Unthemed {}
+
+impl WidgetBuilder for Unthemed {
+    fn frame() -> impl Bundle {
+        NodeBundle::default()
+    }
+}
+
+#[test]
+fn spawns_derived_widget() {
+    let mut harness = UiTestHarness::new();
+    harness.app().add_plugins((
+        WidgetPlugin::<Card>::default(),
+        WidgetPlugin::<Badge>::default(),
+    ));
+
+    let mut inner = Entity::PLACEHOLDER;
+    let card = harness.spawn_ui(|ui| {
+        let mut card = Entity::PLACEHOLDER;
+        ui.column(|column| {
+            card = column
+                .card("Settings", |card| {
+                    inner = card.badge_widget(Badge).id();
+                })
+                .id();
+        });
+
+        card
+    });
+    harness.run_frames(2);
+
+    let world = harness.world();
+    assert_eq!(world.get::<Name>(card).unwrap().as_str(), "Card");
+    assert!(world.get::<PseudoStates>(card).is_some());
+    assert_eq!(world.get::<Style>(card).unwrap().width, Val::Px(240.));
+
+    let title = world.get::<Card>(card).unwrap().get(Card::TITLE).unwrap();
+    assert_eq!(
+        world.get::<Text>(title).unwrap().sections[0].value,
+        "Settings"
+    );
+    assert_eq!(
+        world.get::<Style>(title).unwrap().margin.bottom,
+        Val::Px(8.)
+    );
+
+    let children = world.get::<Children>(card).unwrap();
+    assert_eq!(children.to_vec(), vec![title, inner]);
+    assert!(world.get::<Badge>(inner).is_some());
+    assert_eq!(world.get::<Style>(inner).unwrap().height, Val::Px(16.));
+}
+
+#[test]
+fn registers_theme_on_first_spawn() {
+    let mut harness = UiTestHarness::new();
+    assert!(!harness
+        .world()
+        .resource::<ThemeRegistry>()
+        .contains::<UIButton>());
+
+    let button = harness.spawn_ui(|ui| {
+        let mut button = Entity::PLACEHOLDER;
+        ui.column(|column| {
+            button = column.ui_button(UIButton).id();
+            column.ui_button(UIButton);
+        });
+
+        button
+    });
+    harness.run_frames(2);
+
+    let world = harness.world();
+    assert!(world.resource::<ThemeRegistry>().contains::<UIButton>());
+    assert_eq!(world.get::<Name>(button).unwrap().as_str(), "UIButton");
+    assert_eq!(world.get::<Style>(button).unwrap().width, Val::Px(64.));
+}
+
+#[test]
+#[should_panic(expected = "widget Unthemed has no default theme")]
+fn spawning_widget_without_default_theme_panics() {
+    let mut harness = UiTestHarness::new();
+    harness.spawn_ui(|ui| {
+        ui.column(|column| {
+            column.unthemed(Unthemed);
+        });
+    });
+    harness.run_frames(1);
+}
+
+#[derive(Component, Clone, Debug, UiContext)]
+struct Toolbar {
+    #[context("Icon")]
+    icon: Entity,
+    #[context("Menu", no_clear)]
+    menu: Entity,
+    #[context("DragHandle", const = HANDLE)]
+    drag_handle_entity: Entity,
+    #[allow(dead_code)]
+    label: String,
+}
+
+#[test]
+fn derives_ui_context() {
+    let toolbar = Toolbar {
+        icon: Entity::from_raw(1),
+        menu: Entity::from_raw(2),
+        drag_handle_entity: Entity::from_raw(3),
+        label: "Tools".into(),
+    };
+
+    assert_eq!(Toolbar::ICON, "Icon");
+    assert_eq!(Toolbar::MENU, "Menu");
+    assert_eq!(Toolbar::HANDLE, "DragHandle");
+
+    assert_eq!(toolbar.get(Toolbar::ICON), Ok(toolbar.icon));
+    assert_eq!(toolbar.get(Toolbar::MENU), Ok(toolbar.menu));
+    assert_eq!(toolbar.get("DragHandle"), Ok(toolbar.drag_handle_entity));
+    assert!(toolbar.get("Label").is_err());
+
+    assert_eq!(toolbar.contexts(), vec!["Icon", "Menu", "DragHandle"]);
+    assert_eq!(toolbar.cleared_contexts(), vec!["Icon", "DragHandle"]);
+}
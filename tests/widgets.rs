@@ -0,0 +1,1406 @@
+use bevy::{
+    a11y::{accesskit::Role, AccessibilityNode},
+    ecs::system::CommandQueue,
+    prelude::*,
+    ui::RelativeCursorPosition,
+};
+
+use sickle_ui::{
+    assert_ui_snapshot,
+    drag_interaction::DragGhostFrame,
+    drop_interaction::DragPayloadTypes,
+    prelude::*,
+    test_support::UiTestHarness,
+    widgets::{
+        inputs::{radio_group::RadioButton, slider::SliderDragHandle},
+        layout::{
+            docking_zone::DockingZone,
+            floating_panel::FloatingPanelTitle,
+            tab_container::{CloseTabContextMenu, Tab, TabContainer},
+        },
+        menus::toggle_menu_item::ToggleMenuItem,
+    },
+};
+
+fn spawn_in_column(
+    harness: &mut UiTestHarness,
+    spawn: impl FnOnce(&mut UiBuilder<Entity>) -> Entity,
+) -> Entity {
+    harness.spawn_ui(|ui| {
+        let mut entity = Entity::PLACEHOLDER;
+        ui.column(|column| {
+            column.style().width(Val::Px(400.));
+            entity = spawn(column);
+        });
+
+        entity
+    })
+}
+
+/// Spawns a tab container filling the window with a tab per title
+fn spawn_tab_container(harness: &mut UiTestHarness, titles: &[&str]) -> Entity {
+    harness.spawn_ui(|ui| {
+        let mut tab_container = Entity::PLACEHOLDER;
+        ui.column(|column| {
+            column
+                .style()
+                .width(Val::Percent(100.))
+                .height(Val::Percent(100.));
+
+            tab_container = column
+                .tab_container(|tabs| {
+                    for title in titles {
+                        tabs.add_tab(title.to_string(), |panel| {
+                            panel.label(LabelConfig::from(*title));
+                        });
+                    }
+                })
+                .id();
+        });
+
+        tab_container
+    })
+}
+
+/// Tabs of the container in the order of the tab bar
+fn tabs_in_bar(harness: &mut UiTestHarness, tab_container: Entity) -> Vec<Entity> {
+    let bar = harness
+        .world()
+        .get::<TabContainer>(tab_container)
+        .unwrap()
+        .bar_id();
+    let children: Vec<Entity> = harness.world().get::<Children>(bar).unwrap().to_vec();
+
+    children
+        .into_iter()
+        .filter(|child| harness.world().get::<Tab>(*child).is_some())
+        .collect()
+}
+
+fn is_selected(harness: &UiTestHarness, entity: Entity) -> bool {
+    harness
+        .world()
+        .get::<PseudoStates>(entity)
+        .is_some_and(|states| states.has(&PseudoState::Selected))
+}
+
+fn slider_handle(harness: &mut UiTestHarness, slider: Entity) -> Entity {
+    harness
+        .world_mut()
+        .query::<(Entity, &SliderDragHandle)>()
+        .iter(harness.world())
+        .find(|(_, handle)| handle.slider == slider)
+        .map(|(entity, _)| entity)
+        .expect("slider has no drag handle")
+}
+
+#[test]
+fn checkbox_toggles_on_click() {
+    let mut harness = UiTestHarness::new();
+    let checkbox = spawn_in_column(&mut harness, |column| {
+        column.checkbox("Check".to_string(), false).id()
+    });
+
+    harness.click(checkbox);
+    assert!(harness.world().get::<Checkbox>(checkbox).unwrap().checked);
+
+    harness.click(checkbox);
+    assert!(!harness.world().get::<Checkbox>(checkbox).unwrap().checked);
+}
+
+#[test]
+fn text_input_receives_text_while_focused() {
+    let mut harness = UiTestHarness::new();
+    let input = spawn_in_column(&mut harness, |column| column.text_input("", "Name").id());
+
+    harness.type_text("ignored");
+    assert_eq!(harness.world().get::<TextInput>(input).unwrap().value, "");
+
+    harness.click(input);
+    assert!(harness.world().get::<TextInput>(input).unwrap().focused());
+
+    harness.type_text("sickle");
+    assert_eq!(
+        harness.world().get::<TextInput>(input).unwrap().value,
+        "sickle"
+    );
+
+    harness.press_keys([KeyCode::Enter]);
+    assert!(!harness.world().get::<TextInput>(input).unwrap().focused());
+}
+
+#[test]
+fn text_input_deletes_with_backspace_key() {
+    let mut harness = UiTestHarness::new();
+    let input = spawn_in_column(&mut harness, |column| column.text_input("", "Name").id());
+
+    harness.click(input);
+    harness.type_text("sickle");
+    harness.press_keys([KeyCode::Backspace]);
+    assert_eq!(
+        harness.world().get::<TextInput>(input).unwrap().value,
+        "sickl"
+    );
+
+    // Control characters sent for backspace or delete are not inserted
+    harness.type_text("\u{8}\u{7f}");
+    assert_eq!(
+        harness.world().get::<TextInput>(input).unwrap().value,
+        "sickl"
+    );
+}
+
+#[test]
+fn text_input_reports_changes() {
+    let mut harness = UiTestHarness::new();
+    harness.collect_events::<TextInputChanged>();
+    let input = spawn_in_column(&mut harness, |column| column.text_input("", "Name").id());
+
+    harness.click(input);
+    harness.type_text("ab");
+    harness.press_keys([KeyCode::Backspace]);
+    harness
+        .world_mut()
+        .get_mut::<TextInput>(input)
+        .unwrap()
+        .value = "code".into();
+    harness.run_frames(2);
+
+    let changes: Vec<(String, ValueChangeSource)> = harness
+        .collected_events::<TextInputChanged>()
+        .iter()
+        .map(|event| {
+            assert_eq!(event.entity, input);
+            (event.value.clone(), event.source)
+        })
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            ("ab".into(), ValueChangeSource::User),
+            ("a".into(), ValueChangeSource::User),
+            ("code".into(), ValueChangeSource::Code),
+        ]
+    );
+}
+
+#[test]
+fn text_input_theme_and_accessibility() {
+    let mut harness = UiTestHarness::new();
+    let input = spawn_in_column(&mut harness, |column| column.text_input("", "Name").id());
+    harness.run_frames(2);
+
+    assert!(harness.world().get::<DynamicStyle>(input).is_some());
+    let pseudo_states = harness.world().get::<PseudoStates>(input).unwrap();
+    assert!(pseudo_states.has(&PseudoState::Empty));
+
+    let node = harness.world().get::<AccessibilityNode>(input).unwrap();
+    assert_eq!(node.role(), Role::TextInput);
+    assert_eq!(node.name(), Some("Name"));
+
+    harness.click(input);
+    harness.type_text("sickle");
+
+    let pseudo_states = harness.world().get::<PseudoStates>(input).unwrap();
+    assert!(pseudo_states.has(&PseudoState::Selected));
+    assert!(!pseudo_states.has(&PseudoState::Empty));
+
+    let node = harness.world().get::<AccessibilityNode>(input).unwrap();
+    assert_eq!(node.value(), Some("sickle"));
+}
+
+#[test]
+fn slider_follows_drag() {
+    let mut harness = UiTestHarness::new();
+    let slider = spawn_in_column(&mut harness, |column| {
+        column
+            .slider(SliderConfig::horizontal(None, 0., 100., 0., false))
+            .id()
+    });
+
+    let handle = slider_handle(&mut harness, slider);
+    let from = harness.center(handle);
+    harness.drag(from, from + Vec2::new(1000., 0.));
+
+    let value = harness.world().get::<Slider>(slider).unwrap().value();
+    assert_eq!(value, 100.);
+}
+
+#[test]
+fn slider_follows_scroll() {
+    let mut harness = UiTestHarness::new();
+    let slider = spawn_in_column(&mut harness, |column| {
+        column
+            .slider(SliderConfig::horizontal(None, 0., 100., 50., false))
+            .id()
+    });
+
+    let handle = slider_handle(&mut harness, slider);
+    harness.hover(handle);
+    harness.scroll(Vec2::new(0., 10.));
+
+    let value = harness.world().get::<Slider>(slider).unwrap().value();
+    assert!(value > 50., "slider value {} did not increase", value);
+}
+
+#[test]
+fn checkbox_reports_changes() {
+    let mut harness = UiTestHarness::new();
+    harness.collect_events::<CheckboxChanged>();
+    let checkbox = spawn_in_column(&mut harness, |column| {
+        column.checkbox("Check".to_string(), false).id()
+    });
+
+    harness.click(checkbox);
+    harness
+        .world_mut()
+        .get_mut::<Checkbox>(checkbox)
+        .unwrap()
+        .checked = false;
+    harness.run_frames(2);
+
+    let changes: Vec<(bool, ValueChangeSource)> = harness
+        .collected_events::<CheckboxChanged>()
+        .iter()
+        .map(|event| {
+            assert_eq!(event.entity, checkbox);
+            (event.value, event.source)
+        })
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            (true, ValueChangeSource::User),
+            (false, ValueChangeSource::Code)
+        ]
+    );
+}
+
+#[test]
+fn slider_reports_changes() {
+    let mut harness = UiTestHarness::new();
+    harness.collect_events::<SliderChanged>();
+    let slider = spawn_in_column(&mut harness, |column| {
+        column
+            .slider(SliderConfig::horizontal(None, 0., 100., 0., false))
+            .id()
+    });
+
+    let handle = slider_handle(&mut harness, slider);
+    let from = harness.center(handle);
+    harness.drag(from, from + Vec2::new(1000., 0.));
+    harness
+        .world_mut()
+        .get_mut::<Slider>(slider)
+        .unwrap()
+        .set_value(25.);
+    harness.run_frames(2);
+
+    let changes: Vec<(f32, ValueChangeSource)> = harness
+        .collected_events::<SliderChanged>()
+        .iter()
+        .map(|event| {
+            assert_eq!(event.entity, slider);
+            (event.value, event.source)
+        })
+        .collect();
+    let (code_change, user_changes) = changes.split_last().unwrap();
+    assert!(!user_changes.is_empty());
+    assert!(user_changes
+        .iter()
+        .all(|(_, source)| *source == ValueChangeSource::User));
+    assert_eq!(user_changes.last().unwrap().0, 100.);
+    assert_eq!(*code_change, (25., ValueChangeSource::Code));
+}
+
+#[test]
+fn dropdown_reports_changes() {
+    let mut harness = UiTestHarness::new();
+    harness.collect_events::<DropdownChanged>();
+    let dropdown = spawn_in_column(&mut harness, |column| {
+        column.dropdown(vec!["A", "B", "C"], None).id()
+    });
+
+    harness.click(dropdown);
+    let option = harness
+        .find_named("Option 1")
+        .expect("dropdown did not open");
+    harness.click(option);
+    harness
+        .world_mut()
+        .get_mut::<Dropdown>(dropdown)
+        .unwrap()
+        .set_value(0);
+    harness.run_frames(2);
+
+    let changes: Vec<(Option<usize>, ValueChangeSource)> = harness
+        .collected_events::<DropdownChanged>()
+        .iter()
+        .map(|event| {
+            assert_eq!(event.entity, dropdown);
+            (event.value, event.source)
+        })
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            (Some(1), ValueChangeSource::User),
+            (Some(0), ValueChangeSource::Code)
+        ]
+    );
+}
+
+#[test]
+fn radio_group_reports_changes() {
+    let mut harness = UiTestHarness::new();
+    harness.collect_events::<RadioGroupChanged>();
+    let radio_group = spawn_in_column(&mut harness, |column| {
+        column.radio_group(vec!["A", "B", "C"], None, false).id()
+    });
+
+    let button = harness
+        .world_mut()
+        .query::<(Entity, &RadioButton)>()
+        .iter(harness.world())
+        .find(|(_, button)| button.index == 1)
+        .map(|(entity, _)| entity)
+        .unwrap();
+    harness.click(button);
+    harness
+        .world_mut()
+        .get_mut::<RadioGroup>(radio_group)
+        .unwrap()
+        .select(0);
+    harness.run_frames(2);
+
+    let changes: Vec<(Option<usize>, ValueChangeSource)> = harness
+        .collected_events::<RadioGroupChanged>()
+        .iter()
+        .map(|event| {
+            assert_eq!(event.entity, radio_group);
+            (event.value, event.source)
+        })
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            (Some(1), ValueChangeSource::User),
+            (Some(0), ValueChangeSource::Code)
+        ]
+    );
+}
+
+#[test]
+fn toggle_menu_item_reports_changes() {
+    let mut harness = UiTestHarness::new();
+    harness.collect_events::<ToggleMenuItemChanged>();
+    let item = spawn_in_column(&mut harness, |column| {
+        column
+            .toggle_menu_item(ToggleMenuItemConfig {
+                name: "Toggle".into(),
+                shortcut: vec![KeyCode::KeyT].into(),
+                ..default()
+            })
+            .id()
+    });
+
+    harness.click(item);
+    harness.press_keys([KeyCode::KeyT]);
+    harness
+        .world_mut()
+        .get_mut::<ToggleMenuItem>(item)
+        .unwrap()
+        .checked = true;
+    harness.run_frames(2);
+
+    let changes: Vec<(bool, ValueChangeSource)> = harness
+        .collected_events::<ToggleMenuItemChanged>()
+        .iter()
+        .map(|event| {
+            assert_eq!(event.entity, item);
+            (event.value, event.source)
+        })
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            (true, ValueChangeSource::User),
+            (false, ValueChangeSource::User),
+            (true, ValueChangeSource::Code)
+        ]
+    );
+}
+
+#[test]
+fn menu_item_reports_presses() {
+    let mut harness = UiTestHarness::new();
+    harness.collect_events::<MenuItemPressed>();
+    let item = spawn_in_column(&mut harness, |column| {
+        column
+            .menu_item(MenuItemConfig {
+                name: "Item".into(),
+                shortcut: vec![KeyCode::KeyI].into(),
+                ..default()
+            })
+            .id()
+    });
+
+    harness.click(item);
+    harness.press_keys([KeyCode::KeyI]);
+
+    let presses: Vec<bool> = harness
+        .collected_events::<MenuItemPressed>()
+        .iter()
+        .map(|event| {
+            assert_eq!(event.entity, item);
+            event.shortcut
+        })
+        .collect();
+    assert_eq!(presses, vec![false, true]);
+}
+
+#[derive(Resource, Default)]
+struct CheckboxCallbacks(Vec<(Entity, bool, ValueChangeSource)>);
+
+#[test]
+fn widget_event_callbacks_run_for_their_entity() {
+    let mut harness = UiTestHarness::new();
+    harness.app().init_resource::<CheckboxCallbacks>();
+
+    let (observed, other) = harness.spawn_ui(|ui| {
+        let mut checkboxes = (Entity::PLACEHOLDER, Entity::PLACEHOLDER);
+        ui.column(|column| {
+            column.style().width(Val::Px(400.));
+            checkboxes.0 = column
+                .checkbox("Observed".to_string(), false)
+                .on_event(|changed: &CheckboxChanged, commands| {
+                    let entry = (changed.entity, changed.value, changed.source);
+                    commands.add(move |world: &mut World| {
+                        world.resource_mut::<CheckboxCallbacks>().0.push(entry);
+                    });
+                })
+                .id();
+            checkboxes.1 = column.checkbox("Other".to_string(), false).id();
+        });
+
+        checkboxes
+    });
+
+    harness.click(observed);
+    harness.click(other);
+    harness
+        .world_mut()
+        .get_mut::<Checkbox>(observed)
+        .unwrap()
+        .checked = false;
+    harness.run_frames(2);
+
+    assert_eq!(
+        harness.world().resource::<CheckboxCallbacks>().0,
+        vec![
+            (observed, true, ValueChangeSource::User),
+            (observed, false, ValueChangeSource::Code)
+        ]
+    );
+}
+#[test]
+fn checkbox_snapshot() {
+    let mut harness = UiTestHarness::new();
+    let checkbox = spawn_in_column(&mut harness, |column| {
+        column.checkbox("Check".to_string(), false).id()
+    });
+
+    harness.click(checkbox);
+    assert_ui_snapshot!("checkbox_checked", harness.snapshot(checkbox));
+}
+
+#[test]
+fn dropdown_snapshot() {
+    let mut harness = UiTestHarness::new();
+    let dropdown = spawn_in_column(&mut harness, |column| {
+        column.dropdown(vec!["Small", "Medium", "Large"], 1).id()
+    });
+
+    harness.click(dropdown);
+    harness.run_frames(30);
+    assert_ui_snapshot!("dropdown_open", harness.snapshot(dropdown));
+}
+
+#[test]
+fn checkbox_contexts() {
+    let mut harness = UiTestHarness::new();
+    let checkbox = spawn_in_column(&mut harness, |column| {
+        column.checkbox("Check".to_string(), false).id()
+    });
+
+    let checkbox = harness.world().get::<Checkbox>(checkbox).unwrap();
+    assert_eq!(
+        checkbox.contexts(),
+        vec![
+            Checkbox::CHECKMARK_BACKGROUND,
+            Checkbox::CHECKMARK,
+            Checkbox::LABEL
+        ]
+    );
+    assert_eq!(checkbox.cleared_contexts(), checkbox.contexts());
+
+    let label = checkbox.get(Checkbox::LABEL).unwrap();
+    assert!(harness.world().get::<Text>(label).is_some());
+    assert!(checkbox.get("Missing").is_err());
+}
+
+#[test]
+fn tab_container_adds_and_selects_tabs() {
+    let mut harness = UiTestHarness::new();
+    let tab_container = spawn_tab_container(&mut harness, &["First", "Second", "Third"]);
+
+    let container = harness.world().get::<TabContainer>(tab_container).unwrap();
+    assert_eq!(container.tab_count(), 3);
+
+    let tabs = tabs_in_bar(&mut harness, tab_container);
+    assert_eq!(tabs.len(), 3);
+    assert!(is_selected(&harness, tabs[0]));
+
+    harness.click(tabs[1]);
+    assert!(!is_selected(&harness, tabs[0]));
+    assert!(is_selected(&harness, tabs[1]));
+}
+
+#[test]
+fn tab_container_reorders_dragged_tab() {
+    let mut harness = UiTestHarness::new();
+    let tab_container = spawn_tab_container(&mut harness, &["First", "Second", "Third"]);
+    let tabs = tabs_in_bar(&mut harness, tab_container);
+
+    let from = harness.center(tabs[0]);
+    let to = harness.rect(tabs[2]).max - Vec2::new(2., 2.);
+    harness.drag(from, Vec2::new(to.x, from.y));
+    harness.run_frames(2);
+
+    let reordered = tabs_in_bar(&mut harness, tab_container);
+    assert_eq!(reordered.len(), 3);
+    assert_eq!(reordered.last(), Some(&tabs[0]));
+}
+
+#[test]
+fn tab_container_closes_tab_from_context_menu() {
+    let mut harness = UiTestHarness::new();
+    let tab_container = spawn_tab_container(&mut harness, &["First", "Second"]);
+    let tabs = tabs_in_bar(&mut harness, tab_container);
+
+    harness.hover(tabs[1]);
+    harness.press_mouse(MouseButton::Right);
+    harness.release_mouse(MouseButton::Right);
+    harness.run_frames(2);
+
+    let close_item = harness
+        .world_mut()
+        .query_filtered::<Entity, With<CloseTabContextMenu>>()
+        .single(harness.world());
+    harness.click(close_item);
+    harness.run_frames(2);
+
+    assert!(harness.world().get_entity(tabs[1]).is_none());
+    assert_eq!(tabs_in_bar(&mut harness, tab_container), vec![tabs[0]]);
+    let container = harness.world().get::<TabContainer>(tab_container).unwrap();
+    assert_eq!(container.tab_count(), 1);
+}
+
+#[test]
+fn docking_zone_splits_on_side_drop() {
+    let mut harness = UiTestHarness::new();
+    let zone = harness.spawn_ui(|ui| {
+        let mut zone = Entity::PLACEHOLDER;
+        ui.column(|column| {
+            column
+                .style()
+                .width(Val::Percent(100.))
+                .height(Val::Percent(100.));
+
+            let config = SizedZoneConfig {
+                size: 100.,
+                ..default()
+            };
+            column.docking_zone_split(config, |split| {
+                for name in ["Left", "Right"] {
+                    let id = split
+                        .docking_zone(
+                            SizedZoneConfig {
+                                size: 50.,
+                                ..default()
+                            },
+                            false,
+                            |tabs| {
+                                tabs.add_tab(name.into(), |panel| {
+                                    panel.label(LabelConfig::from(name));
+                                });
+                            },
+                        )
+                        .id();
+                    if zone == Entity::PLACEHOLDER {
+                        zone = id;
+                    }
+                }
+            });
+
+            column.floating_panel(
+                FloatingPanelConfig {
+                    title: Some("Floating".into()),
+                    ..default()
+                },
+                FloatingPanelLayout {
+                    size: Vec2::new(200., 150.),
+                    position: Vec2::new(300., 200.).into(),
+                    droppable: true,
+                },
+                |panel| {
+                    panel.label(LabelConfig::from("Floating"));
+                },
+            );
+        });
+
+        zone
+    });
+    harness.run_frames(2);
+
+    let title = harness
+        .world_mut()
+        .query_filtered::<Entity, With<FloatingPanelTitle>>()
+        .single(harness.world());
+    let zone_rect = harness.rect(zone);
+    let from = harness.center(title);
+    let to = Vec2::new(zone_rect.max.x - 20., zone_rect.center().y);
+    harness.drag(from, to);
+    harness.run_frames(4);
+
+    let zones: Vec<Entity> = harness
+        .world_mut()
+        .query_filtered::<Entity, With<DockingZone>>()
+        .iter(harness.world())
+        .collect();
+    assert_eq!(zones.len(), 3);
+    assert!(harness
+        .world_mut()
+        .query::<&FloatingPanelTitle>()
+        .iter(harness.world())
+        .next()
+        .is_none());
+
+    let docked_rect = harness.rect(zone);
+    assert!(docked_rect.width() < zone_rect.width());
+}
+
+fn is_right_to_left(harness: &UiTestHarness, entity: Entity) -> bool {
+    harness
+        .world()
+        .get::<PseudoStates>(entity)
+        .is_some_and(|states| states.has(&PseudoState::RightToLeft))
+}
+
+#[test]
+fn reparented_nodes_follow_layout_direction() {
+    let mut harness = UiTestHarness::new();
+    let (rtl_root, ltr_root, moved, nested) = harness.spawn_ui(|ui| {
+        let rtl_root = ui
+            .column(|_| {})
+            .insert(LayoutDirectionOverride(LayoutDirection::RightToLeft))
+            .id();
+
+        let mut moved = Entity::PLACEHOLDER;
+        let mut nested = Entity::PLACEHOLDER;
+        let ltr_root = ui
+            .column(|column| {
+                moved = column
+                    .row(|row| {
+                        nested = row.label(LabelConfig::from("Nested")).id();
+                    })
+                    .id();
+            })
+            .id();
+
+        (rtl_root, ltr_root, moved, nested)
+    });
+    harness.run_frames(2);
+
+    assert!(is_right_to_left(&harness, rtl_root));
+    assert!(!is_right_to_left(&harness, moved));
+    assert!(!is_right_to_left(&harness, nested));
+
+    harness.world_mut().entity_mut(moved).set_parent(rtl_root);
+    harness.run_frames(2);
+    assert!(is_right_to_left(&harness, moved));
+    assert!(is_right_to_left(&harness, nested));
+
+    harness.world_mut().entity_mut(moved).set_parent(ltr_root);
+    harness.run_frames(2);
+    assert!(!is_right_to_left(&harness, moved));
+    assert!(!is_right_to_left(&harness, nested));
+
+    harness.world_mut().entity_mut(moved).set_parent(rtl_root);
+    harness.run_frames(2);
+    harness.world_mut().entity_mut(moved).remove_parent();
+    harness.run_frames(2);
+    assert!(!is_right_to_left(&harness, moved));
+    assert!(!is_right_to_left(&harness, nested));
+}
+
+/// Spawns a 300 by 200 scroll view with a single content node of `content_size`
+fn spawn_scroll_view(
+    harness: &mut UiTestHarness,
+    restrict_to: Option<ScrollAxis>,
+    content_size: Vec2,
+) -> Entity {
+    harness.spawn_ui(|ui| {
+        let mut scroll_view = Entity::PLACEHOLDER;
+        ui.column(|column| {
+            column.style().width(Val::Px(300.)).height(Val::Px(200.));
+
+            scroll_view = column
+                .scroll_view(restrict_to, |content| {
+                    content
+                        .container(NodeBundle::default(), |_| {})
+                        .insert(Name::new("Scrolled"))
+                        .style()
+                        .width(Val::Px(content_size.x))
+                        .height(Val::Px(content_size.y))
+                        .flex_shrink(0.);
+                })
+                .id();
+        });
+
+        scroll_view
+    })
+}
+
+fn scroll_view(harness: &UiTestHarness, entity: Entity) -> &ScrollView {
+    harness.world().get::<ScrollView>(entity).unwrap()
+}
+
+#[test]
+fn scroll_view_keeps_axes_without_overflow_at_zero() {
+    let mut harness = UiTestHarness::new();
+    harness.collect_events::<ScrollViewOffsetChanged>();
+    let vertical = spawn_scroll_view(&mut harness, None, Vec2::new(100., 1000.));
+    harness.run_frames(2);
+
+    let viewport = scroll_view(&harness, vertical).viewport_id();
+    harness.hover(viewport);
+    harness.scroll(Vec2::new(-40., -50.));
+    harness.run_frames(15);
+    assert_eq!(
+        scroll_view(&harness, vertical).scroll_offset(),
+        Vec2::new(0., 50.)
+    );
+
+    harness.scroll(Vec2::new(-40., 0.));
+    harness.run_frames(15);
+    assert_eq!(
+        scroll_view(&harness, vertical).scroll_offset(),
+        Vec2::new(0., 50.)
+    );
+
+    let events = harness.collected_events::<ScrollViewOffsetChanged>();
+    assert!(!events.is_empty());
+    assert!(events.iter().all(|event| event.offset.x == 0.));
+}
+
+#[test]
+fn scroll_view_smooths_wheel_scrolling() {
+    let mut harness = UiTestHarness::new();
+    let smooth = spawn_scroll_view(&mut harness, None, Vec2::new(100., 1000.));
+    harness.run_frames(2);
+
+    let viewport = scroll_view(&harness, smooth).viewport_id();
+    harness.hover(viewport);
+    harness.scroll(Vec2::new(0., -100.));
+    let offset = scroll_view(&harness, smooth).scroll_offset().y;
+    assert!(
+        offset > 0. && offset < 100.,
+        "offset {} was not interpolated",
+        offset
+    );
+
+    harness.run_frames(10);
+    assert_eq!(scroll_view(&harness, smooth).scroll_offset().y, 100.);
+
+    harness
+        .world_mut()
+        .get_mut::<ScrollView>(smooth)
+        .unwrap()
+        .smooth_scroll = false;
+    harness.scroll(Vec2::new(0., 50.));
+    assert_eq!(scroll_view(&harness, smooth).scroll_offset().y, 50.);
+}
+
+#[test]
+fn scroll_view_keeps_touch_drag_momentum() {
+    let mut harness = UiTestHarness::new();
+    let kinetic = spawn_scroll_view(&mut harness, None, Vec2::new(100., 1000.));
+    harness.run_frames(2);
+
+    let center = harness.center(scroll_view(&harness, kinetic).viewport_id());
+    harness.touch_drag(center, center - Vec2::new(0., 80.));
+    let released = scroll_view(&harness, kinetic).scroll_offset().y;
+    assert!(released >= 80., "touch drag scrolled to {}", released);
+
+    harness.run_frames(150);
+    let coasted = scroll_view(&harness, kinetic).scroll_offset().y;
+    assert!(coasted > released, "offset {} did not coast", coasted);
+
+    harness.run_frames(10);
+    assert_eq!(scroll_view(&harness, kinetic).scroll_offset().y, coasted);
+
+    harness
+        .world_mut()
+        .get_mut::<ScrollView>(kinetic)
+        .unwrap()
+        .kinetic_scroll = false;
+    harness.touch_drag(center, center - Vec2::new(0., 80.));
+    let released = scroll_view(&harness, kinetic).scroll_offset().y;
+    harness.run_frames(10);
+    assert_eq!(scroll_view(&harness, kinetic).scroll_offset().y, released);
+}
+
+/// Spawns a 300 by 200 scroll view with a 1000 pixel tall spacer above the returned target
+fn spawn_scroll_target(harness: &mut UiTestHarness, display: Display) -> (Entity, Entity) {
+    harness.spawn_ui(|ui| {
+        let mut scroll_view = Entity::PLACEHOLDER;
+        let mut target = Entity::PLACEHOLDER;
+        ui.column(|column| {
+            column.style().width(Val::Px(300.)).height(Val::Px(200.));
+
+            scroll_view = column
+                .scroll_view(ScrollAxis::Vertical, |content| {
+                    content
+                        .container(NodeBundle::default(), |_| {})
+                        .style()
+                        .width(Val::Px(100.))
+                        .height(Val::Px(1000.))
+                        .flex_shrink(0.);
+                    target = content
+                        .container(NodeBundle::default(), |_| {})
+                        .insert(Name::new("Target"))
+                        .style()
+                        .display(display)
+                        .width(Val::Px(100.))
+                        .height(Val::Px(50.))
+                        .flex_shrink(0.)
+                        .id();
+                })
+                .id();
+        });
+
+        (scroll_view, target)
+    })
+}
+
+fn scroll_into_view(harness: &mut UiTestHarness, entity: Entity) {
+    let mut queue = CommandQueue::default();
+    Commands::new(&mut queue, harness.world())
+        .entity(entity)
+        .scroll_into_view();
+    queue.apply(harness.world_mut());
+}
+
+#[test]
+fn scroll_into_view_gives_up_on_hidden_targets() {
+    let mut harness = UiTestHarness::new();
+    let (hidden_view, hidden) = spawn_scroll_target(&mut harness, Display::None);
+    harness.run_frames(2);
+
+    scroll_into_view(&mut harness, hidden);
+    harness.run_frames(70);
+    assert_eq!(
+        scroll_view(&harness, hidden_view).scroll_offset(),
+        Vec2::ZERO
+    );
+
+    // The dropped request doesn't apply once the target is shown
+    harness
+        .world_mut()
+        .get_mut::<Style>(hidden)
+        .unwrap()
+        .display = Display::Flex;
+    harness.run_frames(20);
+    assert_eq!(
+        scroll_view(&harness, hidden_view).scroll_offset(),
+        Vec2::ZERO
+    );
+}
+
+fn scroll_view_mut(harness: &mut UiTestHarness, entity: Entity) -> Mut<'_, ScrollView> {
+    harness.world_mut().get_mut::<ScrollView>(entity).unwrap()
+}
+
+#[test]
+fn scroll_view_scrolls_programmatically() {
+    let mut harness = UiTestHarness::new();
+    let entity = spawn_scroll_view(&mut harness, None, Vec2::new(100., 1000.));
+    harness.run_frames(2);
+    assert_eq!(scroll_view(&harness, entity).overflow().y, 800.);
+
+    scroll_view_mut(&mut harness, entity).smooth_scroll = false;
+    scroll_view_mut(&mut harness, entity).scroll_to(Vec2::new(0., 300.));
+    harness.update();
+    assert_eq!(scroll_view(&harness, entity).scroll_offset().y, 300.);
+
+    scroll_view_mut(&mut harness, entity).scroll_by(Vec2::new(0., 1000.));
+    harness.update();
+    assert_eq!(scroll_view(&harness, entity).scroll_offset().y, 800.);
+
+    scroll_view_mut(&mut harness, entity).scroll_to(Vec2::new(0., -50.));
+    harness.update();
+    assert_eq!(scroll_view(&harness, entity).scroll_offset().y, 0.);
+
+    // Animated: scrolling again continues from the target of the ongoing animation
+    scroll_view_mut(&mut harness, entity).smooth_scroll = true;
+    scroll_view_mut(&mut harness, entity).scroll_to(Vec2::new(0., 100.));
+    scroll_view_mut(&mut harness, entity).scroll_by(Vec2::new(0., 100.));
+    harness.update();
+    let offset = scroll_view(&harness, entity).scroll_offset().y;
+    assert!(
+        offset > 0. && offset < 200.,
+        "offset {} was not animated",
+        offset
+    );
+
+    harness.run_frames(10);
+    assert_eq!(scroll_view(&harness, entity).scroll_offset().y, 200.);
+}
+
+#[test]
+fn scroll_view_applies_offset_requested_before_layout() {
+    let mut harness = UiTestHarness::new();
+    let entity = harness.spawn_ui(|ui| {
+        let mut scroll_view = Entity::PLACEHOLDER;
+        ui.column(|column| {
+            column.style().width(Val::Px(300.)).height(Val::Px(200.));
+
+            scroll_view = column
+                .scroll_view(None, |content| {
+                    content
+                        .container(NodeBundle::default(), |_| {})
+                        .style()
+                        .width(Val::Px(100.))
+                        .height(Val::Px(1000.))
+                        .flex_shrink(0.);
+                })
+                .id();
+        });
+
+        ui.commands().add(move |world: &mut World| {
+            let mut scroll_view = world.get_mut::<ScrollView>(scroll_view).unwrap();
+            assert_eq!(scroll_view.overflow(), Vec2::ZERO);
+            scroll_view.scroll_to(Vec2::new(0., 300.));
+            scroll_view.scroll_by(Vec2::new(50., 100.));
+        });
+
+        scroll_view
+    });
+    harness.run_frames(2);
+
+    assert_eq!(
+        scroll_view(&harness, entity).scroll_offset(),
+        Vec2::new(0., 400.)
+    );
+}
+#[test]
+fn scroll_view_reports_offset_changes() {
+    let mut harness = UiTestHarness::new();
+    harness.collect_events::<ScrollViewOffsetChanged>();
+    let entity = spawn_scroll_view(&mut harness, None, Vec2::new(100., 1000.));
+    harness.run_frames(2);
+
+    scroll_view_mut(&mut harness, entity).scroll_to(Vec2::new(0., 400.));
+    harness.run_frames(15);
+
+    let events = harness.collected_events::<ScrollViewOffsetChanged>();
+    assert!(
+        events.len() > 1,
+        "smooth scroll sent {} events",
+        events.len()
+    );
+    assert!(events.iter().all(|event| event.scroll_view == entity));
+    assert_eq!(events[0].previous, Vec2::ZERO);
+    assert_eq!(events.last().unwrap().offset, Vec2::new(0., 400.));
+    for pair in events.windows(2) {
+        assert_eq!(pair[1].previous, pair[0].offset);
+        assert!(pair[1].offset.y > pair[0].offset.y);
+    }
+
+    // Setting the same offset again doesn't report a change
+    let sent = events.len();
+    scroll_view_mut(&mut harness, entity).scroll_to(Vec2::new(0., 400.));
+    harness.run_frames(15);
+    assert_eq!(
+        harness.collected_events::<ScrollViewOffsetChanged>().len(),
+        sent
+    );
+}
+
+#[test]
+fn scroll_into_view_reveals_target() {
+    let mut harness = UiTestHarness::new();
+    let (entity, target) = spawn_scroll_target(&mut harness, Display::Flex);
+    harness.run_frames(2);
+
+    let viewport = harness.rect(scroll_view(&harness, entity).viewport_id());
+    assert!(harness.rect(target).min.y >= viewport.max.y);
+
+    scroll_into_view(&mut harness, target);
+    harness.run_frames(15);
+
+    // Scrolled just enough to show the target at the bottom edge
+    let target_rect = harness.rect(target);
+    assert_eq!(target_rect.max.y, viewport.max.y);
+    assert_eq!(
+        scroll_view(&harness, entity).scroll_offset().y,
+        1050. - viewport.height()
+    );
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Item(u32);
+
+#[derive(Clone, Debug, PartialEq)]
+struct Tool;
+
+/// Spawns a droppable source carrying `payload` and a drop zone accepting [`Item`]s
+fn spawn_drop_zone(harness: &mut UiTestHarness, payload: impl Bundle) -> (Entity, Entity) {
+    harness.app().add_plugins((
+        DropPayloadPlugin::<Item>::new(),
+        DropPayloadPlugin::<Tool>::new(),
+    ));
+    harness.collect_events::<Dropped<Item>>();
+    harness.collect_events::<Dropped<Tool>>();
+
+    let entities = harness.spawn_ui(|ui| {
+        let (mut source, mut zone) = (Entity::PLACEHOLDER, Entity::PLACEHOLDER);
+        ui.column(|column| {
+            source = column
+                .spawn((
+                    NodeBundle::default(),
+                    Interaction::default(),
+                    TrackedInteraction::default(),
+                    Draggable::default(),
+                    RelativeCursorPosition::default(),
+                    Droppable,
+                    payload,
+                ))
+                .style()
+                .width(Val::Px(100.))
+                .height(Val::Px(50.))
+                .id();
+            zone = column
+                .spawn((
+                    NodeBundle::default(),
+                    Interaction::default(),
+                    RelativeCursorPosition::default(),
+                    DropZone::default(),
+                    AcceptedPayloads::of::<Item>(),
+                ))
+                .style()
+                .width(Val::Px(200.))
+                .height(Val::Px(200.))
+                .id();
+        });
+
+        (source, zone)
+    });
+    harness.run_frames(2);
+
+    entities
+}
+
+/// Presses the mouse over `source` and moves it over `zone` without releasing
+fn drag_over(harness: &mut UiTestHarness, source: Entity, zone: Entity) {
+    let (from, to) = (harness.center(source), harness.center(zone));
+    harness.move_cursor(from);
+    harness.press_mouse(MouseButton::Left);
+    for step in 1..=UiTestHarness::DRAG_STEPS {
+        harness.move_cursor(from.lerp(to, step as f32 / UiTestHarness::DRAG_STEPS as f32));
+    }
+}
+
+fn has_pseudo_state(harness: &UiTestHarness, entity: Entity, state: PseudoState) -> bool {
+    harness
+        .world()
+        .get::<PseudoStates>(entity)
+        .is_some_and(|states| states.has(&state))
+}
+
+#[test]
+fn drop_zone_accepts_payload() {
+    let mut harness = UiTestHarness::new();
+    let (source, zone) = spawn_drop_zone(&mut harness, DragPayload(Item(7)));
+
+    drag_over(&mut harness, source, zone);
+    assert!(has_pseudo_state(&harness, zone, PseudoState::DropAccepted));
+    assert!(!has_pseudo_state(&harness, zone, PseudoState::DropRejected));
+    assert!(harness.collected_events::<Dropped<Item>>().is_empty());
+
+    harness.release_mouse(MouseButton::Left);
+    let dropped = harness.collected_events::<Dropped<Item>>();
+    assert_eq!(dropped.len(), 1);
+    assert_eq!(dropped[0].zone, zone);
+    assert_eq!(dropped[0].source, source);
+    assert_eq!(dropped[0].payload, Item(7));
+    assert_eq!(dropped[0].position, Some(harness.center(zone)));
+}
+
+#[test]
+fn drop_zone_rejects_droppable_without_payload() {
+    let mut harness = UiTestHarness::new();
+    let (source, zone) = spawn_drop_zone(&mut harness, ());
+
+    drag_over(&mut harness, source, zone);
+    assert!(has_pseudo_state(&harness, zone, PseudoState::DropRejected));
+    assert!(!has_pseudo_state(&harness, zone, PseudoState::DropAccepted));
+
+    harness.release_mouse(MouseButton::Left);
+    assert!(harness.collected_events::<Dropped<Item>>().is_empty());
+    assert_eq!(
+        harness.world().get::<DropZone>(zone).unwrap().drop_phase(),
+        DropPhase::Inactive
+    );
+}
+
+#[test]
+fn drop_zone_rejects_mismatched_payload() {
+    let mut harness = UiTestHarness::new();
+    let (source, zone) = spawn_drop_zone(&mut harness, DragPayload(Tool));
+    assert!(harness
+        .world()
+        .get::<DragPayloadTypes>(source)
+        .unwrap()
+        .contains::<Tool>());
+
+    drag_over(&mut harness, source, zone);
+    assert!(has_pseudo_state(&harness, zone, PseudoState::DropRejected));
+    assert!(!has_pseudo_state(&harness, zone, PseudoState::DropAccepted));
+
+    harness.release_mouse(MouseButton::Left);
+    assert!(harness.collected_events::<Dropped<Item>>().is_empty());
+    assert!(harness.collected_events::<Dropped<Tool>>().is_empty());
+}
+
+#[test]
+fn drop_zone_clears_feedback_after_drop() {
+    let mut harness = UiTestHarness::new();
+    let (source, zone) = spawn_drop_zone(&mut harness, DragPayload(Item(1)));
+
+    drag_over(&mut harness, source, zone);
+    assert!(has_pseudo_state(&harness, zone, PseudoState::DropAccepted));
+    harness.release_mouse(MouseButton::Left);
+    harness.run_frames(2);
+    assert!(!has_pseudo_state(&harness, zone, PseudoState::DropAccepted));
+    assert!(!has_pseudo_state(&harness, zone, PseudoState::DropRejected));
+
+    // Removing the payload turns the same drag into a rejected one, also cleared on drop
+    harness
+        .world_mut()
+        .entity_mut(source)
+        .remove::<DragPayload<Item>>();
+    harness.update();
+    drag_over(&mut harness, source, zone);
+    assert!(has_pseudo_state(&harness, zone, PseudoState::DropRejected));
+    harness.release_mouse(MouseButton::Left);
+    harness.run_frames(2);
+    assert!(!has_pseudo_state(&harness, zone, PseudoState::DropRejected));
+    assert_eq!(harness.collected_events::<Dropped<Item>>().len(), 1);
+}
+
+/// Spawns a 300 by 200 scroll view with a draggable item above 1000 pixels of content
+fn spawn_draggable_in_scroll_view(harness: &mut UiTestHarness) -> (Entity, Entity) {
+    harness.spawn_ui(|ui| {
+        let (mut scroll_view, mut item) = (Entity::PLACEHOLDER, Entity::PLACEHOLDER);
+        ui.column(|column| {
+            column.style().width(Val::Px(300.)).height(Val::Px(200.));
+
+            scroll_view = column
+                .scroll_view(ScrollAxis::Vertical, |content| {
+                    item = content
+                        .spawn((
+                            NodeBundle::default(),
+                            Interaction::default(),
+                            TrackedInteraction::default(),
+                            Draggable::default(),
+                            RelativeCursorPosition::default(),
+                            Droppable,
+                        ))
+                        .style()
+                        .width(Val::Px(100.))
+                        .height(Val::Px(50.))
+                        .flex_shrink(0.)
+                        .id();
+                    content
+                        .container(NodeBundle::default(), |_| {})
+                        .style()
+                        .width(Val::Px(100.))
+                        .height(Val::Px(1000.))
+                        .flex_shrink(0.);
+                })
+                .id();
+        });
+
+        (scroll_view, item)
+    })
+}
+
+#[test]
+fn drag_hover_scrolling_cancels_scroll_animation() {
+    let mut harness = UiTestHarness::new();
+    let (entity, item) = spawn_draggable_in_scroll_view(&mut harness);
+    harness.run_frames(2);
+
+    // Hold the item over the bottom edge of the viewport
+    let from = harness.center(item);
+    harness.move_cursor(from);
+    harness.press_mouse(MouseButton::Left);
+    harness.move_cursor(Vec2::new(from.x, 195.));
+    harness.run_frames(5);
+    let edge_scrolled = scroll_view(&harness, entity).scroll_offset().y;
+    assert!(edge_scrolled > 0., "drag hover did not scroll");
+
+    // An animation back to the top is interrupted by the ongoing edge scrolling
+    scroll_view_mut(&mut harness, entity).smooth_scroll = true;
+    scroll_view_mut(&mut harness, entity).scroll_to(Vec2::ZERO);
+    harness.run_frames(15);
+    let offset = scroll_view(&harness, entity).scroll_offset().y;
+    assert!(
+        offset > edge_scrolled,
+        "offset {} was reset by the animation",
+        offset
+    );
+
+    harness.release_mouse(MouseButton::Left);
+    let released = scroll_view(&harness, entity).scroll_offset().y;
+    harness.run_frames(5);
+    assert_eq!(scroll_view(&harness, entity).scroll_offset().y, released);
+}
+
+fn spawn_drag_ghost_source(harness: &mut UiTestHarness, drag_ghost: DragGhost) -> Entity {
+    let source = spawn_in_column(harness, |column| {
+        column
+            .spawn((
+                NodeBundle {
+                    background_color: Color::RED.into(),
+                    ..default()
+                },
+                Interaction::default(),
+                TrackedInteraction::default(),
+                Draggable::default(),
+                RelativeCursorPosition::default(),
+                drag_ghost,
+            ))
+            .style()
+            .width(Val::Px(100.))
+            .height(Val::Px(50.))
+            .id()
+    });
+    harness.run_frames(2);
+
+    source
+}
+
+fn drag_ghost(harness: &UiTestHarness, source: Entity) -> Option<Entity> {
+    harness.world().get::<DragGhost>(source).unwrap().ghost()
+}
+
+#[test]
+fn drag_ghost_follows_dragged_node() {
+    let mut harness = UiTestHarness::new();
+    let source = spawn_drag_ghost_source(&mut harness, DragGhost::translucent_clone());
+    assert_eq!(drag_ghost(&harness, source), None);
+
+    // Grabbed 10 pixels right and 5 pixels down from the top left corner
+    harness.move_cursor(Vec2::new(10., 5.));
+    harness.press_mouse(MouseButton::Left);
+    harness.move_cursor(Vec2::new(210., 305.));
+
+    let ghost = drag_ghost(&harness, source).expect("drag ghost was not spawned");
+    let frame = harness.world().get::<DragGhostFrame>(ghost).unwrap();
+    assert_eq!(frame.source(), source);
+    assert_eq!(harness.rect(ghost).min, Vec2::new(200., 300.));
+
+    // The clone is a faded copy of the source, without its interaction components
+    let clone = harness.world().get::<Children>(ghost).unwrap()[0];
+    let world = harness.world();
+    assert_eq!(
+        world.get::<BackgroundColor>(clone).unwrap().0,
+        Color::RED.with_a(0.5)
+    );
+    assert!(world.get::<Draggable>(clone).is_none());
+    assert_eq!(harness.rect(clone).size(), Vec2::new(100., 50.));
+
+    harness.release_mouse(MouseButton::Left);
+    assert_eq!(drag_ghost(&harness, source), None);
+    assert!(harness.world().get_entity(ghost).is_none());
+}
+
+#[test]
+fn drag_ghost_builds_custom_content() {
+    let mut harness = UiTestHarness::new();
+    let source = spawn_drag_ghost_source(
+        &mut harness,
+        DragGhost::custom(|builder, _| {
+            builder.label(LabelConfig::from("Moving"));
+        })
+        .with_opacity(2.),
+    );
+    assert_eq!(
+        harness.world().get::<DragGhost>(source).unwrap().opacity,
+        1.
+    );
+
+    harness.move_cursor(Vec2::new(10., 5.));
+    harness.press_mouse(MouseButton::Left);
+    harness.move_cursor(Vec2::new(110., 105.));
+
+    let ghost = drag_ghost(&harness, source).unwrap();
+    let label = harness.world().get::<Children>(ghost).unwrap()[0];
+    assert_eq!(
+        harness.world().get::<Text>(label).unwrap().sections[0].value,
+        "Moving"
+    );
+}
+
+#[test]
+fn orphaned_drag_ghosts_are_removed() {
+    let mut harness = UiTestHarness::new();
+    let removed = spawn_drag_ghost_source(&mut harness, DragGhost::default());
+
+    harness.move_cursor(Vec2::new(10., 5.));
+    harness.press_mouse(MouseButton::Left);
+    harness.move_cursor(Vec2::new(110., 105.));
+    let ghost = drag_ghost(&harness, removed).unwrap();
+
+    harness
+        .world_mut()
+        .entity_mut(removed)
+        .remove::<DragGhost>();
+    harness.update();
+    assert!(harness.world().get_entity(ghost).is_none());
+    harness.release_mouse(MouseButton::Left);
+
+    let despawned = spawn_drag_ghost_source(&mut harness, DragGhost::default());
+    harness.move_cursor(harness.center(despawned));
+    harness.press_mouse(MouseButton::Left);
+    harness.move_cursor(Vec2::new(300., 300.));
+    let ghost = drag_ghost(&harness, despawned).unwrap();
+
+    harness
+        .world_mut()
+        .entity_mut(despawned)
+        .despawn_recursive();
+    harness.update();
+    assert!(harness.world().get_entity(ghost).is_none());
+    assert_eq!(
+        harness
+            .world_mut()
+            .query::<&DragGhostFrame>()
+            .iter(harness.world())
+            .count(),
+        0
+    );
+}